@@ -6,15 +6,13 @@ use std::{error::Error, path::PathBuf};
 fn generate_link_search_paths(paths: &[Result<String, impl Error + Clone>]) -> Vec<String> {
     paths
         .iter()
-        .map(|path| {
+        .flat_map(|path| {
             path.clone()
                 .unwrap_or_default()
                 .split(":")
                 .map(|path| path.to_string())
                 .collect::<Vec<_>>()
         })
-        .into_iter()
-        .flatten()
         .filter(|path| !path.is_empty())
         .collect::<Vec<_>>()
 }
@@ -33,10 +31,11 @@ fn check_library_found(
             }
         }
     }
-    return None;
+    None
 }
 
-fn main() {
+/// Link to dftd4 library, or build it by cmake if not found.
+fn link_dftd4() {
     // search dirs
     for key in ["DFTD4_DIR", "REST_EXT_DIR"].iter() {
         println!("cargo:rerun-if-env-changed={}", key);
@@ -80,3 +79,10 @@ fn main() {
         }
     }
 }
+
+fn main() {
+    // pure-Rust implementation does not require the dftd4 library
+    if !cfg!(feature = "pure-rust") {
+        link_dftd4();
+    }
+}
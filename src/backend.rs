@@ -0,0 +1,378 @@
+//! Backends that evaluate the D4 dispersion model.
+//!
+//! [`DispersionBackend`] covers everything `library.rs` needs from an implementation of D4:
+//! structure and model creation, damping parameters, energy with derivatives, properties,
+//! pairwise energies and hessian. Two implementations are provided:
+//!
+//! - [`FFIBackend`]: upstream `libdftd4` through its C API (not available with feature
+//!   `pure-rust`);
+//! - [`NativeBackend`]: pure-Rust implementation (see [`crate::native`]).
+//!
+//! Code generic over the backend can also be driven by a user-defined (e.g. mock) backend.
+
+#[cfg(not(feature = "pure-rust"))]
+mod ffi;
+mod native;
+
+#[cfg(not(feature = "pure-rust"))]
+pub use ffi::{FFIBackend, FFIModel, FFIParam, FFIStructure};
+pub use native::NativeBackend;
+
+use crate::library::{
+    DFTD4Cutoff, DFTD4Dispersion, DFTD4EnergyGradient, DFTD4Error, DFTD4Properties,
+};
+
+/// Backend used by `library.rs`
+#[cfg(not(feature = "pure-rust"))]
+pub type DefaultBackend = FFIBackend;
+
+/// Backend used by `library.rs`
+#[cfg(feature = "pure-rust")]
+pub type DefaultBackend = NativeBackend;
+
+/// Implementation of the D4 dispersion model.
+///
+/// All quantities are in atomic units (Bohr, Hartree). Arrays are flattened in row-major order.
+pub trait DispersionBackend {
+    /// Molecular structure data
+    type Structure;
+    /// Dispersion model
+    type Model;
+    /// Damping parameters
+    type Param;
+
+    /// Version of the implementation, encoded as `major * 10000 + minor * 100 + patch`
+    fn get_version() -> usize;
+
+    /// Create new molecular structure data
+    ///
+    /// # Arguments
+    ///
+    /// * `numbers` - numbers [natoms]
+    /// * `positions` - positions [natoms][3]
+    /// * `lattice` - lattice [3][3]
+    /// * `periodic` - periodic [3]
+    fn new_structure_f(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+    ) -> Result<Self::Structure, DFTD4Error>;
+
+    /// Update coordinates and lattice parameters
+    ///
+    /// # Arguments
+    ///
+    /// * `positions` - positions [natoms][3]
+    /// * `lattice` - lattice [3][3]
+    fn update_structure_f(
+        structure: &Self::Structure,
+        positions: &[f64],
+        lattice: Option<&[f64]>,
+    ) -> Result<(), DFTD4Error>;
+
+    /// Get number of atoms
+    fn get_natoms(structure: &Self::Structure) -> usize;
+
+    /// Create new D4 dispersion model
+    fn new_model_f(structure: &Self::Structure) -> Result<Self::Model, DFTD4Error>;
+
+    /// Create new D4 dispersion model with custom charge scaling and weighting
+    fn custom_model_f(
+        structure: &Self::Structure,
+        ga: f64,
+        gc: f64,
+        wf: f64,
+    ) -> Result<Self::Model, DFTD4Error>;
+
+    /// Get real-space cutoffs of the dispersion model
+    fn get_cutoff(_model: &Self::Model) -> DFTD4Cutoff {
+        DFTD4Cutoff::default()
+    }
+
+    /// Set real-space cutoffs of the dispersion model
+    ///
+    /// Implementations without control of cutoffs only accept the default cutoffs.
+    fn set_cutoff_f(_model: &mut Self::Model, cutoff: DFTD4Cutoff) -> Result<(), DFTD4Error> {
+        match cutoff == DFTD4Cutoff::default() {
+            true => Ok(()),
+            false => Err(DFTD4Error::Rust(
+                "Real-space cutoffs cannot be changed in this backend".to_string(),
+            )),
+        }
+    }
+
+    /// Set custom atomic charges [natoms] of the dispersion model, or EEQ charges if `None`
+    ///
+    /// Implementations with EEQ charges only accept `None`.
+    fn set_charges_f(_model: &mut Self::Model, charges: Option<&[f64]>) -> Result<(), DFTD4Error> {
+        match charges {
+            None => Ok(()),
+            Some(_) => Err(DFTD4Error::Rust(
+                "Custom charges are not supported in this backend".to_string(),
+            )),
+        }
+    }
+
+    /// Create new rational damping parameters
+    fn new_rational_damping_f(
+        s6: f64,
+        s8: f64,
+        s9: f64,
+        a1: f64,
+        a2: f64,
+        alp: f64,
+    ) -> Result<Self::Param, DFTD4Error>;
+
+    /// Load rational damping parameters from internal storage
+    fn load_rational_damping_f(method: &str, mdb: bool) -> Result<Self::Param, DFTD4Error>;
+
+    /// Evaluate properties related to the dispersion model
+    ///
+    /// Returns coordination numbers [natoms], charges [natoms], C6 coefficients [natoms][natoms]
+    /// and static polarizabilities [natoms].
+    fn get_properties_f(
+        structure: &Self::Structure,
+        model: &Self::Model,
+    ) -> Result<DFTD4Properties, DFTD4Error>;
+
+    /// Evaluate the dispersion energy and its derivative
+    ///
+    /// Returns energy, gradient [natoms][3] if `eval_grad`, and virial [3][3] if `eval_sigma`.
+    fn get_dispersion_f(
+        structure: &Self::Structure,
+        model: &Self::Model,
+        param: &Self::Param,
+        eval_grad: bool,
+        eval_sigma: bool,
+    ) -> Result<DFTD4Dispersion, DFTD4Error>;
+
+    /// Evaluate the pairwise representation of the dispersion energy
+    ///
+    /// Returns two-body and three-body pair energies [natoms][natoms].
+    fn get_pairwise_dispersion_f(
+        structure: &Self::Structure,
+        model: &Self::Model,
+        param: &Self::Param,
+    ) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error>;
+
+    /// Evaluate the dispersion hessian numerically
+    ///
+    /// Returns hessian [natoms][3][natoms][3].
+    fn get_numerical_hessian_f(
+        structure: &Self::Structure,
+        model: &Self::Model,
+        param: &Self::Param,
+    ) -> Result<Vec<f64>, DFTD4Error>;
+
+    /// Evaluate the derivative of the dispersion energy with respect to atomic charges
+    ///
+    /// Returns `dE/dq` [natoms] at fixed positions. Not available through the C API of
+    /// `libdftd4`, so the FFI backend returns an error.
+    fn get_charge_derivatives_f(
+        _structure: &Self::Structure,
+        _model: &Self::Model,
+        _param: &Self::Param,
+    ) -> Result<Vec<f64>, DFTD4Error> {
+        Err(DFTD4Error::Rust(
+            "Charge derivatives are not available in this backend".to_string(),
+        ))
+    }
+
+    /// Evaluate the dispersion energy and optionally its gradient [natoms][3] for several damping
+    /// parameters
+    ///
+    /// The default implementation evaluates each parameter separately; implementations may share
+    /// the evaluation of the dispersion model (coordination numbers, charges, C6 coefficients).
+    fn get_dispersion_multi_f(
+        structure: &Self::Structure,
+        model: &Self::Model,
+        params: &[&Self::Param],
+        eval_grad: bool,
+    ) -> Result<Vec<DFTD4EnergyGradient>, DFTD4Error> {
+        params
+            .iter()
+            .map(|param| {
+                let (energy, gradient, _) =
+                    Self::get_dispersion_f(structure, model, param, eval_grad, false)?;
+                Ok((energy, gradient))
+            })
+            .collect()
+    }
+
+    /// Get rational damping parameters `[s6, s8, s9, a1, a2, alp]`
+    ///
+    /// Parameters are opaque in the C API of `libdftd4`, which only supports parameters created
+    /// from explicit values.
+    fn get_rational_damping_f(_param: &Self::Param) -> Result<[f64; 6], DFTD4Error> {
+        Err(DFTD4Error::Rust(
+            "Values of loaded damping parameters are not available in this backend".to_string(),
+        ))
+    }
+
+    /// Evaluate dynamic polarizabilities at imaginary frequencies
+    ///
+    /// Returns polarizabilities [natoms][nfreq] at the frequencies `native::FREQ` of the D4
+    /// model. Not available through the C API of `libdftd4`, so the FFI backend returns an error.
+    fn get_dynamic_polarizabilities_f(
+        _structure: &Self::Structure,
+        _model: &Self::Model,
+    ) -> Result<Vec<f64>, DFTD4Error> {
+        Err(DFTD4Error::Rust(
+            "Dynamic polarizabilities are not available in this backend".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Mock backend: pairwise `-c6 / r^6` with unit C6 coefficients
+    struct MockBackend;
+
+    impl DispersionBackend for MockBackend {
+        type Structure = RefCell<Vec<f64>>;
+        type Model = f64;
+        type Param = f64;
+
+        fn get_version() -> usize {
+            0
+        }
+
+        fn new_structure_f(
+            _natoms: usize,
+            _numbers: &[usize],
+            positions: &[f64],
+            _charge: Option<f64>,
+            _lattice: Option<&[f64]>,
+            _periodic: Option<&[bool]>,
+        ) -> Result<Self::Structure, DFTD4Error> {
+            Ok(RefCell::new(positions.to_vec()))
+        }
+
+        fn update_structure_f(
+            structure: &Self::Structure,
+            positions: &[f64],
+            _lattice: Option<&[f64]>,
+        ) -> Result<(), DFTD4Error> {
+            structure.borrow_mut().copy_from_slice(positions);
+            Ok(())
+        }
+
+        fn get_natoms(structure: &Self::Structure) -> usize {
+            structure.borrow().len() / 3
+        }
+
+        fn new_model_f(_structure: &Self::Structure) -> Result<Self::Model, DFTD4Error> {
+            Ok(1.0)
+        }
+
+        fn custom_model_f(
+            _structure: &Self::Structure,
+            _ga: f64,
+            _gc: f64,
+            _wf: f64,
+        ) -> Result<Self::Model, DFTD4Error> {
+            Ok(1.0)
+        }
+
+        fn new_rational_damping_f(
+            s6: f64,
+            _s8: f64,
+            _s9: f64,
+            _a1: f64,
+            _a2: f64,
+            _alp: f64,
+        ) -> Result<Self::Param, DFTD4Error> {
+            Ok(s6)
+        }
+
+        fn load_rational_damping_f(_method: &str, _mdb: bool) -> Result<Self::Param, DFTD4Error> {
+            Ok(1.0)
+        }
+
+        fn get_properties_f(
+            structure: &Self::Structure,
+            model: &Self::Model,
+        ) -> Result<DFTD4Properties, DFTD4Error> {
+            let natoms = Self::get_natoms(structure);
+            let c6 = vec![*model; natoms * natoms];
+            Ok((vec![0.0; natoms], vec![0.0; natoms], c6, vec![0.0; natoms]))
+        }
+
+        fn get_dispersion_f(
+            structure: &Self::Structure,
+            model: &Self::Model,
+            param: &Self::Param,
+            eval_grad: bool,
+            _eval_sigma: bool,
+        ) -> Result<DFTD4Dispersion, DFTD4Error> {
+            let positions = structure.borrow();
+            let natoms = positions.len() / 3;
+            let mut energy = 0.0;
+            let mut gradient = vec![0.0; 3 * natoms];
+            for i in 0..natoms {
+                for j in 0..i {
+                    let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k]);
+                    let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                    energy -= param * model / r2.powi(3);
+                    for (k, vk) in v.iter().enumerate() {
+                        let g = 6.0 * param * model / r2.powi(4) * vk;
+                        gradient[3 * i + k] += g;
+                        gradient[3 * j + k] -= g;
+                    }
+                }
+            }
+            Ok((energy, eval_grad.then_some(gradient), None))
+        }
+
+        fn get_pairwise_dispersion_f(
+            structure: &Self::Structure,
+            _model: &Self::Model,
+            _param: &Self::Param,
+        ) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
+            let natoms = Self::get_natoms(structure);
+            Ok((vec![0.0; natoms * natoms], vec![0.0; natoms * natoms]))
+        }
+
+        fn get_numerical_hessian_f(
+            structure: &Self::Structure,
+            _model: &Self::Model,
+            _param: &Self::Param,
+        ) -> Result<Vec<f64>, DFTD4Error> {
+            let natoms = Self::get_natoms(structure);
+            Ok(vec![0.0; 9 * natoms * natoms])
+        }
+    }
+
+    /// Example of a driver generic over the backend: energy after a displacement of atom 0
+    fn displaced_energy<B: DispersionBackend>(
+        numbers: &[usize],
+        positions: &[f64],
+        shift: f64,
+    ) -> Result<f64, DFTD4Error> {
+        let structure = B::new_structure_f(numbers.len(), numbers, positions, None, None, None)?;
+        let model = B::new_model_f(&structure)?;
+        let param = B::load_rational_damping_f("pbe", true)?;
+        let mut displaced = positions.to_vec();
+        displaced[0] += shift;
+        B::update_structure_f(&structure, &displaced, None)?;
+        let (energy, _, _) = B::get_dispersion_f(&structure, &model, &param, false, false)?;
+        Ok(energy)
+    }
+
+    #[test]
+    fn test_mock_backend() {
+        let numbers = [1, 1];
+        let positions = [0.0, 0.0, 0.0, 0.0, 0.0, 2.0];
+        let energy = displaced_energy::<MockBackend>(&numbers, &positions, 0.0).unwrap();
+        assert!((energy + 1.0 / 64.0).abs() < 1e-12);
+        // energy is invariant to displacement perpendicular to the bond, up to second order
+        let energy = displaced_energy::<MockBackend>(&numbers, &positions, 1e-4).unwrap();
+        assert!((energy + 1.0 / 64.0).abs() < 1e-8);
+    }
+}
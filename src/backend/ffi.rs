@@ -0,0 +1,325 @@
+//! Backend of upstream `libdftd4` through its C API.
+//!
+//! The C API has no control of real-space cutoffs or charges. Cutoffs other than the defaults of
+//! upstream dftd4, custom charges, charge derivatives and dynamic polarizabilities are not
+//! available, and return an error. The C API evaluates the model for each damping parameter, so
+//! several parameters are a plain loop over `dftd4_get_dispersion`.
+
+use super::DispersionBackend;
+use crate::ffi;
+use crate::library::{DFTD4Dispersion, DFTD4Error, DFTD4Properties};
+use std::ffi::{c_char, c_int};
+use std::ptr::{null, null_mut};
+
+/// Backend of upstream `libdftd4`
+pub struct FFIBackend;
+
+/// Molecular structure data of `libdftd4`
+pub struct FFIStructure {
+    ptr: ffi::dftd4_structure,
+    natoms: usize,
+}
+
+impl Drop for FFIStructure {
+    fn drop(&mut self) {
+        unsafe { ffi::dftd4_delete_structure(&mut self.ptr) };
+    }
+}
+
+/// Dispersion model of `libdftd4`
+pub struct FFIModel {
+    ptr: ffi::dftd4_model,
+}
+
+impl Drop for FFIModel {
+    fn drop(&mut self) {
+        unsafe { ffi::dftd4_delete_model(&mut self.ptr) };
+    }
+}
+
+/// Damping parameters of `libdftd4`
+pub struct FFIParam {
+    ptr: ffi::dftd4_param,
+}
+
+impl Drop for FFIParam {
+    fn drop(&mut self) {
+        unsafe { ffi::dftd4_delete_param(&mut self.ptr) };
+    }
+}
+
+impl DispersionBackend for FFIBackend {
+    type Structure = FFIStructure;
+    type Model = FFIModel;
+    type Param = FFIParam;
+
+    fn get_version() -> usize {
+        unsafe { ffi::dftd4_get_version() as usize }
+    }
+
+    fn new_structure_f(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+    ) -> Result<FFIStructure, DFTD4Error> {
+        // check dimension
+        if numbers.len() != natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for numbers, expected {}, got {}",
+                natoms,
+                numbers.len()
+            )));
+        }
+        if positions.len() != 3 * natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for positions, expected {}, got {}",
+                3 * natoms,
+                positions.len()
+            )));
+        }
+        if lattice.is_some_and(|lattice| lattice.len() != 9) {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for lattice, expected 9, got {}",
+                lattice.unwrap().len()
+            )));
+        }
+        if periodic.is_some_and(|periodic| periodic.len() != 3) {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for periodic, expected 3, got {}",
+                periodic.unwrap().len()
+            )));
+        }
+        // unwrap optional values
+        let charge_ptr = charge.map_or(null(), |x| &x as *const f64);
+        let lattice_ptr = lattice.map_or(null(), |x| x.as_ptr());
+        let periodic_ptr = periodic.map_or(null(), |x| x.as_ptr());
+        // type conversion from usual definitions
+        let natoms_c_int = natoms as c_int;
+        let atomic_numbers = numbers.iter().map(|&x| x as c_int).collect::<Vec<c_int>>();
+        // actual driver for creating the structure
+        let mut error = DFTD4Error::new();
+        let ptr = unsafe {
+            ffi::dftd4_new_structure(
+                error.get_c_ptr(),
+                natoms_c_int,
+                atomic_numbers.as_ptr(),
+                positions.as_ptr(),
+                charge_ptr,
+                lattice_ptr,
+                periodic_ptr,
+            )
+        };
+        match error.check() {
+            true => Err(error),
+            false => Ok(FFIStructure { ptr, natoms }),
+        }
+    }
+
+    fn update_structure_f(
+        structure: &FFIStructure,
+        positions: &[f64],
+        lattice: Option<&[f64]>,
+    ) -> Result<(), DFTD4Error> {
+        // check dimension
+        if positions.len() != 3 * structure.natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for positions, expected {}, got {}",
+                3 * structure.natoms,
+                positions.len()
+            )));
+        }
+        if lattice.is_some_and(|lattice| lattice.len() != 9) {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for lattice, expected 9, got {}",
+                lattice.unwrap().len()
+            )));
+        }
+        // unwrap optional values
+        let lattice_ptr = lattice.map_or(null(), |x| x.as_ptr());
+        // actual driver for updating the structure
+        let mut error = DFTD4Error::new();
+        unsafe {
+            ffi::dftd4_update_structure(
+                error.get_c_ptr(),
+                structure.ptr,
+                positions.as_ptr(),
+                lattice_ptr,
+            )
+        };
+        match error.check() {
+            true => Err(error),
+            false => Ok(()),
+        }
+    }
+
+    fn get_natoms(structure: &FFIStructure) -> usize {
+        structure.natoms
+    }
+
+    fn new_model_f(structure: &FFIStructure) -> Result<FFIModel, DFTD4Error> {
+        let mut error = DFTD4Error::new();
+        let ptr = unsafe { ffi::dftd4_new_d4_model(error.get_c_ptr(), structure.ptr) };
+        match error.check() {
+            true => Err(error),
+            false => Ok(FFIModel { ptr }),
+        }
+    }
+
+    fn custom_model_f(
+        structure: &FFIStructure,
+        ga: f64,
+        gc: f64,
+        wf: f64,
+    ) -> Result<FFIModel, DFTD4Error> {
+        let mut error = DFTD4Error::new();
+        let ptr =
+            unsafe { ffi::dftd4_custom_d4_model(error.get_c_ptr(), structure.ptr, ga, gc, wf) };
+        match error.check() {
+            true => Err(error),
+            false => Ok(FFIModel { ptr }),
+        }
+    }
+
+    fn new_rational_damping_f(
+        s6: f64,
+        s8: f64,
+        s9: f64,
+        a1: f64,
+        a2: f64,
+        alp: f64,
+    ) -> Result<FFIParam, DFTD4Error> {
+        let mut error = DFTD4Error::new();
+        let ptr =
+            unsafe { ffi::dftd4_new_rational_damping(error.get_c_ptr(), s6, s8, s9, a1, a2, alp) };
+        match error.check() {
+            true => Err(error),
+            false => Ok(FFIParam { ptr }),
+        }
+    }
+
+    fn load_rational_damping_f(method: &str, mdb: bool) -> Result<FFIParam, DFTD4Error> {
+        let mut error = DFTD4Error::new();
+        let name_c = std::ffi::CString::new(method).unwrap();
+        let ptr = unsafe {
+            ffi::dftd4_load_rational_damping(error.get_c_ptr(), name_c.as_ptr() as *mut c_char, mdb)
+        };
+        match error.check() {
+            true => Err(error),
+            false => Ok(FFIParam { ptr }),
+        }
+    }
+
+    fn get_properties_f(
+        structure: &FFIStructure,
+        model: &FFIModel,
+    ) -> Result<DFTD4Properties, DFTD4Error> {
+        let mut error = DFTD4Error::new();
+        let natoms = structure.natoms;
+        let mut cn = vec![0.0; natoms];
+        let mut charges = vec![0.0; natoms];
+        let mut c6 = vec![0.0; natoms * natoms];
+        let mut alpha = vec![0.0; natoms];
+        unsafe {
+            ffi::dftd4_get_properties(
+                error.get_c_ptr(),
+                structure.ptr,
+                model.ptr,
+                cn.as_mut_ptr(),
+                charges.as_mut_ptr(),
+                c6.as_mut_ptr(),
+                alpha.as_mut_ptr(),
+            )
+        };
+        match error.check() {
+            true => Err(error),
+            false => Ok((cn, charges, c6, alpha)),
+        }
+    }
+
+    fn get_dispersion_f(
+        structure: &FFIStructure,
+        model: &FFIModel,
+        param: &FFIParam,
+        eval_grad: bool,
+        eval_sigma: bool,
+    ) -> Result<DFTD4Dispersion, DFTD4Error> {
+        let natoms = structure.natoms;
+        let mut energy = 0.0;
+        let mut grad = match eval_grad {
+            true => Some(vec![0.0; 3 * natoms]),
+            false => None,
+        };
+        let mut sigma = match eval_sigma {
+            true => Some(vec![0.0; 9]),
+            false => None,
+        };
+        let mut error = DFTD4Error::new();
+        unsafe {
+            ffi::dftd4_get_dispersion(
+                error.get_c_ptr(),
+                structure.ptr,
+                model.ptr,
+                param.ptr,
+                &mut energy,
+                grad.as_mut().map_or(null_mut(), |x| x.as_mut_ptr()),
+                sigma.as_mut().map_or(null_mut(), |x| x.as_mut_ptr()),
+            )
+        };
+        match error.check() {
+            true => Err(error),
+            false => Ok((energy, grad, sigma)),
+        }
+    }
+
+    fn get_pairwise_dispersion_f(
+        structure: &FFIStructure,
+        model: &FFIModel,
+        param: &FFIParam,
+    ) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
+        let natoms = structure.natoms;
+        let mut pair_energy2 = vec![0.0; natoms * natoms];
+        let mut pair_energy3 = vec![0.0; natoms * natoms];
+        let mut error = DFTD4Error::new();
+
+        unsafe {
+            ffi::dftd4_get_pairwise_dispersion(
+                error.get_c_ptr(),
+                structure.ptr,
+                model.ptr,
+                param.ptr,
+                pair_energy2.as_mut_ptr(),
+                pair_energy3.as_mut_ptr(),
+            )
+        };
+        match error.check() {
+            true => Err(error),
+            false => Ok((pair_energy2, pair_energy3)),
+        }
+    }
+
+    fn get_numerical_hessian_f(
+        structure: &FFIStructure,
+        model: &FFIModel,
+        param: &FFIParam,
+    ) -> Result<Vec<f64>, DFTD4Error> {
+        let natoms = structure.natoms;
+        let mut hessian = vec![0.0; 9 * natoms * natoms];
+        let mut error = DFTD4Error::new();
+        unsafe {
+            ffi::dftd4_get_numerical_hessian(
+                error.get_c_ptr(),
+                structure.ptr,
+                model.ptr,
+                param.ptr,
+                hessian.as_mut_ptr(),
+            )
+        };
+        match error.check() {
+            true => Err(error),
+            false => Ok(hessian),
+        }
+    }
+}
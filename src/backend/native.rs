@@ -0,0 +1,145 @@
+//! Backend of the pure-Rust implementation.
+
+use super::DispersionBackend;
+use crate::library::{
+    DFTD4Cutoff, DFTD4Dispersion, DFTD4EnergyGradient, DFTD4Error, DFTD4Properties,
+};
+use crate::native::{self, NativeModel, NativeParam, NativeStructure};
+
+/// Backend of the pure-Rust implementation
+pub struct NativeBackend;
+
+impl DispersionBackend for NativeBackend {
+    type Structure = NativeStructure;
+    type Model = NativeModel;
+    type Param = NativeParam;
+
+    fn get_version() -> usize {
+        native::API_VERSION
+    }
+
+    fn new_structure_f(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+    ) -> Result<NativeStructure, DFTD4Error> {
+        NativeStructure::new_f(natoms, numbers, positions, charge, lattice, periodic)
+    }
+
+    fn update_structure_f(
+        structure: &NativeStructure,
+        positions: &[f64],
+        lattice: Option<&[f64]>,
+    ) -> Result<(), DFTD4Error> {
+        structure.update_f(positions, lattice)
+    }
+
+    fn get_natoms(structure: &NativeStructure) -> usize {
+        structure.get_natoms()
+    }
+
+    fn new_model_f(structure: &NativeStructure) -> Result<NativeModel, DFTD4Error> {
+        NativeModel::new_f(structure)
+    }
+
+    fn custom_model_f(
+        structure: &NativeStructure,
+        ga: f64,
+        gc: f64,
+        wf: f64,
+    ) -> Result<NativeModel, DFTD4Error> {
+        NativeModel::custom_f(structure, ga, gc, wf)
+    }
+
+    fn get_cutoff(model: &NativeModel) -> DFTD4Cutoff {
+        model.get_cutoff()
+    }
+
+    fn set_cutoff_f(model: &mut NativeModel, cutoff: DFTD4Cutoff) -> Result<(), DFTD4Error> {
+        model.set_cutoff(cutoff);
+        Ok(())
+    }
+
+    fn get_charge_derivatives_f(
+        structure: &NativeStructure,
+        model: &NativeModel,
+        param: &NativeParam,
+    ) -> Result<Vec<f64>, DFTD4Error> {
+        native::get_charge_derivatives_f(structure, model, param)
+    }
+
+    fn get_dynamic_polarizabilities_f(
+        structure: &NativeStructure,
+        model: &NativeModel,
+    ) -> Result<Vec<f64>, DFTD4Error> {
+        native::get_dynamic_polarizabilities_f(structure, model)
+    }
+
+    fn set_charges_f(model: &mut NativeModel, charges: Option<&[f64]>) -> Result<(), DFTD4Error> {
+        model.set_charges_f(charges)
+    }
+
+    fn new_rational_damping_f(
+        s6: f64,
+        s8: f64,
+        s9: f64,
+        a1: f64,
+        a2: f64,
+        alp: f64,
+    ) -> Result<NativeParam, DFTD4Error> {
+        NativeParam::new_rational_damping_f(s6, s8, s9, a1, a2, alp)
+    }
+
+    fn load_rational_damping_f(method: &str, mdb: bool) -> Result<NativeParam, DFTD4Error> {
+        NativeParam::load_rational_damping_f(method, mdb)
+    }
+
+    fn get_rational_damping_f(param: &NativeParam) -> Result<[f64; 6], DFTD4Error> {
+        Ok(param.get_rational_damping())
+    }
+
+    fn get_properties_f(
+        structure: &NativeStructure,
+        model: &NativeModel,
+    ) -> Result<DFTD4Properties, DFTD4Error> {
+        native::get_properties_f(structure, model)
+    }
+
+    fn get_dispersion_f(
+        structure: &NativeStructure,
+        model: &NativeModel,
+        param: &NativeParam,
+        eval_grad: bool,
+        eval_sigma: bool,
+    ) -> Result<DFTD4Dispersion, DFTD4Error> {
+        native::get_dispersion_f(structure, model, param, eval_grad, eval_sigma)
+    }
+
+    fn get_dispersion_multi_f(
+        structure: &NativeStructure,
+        model: &NativeModel,
+        params: &[&NativeParam],
+        eval_grad: bool,
+    ) -> Result<Vec<DFTD4EnergyGradient>, DFTD4Error> {
+        native::get_dispersion_multi_f(structure, model, params, eval_grad)
+    }
+
+    fn get_pairwise_dispersion_f(
+        structure: &NativeStructure,
+        model: &NativeModel,
+        param: &NativeParam,
+    ) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
+        native::get_pairwise_dispersion_f(structure, model, param)
+    }
+
+    fn get_numerical_hessian_f(
+        structure: &NativeStructure,
+        model: &NativeModel,
+        param: &NativeParam,
+    ) -> Result<Vec<f64>, DFTD4Error> {
+        native::get_numerical_hessian_f(structure, model, param)
+    }
+}
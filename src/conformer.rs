@@ -0,0 +1,350 @@
+//! Ranking of conformer ensembles by D4 dispersion energies.
+//!
+//! Conformers are read from multi-frame XYZ files (positions in Angstrom), as written by conformer
+//! search programs such as CREST. Dispersion energies of frames are evaluated in parallel, each
+//! thread with its own structure data; consecutive frames with the same atoms update positions of
+//! the structure (`DFTD4Structure::update_f`) instead of creating new structure data. Optional
+//! external base energies (e.g. DFT energies without dispersion) are added, and conformers are
+//! ranked by total energy relative to the lowest one.
+
+use crate::data::{get_atomic_number, get_element_symbol, AATOAU, AUTOKCAL};
+use crate::library::*;
+
+/// Frame of a multi-frame XYZ file
+#[derive(Debug, Clone)]
+pub struct XYZFrame {
+    /// atomic numbers [natoms]
+    pub numbers: Vec<usize>,
+    /// positions (Bohr) [natoms][3]
+    pub positions: Vec<f64>,
+    /// comment line
+    pub comment: String,
+}
+
+/// Configuration of conformer ranking
+#[derive(Debug, Clone)]
+pub struct ConformerConfig {
+    /// functional, as in `DFTD4Param::load_rational_damping`
+    pub method: String,
+    /// whether to include the three-body term
+    pub mdb: bool,
+    /// total charge of conformers
+    pub charge: Option<f64>,
+    /// spin multiplicity of conformers; lowest multiplicity for the number of electrons if `None`
+    pub multiplicity: Option<usize>,
+    /// number of threads; available parallelism if 0
+    pub nthreads: usize,
+}
+
+impl Default for ConformerConfig {
+    fn default() -> Self {
+        Self {
+            method: "PBE0".to_string(),
+            mdb: true,
+            charge: None,
+            multiplicity: None,
+            nthreads: 0,
+        }
+    }
+}
+
+/// Conformers ranked by total energy
+#[derive(Debug, Clone)]
+pub struct ConformerRanking {
+    /// dispersion energies of frames (Hartree) [nframes]
+    pub dispersion: Vec<f64>,
+    /// total energies of frames, base energy plus dispersion (Hartree) [nframes]
+    pub total: Vec<f64>,
+    /// total energies relative to the lowest (kcal/mol) [nframes]
+    pub relative: Vec<f64>,
+    /// frame indices in order of increasing total energy [nframes]
+    pub order: Vec<usize>,
+    /// spin multiplicity of conformers
+    pub multiplicity: usize,
+}
+
+/// Read frames of a multi-frame XYZ file (failable)
+pub fn read_xyz_frames_f(xyz: &str) -> Result<Vec<XYZFrame>, DFTD4Error> {
+    let lines = xyz.lines().collect::<Vec<&str>>();
+    let mut frames = vec![];
+    let mut iline = 0;
+    while iline < lines.len() {
+        if lines[iline].trim().is_empty() {
+            iline += 1;
+            continue;
+        }
+        let natoms = lines[iline].trim().parse::<usize>().map_err(|_| {
+            DFTD4Error::Rust(format!(
+                "Invalid number of atoms in line {}: {}",
+                iline + 1,
+                lines[iline]
+            ))
+        })?;
+        if iline + 2 + natoms > lines.len() {
+            return Err(DFTD4Error::Rust(format!(
+                "Incomplete frame {} with {} atoms in line {}",
+                frames.len(),
+                natoms,
+                iline + 1
+            )));
+        }
+        let comment = lines[iline + 1].trim().to_string();
+        let mut numbers = vec![];
+        let mut positions = vec![];
+        for (k, line) in lines[iline + 2..iline + 2 + natoms].iter().enumerate() {
+            let invalid =
+                || DFTD4Error::Rust(format!("Invalid atom in line {}: {}", iline + 3 + k, line));
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            if fields.len() < 4 {
+                return Err(invalid());
+            }
+            let number = match fields[0].parse::<usize>() {
+                Ok(number) => number,
+                Err(_) => get_atomic_number(fields[0]).ok_or_else(invalid)?,
+            };
+            numbers.push(number);
+            for x in &fields[1..4] {
+                positions.push(x.parse::<f64>().map_err(|_| invalid())? * AATOAU);
+            }
+        }
+        frames.push(XYZFrame {
+            numbers,
+            positions,
+            comment,
+        });
+        iline += 2 + natoms;
+    }
+    if frames.is_empty() {
+        return Err(DFTD4Error::Rust("No frames in XYZ file".to_string()));
+    }
+    Ok(frames)
+}
+
+/// Read frames of a multi-frame XYZ file
+///
+/// Atoms are given by element symbols or atomic numbers, followed by positions in Angstrom;
+/// positions of frames are converted to Bohr.
+pub fn read_xyz_frames(xyz: &str) -> Vec<XYZFrame> {
+    read_xyz_frames_f(xyz).unwrap()
+}
+
+/// Evaluate dispersion energies of consecutive frames in one thread
+fn get_frame_energies_f(
+    frames: &[XYZFrame],
+    config: &ConformerConfig,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let param = DFTD4Param::load_rational_damping_f(&config.method, config.mdb)?;
+    let mut current: Option<(DFTD4Structure, DFTD4Model)> = None;
+    let mut energies = vec![];
+    for frame in frames {
+        let natoms = frame.numbers.len();
+        match &current {
+            Some((structure, _)) if structure.get_numbers() == frame.numbers => {
+                structure.update_f(&frame.positions, None)?;
+            }
+            _ => {
+                let structure = DFTD4Structure::new_with_multiplicity_f(
+                    natoms,
+                    &frame.numbers,
+                    &frame.positions,
+                    config.charge,
+                    config.multiplicity,
+                    None,
+                    None,
+                )?;
+                let model = DFTD4Model::new_f(&structure)?;
+                current = Some((structure, model));
+            }
+        }
+        let (structure, model) = current.as_ref().unwrap();
+        let (energy, _, _) = get_dispersion_f(structure, model, &param, false, false)?;
+        energies.push(energy);
+    }
+    Ok(energies)
+}
+
+/// Evaluate dispersion energies of frames in parallel (failable)
+pub fn get_conformer_energies_f(
+    frames: &[XYZFrame],
+    config: &ConformerConfig,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let nthreads = match config.nthreads {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    let chunk = frames.len().div_ceil(nthreads).max(1);
+    // errors of libdftd4 are not sendable between threads, and are passed as messages
+    let results = std::thread::scope(|scope| {
+        let handles = frames
+            .chunks(chunk)
+            .map(|frames| {
+                scope.spawn(|| get_frame_energies_f(frames, config).map_err(|e| e.get_message()))
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err("Thread panicked".to_string()))
+            })
+            .collect::<Vec<_>>()
+    });
+    let mut energies = vec![];
+    for result in results {
+        energies.extend(result.map_err(DFTD4Error::Rust)?);
+    }
+    Ok(energies)
+}
+
+/// Evaluate dispersion energies of frames in parallel
+///
+/// Frames are split into contiguous chunks, one for each of `config.nthreads` threads.
+pub fn get_conformer_energies(frames: &[XYZFrame], config: &ConformerConfig) -> Vec<f64> {
+    get_conformer_energies_f(frames, config).unwrap()
+}
+
+/// Rank conformers by total energy (failable)
+pub fn rank_conformers_f(
+    frames: &[XYZFrame],
+    base_energies: Option<&[f64]>,
+    config: &ConformerConfig,
+) -> Result<ConformerRanking, DFTD4Error> {
+    let nframes = frames.len();
+    // total energies are only comparable between frames of the same atoms
+    let composition = |frame: &XYZFrame| {
+        let mut numbers = frame.numbers.clone();
+        numbers.sort_unstable();
+        numbers
+    };
+    let mut multiplicity = config.multiplicity.unwrap_or(1);
+    if let Some(first) = frames.first() {
+        if let Some(k) = frames
+            .iter()
+            .position(|frame| composition(frame) != composition(first))
+        {
+            return Err(DFTD4Error::Rust(format!(
+                "Composition of frame {} differs from frame 1",
+                k + 1
+            )));
+        }
+        // frames of the same composition and charge share the spin multiplicity
+        multiplicity = DFTD4Structure::new_with_multiplicity_f(
+            first.numbers.len(),
+            &first.numbers,
+            &first.positions,
+            config.charge,
+            config.multiplicity,
+            None,
+            None,
+        )?
+        .get_multiplicity();
+    }
+    if let Some(base_energies) = base_energies {
+        if base_energies.len() != nframes {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for base energies, expected {}, got {}",
+                nframes,
+                base_energies.len()
+            )));
+        }
+    }
+    let dispersion = get_conformer_energies_f(frames, config)?;
+    let total = (0..nframes)
+        .map(|k| dispersion[k] + base_energies.map_or(0.0, |e| e[k]))
+        .collect::<Vec<f64>>();
+    let lowest = total.iter().copied().fold(f64::INFINITY, f64::min);
+    let relative = total.iter().map(|e| (e - lowest) * AUTOKCAL).collect();
+    let mut order = (0..nframes).collect::<Vec<usize>>();
+    order.sort_by(|&a, &b| total[a].total_cmp(&total[b]));
+    Ok(ConformerRanking {
+        dispersion,
+        total,
+        relative,
+        order,
+        multiplicity,
+    })
+}
+
+/// Rank conformers by total energy
+///
+/// All frames should have the same composition (atoms in any order).
+///
+/// # Arguments
+///
+/// * `base_energies` - energies added to dispersion energies (Hartree) [nframes], e.g. DFT
+///   energies of conformers; conformers are ranked by dispersion energies only if `None`
+pub fn rank_conformers(
+    frames: &[XYZFrame],
+    base_energies: Option<&[f64]>,
+    config: &ConformerConfig,
+) -> ConformerRanking {
+    rank_conformers_f(frames, base_energies, config).unwrap()
+}
+
+/// Format ranked conformers as multi-frame XYZ (positions in Angstrom) (failable)
+pub fn get_xyz_ensemble_f(
+    frames: &[XYZFrame],
+    ranking: &ConformerRanking,
+) -> Result<String, DFTD4Error> {
+    let mut xyz = String::new();
+    for &k in &ranking.order {
+        let frame = frames.get(k).ok_or_else(|| {
+            DFTD4Error::Rust(format!(
+                "Invalid frame index {}, number of frames is {}",
+                k,
+                frames.len()
+            ))
+        })?;
+        xyz += &format!("{}\n", frame.numbers.len());
+        xyz += &format!(
+            "{:.10} {:.4} kcal/mol dispersion {:.10} multiplicity {} frame {}",
+            ranking.total[k],
+            ranking.relative[k],
+            ranking.dispersion[k],
+            ranking.multiplicity,
+            k + 1
+        );
+        match frame.comment.is_empty() {
+            true => xyz += "\n",
+            false => xyz += &format!(" | {}\n", frame.comment),
+        }
+        for (&z, r) in frame.numbers.iter().zip(frame.positions.chunks(3)) {
+            let symbol = get_element_symbol(z)
+                .ok_or_else(|| DFTD4Error::Rust(format!("Unsupported atomic number {}", z)))?;
+            let r = r.iter().map(|x| x / AATOAU).collect::<Vec<f64>>();
+            xyz += &format!("{:<3}{:16.8}{:16.8}{:16.8}\n", symbol, r[0], r[1], r[2]);
+        }
+    }
+    Ok(xyz)
+}
+
+/// Format ranked conformers as multi-frame XYZ (positions in Angstrom)
+///
+/// Frames are sorted by total energy; the comment line of each frame holds total energy, relative
+/// energy in kcal/mol, dispersion energy, spin multiplicity and the (1-based) index of the frame
+/// in the input, followed by the input comment line after ` | ` if it is not empty.
+pub fn get_xyz_ensemble(frames: &[XYZFrame], ranking: &ConformerRanking) -> String {
+    get_xyz_ensemble_f(frames, ranking).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_xyz_frames() {
+        let xyz = "3\n  -76.1 \nO 0.0 0.0 0.1\nh 0.0 0.7 -0.4\n1 0.0 -0.7 -0.4\n\n1\n\nHe 0 0 1\n";
+        let frames = read_xyz_frames(xyz);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].numbers, [8, 1, 1]);
+        assert_eq!(frames[0].comment, "-76.1");
+        assert!((frames[0].positions[4] - 0.7 * AATOAU).abs() < 1e-12);
+        assert_eq!(frames[1].numbers, [2]);
+        assert_eq!(frames[1].comment, "");
+        assert!(read_xyz_frames_f("").is_err());
+        assert!(read_xyz_frames_f("2\n\nO 0 0 0\n").is_err());
+        assert!(read_xyz_frames_f("1\n\nXx 0 0 0\n").is_err());
+        assert!(read_xyz_frames_f("1\n\nO 0 0\n").is_err());
+    }
+}
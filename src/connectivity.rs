@@ -0,0 +1,177 @@
+//! Connectivity of atoms and detection of fragments.
+//!
+//! Atoms are bonded if their distance is below the sum of covalent radii, which are scaled in the
+//! same way as in the D4 coordination number (4/3 of Pyykkö radii), so that bonded atoms are those
+//! that count at least half to each other's coordination number. Periodic images are taken into
+//! account for directions that are periodic.
+
+use crate::data::get_covalent_rad;
+use crate::lattice::get_lattice_points;
+use crate::library::*;
+
+/// Neighbor lists of atoms [natoms][..], sorted and without duplicates.
+pub(crate) fn get_neighbors(
+    numbers: &[usize],
+    positions: &[f64],
+    lattice: Option<&[f64]>,
+    periodic: Option<&[bool]>,
+    scale: f64,
+) -> Result<Vec<Vec<usize>>, DFTD4Error> {
+    let natoms = numbers.len();
+    let rcov = numbers
+        .iter()
+        .map(|&z| {
+            get_covalent_rad(z)
+                .ok_or_else(|| DFTD4Error::Rust(format!("Unsupported atomic number {}", z)))
+        })
+        .collect::<Result<Vec<f64>, DFTD4Error>>()?;
+    let rmax = rcov.iter().cloned().fold(0.0, f64::max);
+    let periodic = periodic.unwrap_or(&[true; 3]);
+    let images = get_lattice_points(lattice, periodic, positions, 2.0 * scale * rmax);
+    let mut neighbors = vec![vec![]; natoms];
+    for i in 0..natoms {
+        for j in 0..=i {
+            let rc = scale * (rcov[i] + rcov[j]);
+            let bonded = images.iter().any(|t| {
+                let r2 = (0..3)
+                    .map(|k| (positions[3 * i + k] - positions[3 * j + k] - t[k]).powi(2))
+                    .sum::<f64>();
+                // an atom is not bonded to itself in the same cell
+                r2 > 1e-12 && r2 < rc * rc
+            });
+            if bonded {
+                neighbors[i].push(j);
+                if i != j {
+                    neighbors[j].push(i);
+                }
+            }
+        }
+    }
+    neighbors.iter_mut().for_each(|n| n.sort_unstable());
+    Ok(neighbors)
+}
+
+/// Connected components of neighbor lists, sorted by their smallest atom index.
+pub(crate) fn get_components(neighbors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let natoms = neighbors.len();
+    let mut visited = vec![false; natoms];
+    let mut components = vec![];
+    for start in 0..natoms {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut component = vec![];
+        let mut stack = vec![start];
+        while let Some(i) = stack.pop() {
+            component.push(i);
+            for &j in &neighbors[i] {
+                if !visited[j] {
+                    visited[j] = true;
+                    stack.push(j);
+                }
+            }
+        }
+        component.sort_unstable();
+        components.push(component);
+    }
+    components
+}
+
+/// Evaluate neighbor lists of bonded atoms (failable)
+pub fn get_connectivity_f(
+    structure: &DFTD4Structure,
+    scale: f64,
+) -> Result<Vec<Vec<usize>>, DFTD4Error> {
+    let lattice = structure.get_lattice();
+    let periodic = structure.get_periodic();
+    get_neighbors(
+        &structure.get_numbers(),
+        &structure.get_positions(),
+        lattice.as_deref(),
+        periodic.as_deref(),
+        scale,
+    )
+}
+
+/// Evaluate neighbor lists of bonded atoms
+///
+/// # Arguments
+///
+/// * `scale` - scaling factor of covalent radii (1.0 for the D4 coordination number)
+///
+/// # Returns
+///
+/// Sorted indices of bonded atoms [natoms][..]. In periodic systems, an atom can be bonded to its
+/// own image.
+pub fn get_connectivity(structure: &DFTD4Structure, scale: f64) -> Vec<Vec<usize>> {
+    get_connectivity_f(structure, scale).unwrap()
+}
+
+/// Detect fragments (disconnected molecules) (failable)
+pub fn get_fragments_f(
+    structure: &DFTD4Structure,
+    scale: f64,
+) -> Result<Vec<Vec<usize>>, DFTD4Error> {
+    Ok(get_components(&get_connectivity_f(structure, scale)?))
+}
+
+/// Detect fragments (disconnected molecules)
+///
+/// Fragments can be used in `get_fragment_dispersion`.
+///
+/// # Arguments
+///
+/// * `scale` - scaling factor of covalent radii (1.0 for the D4 coordination number)
+///
+/// # Returns
+///
+/// Sorted atom indices of fragments [nfrag][..], ordered by their first atom.
+pub fn get_fragments(structure: &DFTD4Structure, scale: f64) -> Vec<Vec<usize>> {
+    get_fragments_f(structure, scale).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fragments() {
+        // water dimer
+        let numbers = [8, 1, 1, 8, 1, 1];
+        #[rustfmt::skip]
+        let positions = [
+            -1.551007, -0.114520,  0.000000,  -1.934259,  0.762503,  0.000000,
+            -0.599677,  0.040712,  0.000000,   1.350625,  0.111469,  0.000000,
+             1.680398, -0.373741, -0.758561,   1.680398, -0.373741,  0.758561,
+        ];
+        let positions = positions.map(|x| x * crate::data::AATOAU);
+        let neighbors = get_neighbors(&numbers, &positions, None, None, 1.0).unwrap();
+        assert_eq!(neighbors[0], vec![1, 2]);
+        assert_eq!(neighbors[3], vec![4, 5]);
+        let fragments = get_components(&neighbors);
+        assert_eq!(fragments, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+        // H2 molecules split by cell boundary, periodic in x only
+        let numbers = [1, 1, 1, 1];
+        #[rustfmt::skip]
+        let positions = [
+            0.2, 0.0, 0.0,   9.8, 0.0, 0.0,
+            5.0, 0.0, 0.0,   5.0, 1.4, 0.0,
+        ];
+        #[rustfmt::skip]
+        let lattice = [
+            10.0,  0.0,  0.0,
+             0.0, 10.0,  0.0,
+             0.0,  0.0, 10.0,
+        ];
+        let periodic = [true, false, false];
+        let neighbors =
+            get_neighbors(&numbers, &positions, Some(&lattice), Some(&periodic), 1.0).unwrap();
+        let fragments = get_components(&neighbors);
+        assert_eq!(fragments, vec![vec![0, 1], vec![2, 3]]);
+        // without periodicity, atoms 0 and 1 are far apart
+        let neighbors = get_neighbors(&numbers, &positions, None, None, 1.0).unwrap();
+        assert_eq!(get_components(&neighbors).len(), 3);
+    }
+}
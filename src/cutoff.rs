@@ -0,0 +1,87 @@
+//! Real-space cutoffs: lattice images and convergence of the dispersion energy.
+//!
+//! Cutoffs are attached to the dispersion model ([`DFTD4Model::set_cutoff`]). For large periodic
+//! cells, [`get_cutoff_images`] reports the number of lattice images that each cutoff implies,
+//! and [`get_cutoff_convergence`] evaluates the energy at increasing cutoffs.
+
+use crate::lattice::get_lattice_points;
+use crate::library::*;
+
+/// Number of lattice images for coordination numbers, two-body and three-body dispersion
+///
+/// Images are all translations in periodic directions that bring atoms within the cutoff of each
+/// other (including the zero translation), i.e. 1 for molecules.
+pub fn get_cutoff_images(structure: &DFTD4Structure, cutoff: &DFTD4Cutoff) -> [usize; 3] {
+    let lattice = structure.get_lattice();
+    let periodic = structure.get_periodic().unwrap_or(vec![true; 3]);
+    let positions = structure.get_positions();
+    [cutoff.cn, cutoff.disp2, cutoff.disp3]
+        .map(|c| get_lattice_points(lattice.as_deref(), &periodic, &positions, c).len())
+}
+
+/// Evaluate dispersion energy at scaled cutoffs (failable)
+pub fn get_cutoff_convergence_f(
+    structure: &DFTD4Structure,
+    model: &mut DFTD4Model,
+    param: &DFTD4Param,
+    scales: &[f64],
+) -> Result<Vec<f64>, DFTD4Error> {
+    let cutoff = model.get_cutoff();
+    let energies = scales
+        .iter()
+        .map(|&scale| {
+            model.set_cutoff_f(DFTD4Cutoff {
+                cn: scale * cutoff.cn,
+                disp2: scale * cutoff.disp2,
+                disp3: scale * cutoff.disp3,
+            })?;
+            get_dispersion_f(structure, model, param, false, false).map(|(e, _, _)| e)
+        })
+        .collect::<Result<Vec<f64>, DFTD4Error>>();
+    model.set_cutoff_f(cutoff)?;
+    energies
+}
+
+/// Evaluate dispersion energy at scaled cutoffs
+///
+/// All cutoffs of the model are scaled by each factor in turn, and restored on return.
+///
+/// # Arguments
+///
+/// * `scales` - scaling factors of cutoffs [nscales], e.g. increasing from 1.0
+///
+/// # Returns
+///
+/// Dispersion energies [nscales].
+pub fn get_cutoff_convergence(
+    structure: &DFTD4Structure,
+    model: &mut DFTD4Model,
+    param: &DFTD4Param,
+    scales: &[f64],
+) -> Vec<f64> {
+    get_cutoff_convergence_f(structure, model, param, scales).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lattice_images() {
+        // lattice points within cutoff of a single atom, in periodic directions only
+        #[rustfmt::skip]
+        let lattice = [
+            10.0,  0.0,  0.0,
+             0.0, 10.0,  0.0,
+             0.0,  0.0, 20.0,
+        ];
+        let cutoff = DFTD4Cutoff::default();
+        let count = |periodic: &[bool], c: f64| {
+            get_lattice_points(Some(&lattice), periodic, &[0.0; 3], c).len()
+        };
+        assert_eq!(count(&[true; 3], cutoff.cn), 71);
+        assert_eq!(count(&[true; 3], cutoff.disp2), 455);
+        assert_eq!(count(&[true, true, false], cutoff.disp3), 49);
+        assert_eq!(count(&[false; 3], cutoff.disp2), 1);
+    }
+}
@@ -0,0 +1,120 @@
+//! Element data shared by the Rust implementations in this crate.
+
+/// Conversion factor from Angstrom to Bohr
+pub const AATOAU: f64 = 1.0 / 0.529177210903;
+
+/// Covalent radii in Angstrom (P. Pyykkö, M. Atsumi, Chem. Eur. J. 2009, 15, 188-197)
+#[rustfmt::skip]
+pub const COVALENT_RAD_2009: [f64; 118] = [
+    0.32, 0.46,                                                             // H, He
+    1.33, 1.02, 0.85, 0.75, 0.71, 0.63, 0.64, 0.67,                         // Li-Ne
+    1.55, 1.39, 1.26, 1.16, 1.11, 1.03, 0.99, 0.96,                         // Na-Ar
+    1.96, 1.71,                                                             // K, Ca
+    1.48, 1.36, 1.34, 1.22, 1.19, 1.16, 1.11, 1.10, 1.12, 1.18,             // Sc-Zn
+    1.24, 1.21, 1.21, 1.16, 1.14, 1.17,                                     // Ga-Kr
+    2.10, 1.85,                                                             // Rb, Sr
+    1.63, 1.54, 1.47, 1.38, 1.28, 1.25, 1.25, 1.20, 1.28, 1.36,             // Y-Cd
+    1.42, 1.40, 1.40, 1.36, 1.33, 1.31,                                     // In-Xe
+    2.32, 1.96,                                                             // Cs, Ba
+    1.80, 1.63, 1.76, 1.74, 1.73, 1.72, 1.68, 1.69, 1.68, 1.67, 1.66, 1.65, 1.64, 1.70, // La-Yb
+    1.62, 1.52, 1.46, 1.37, 1.31, 1.29, 1.22, 1.23, 1.24, 1.33,             // Lu-Hg
+    1.44, 1.44, 1.51, 1.45, 1.47, 1.42,                                     // Tl-Rn
+    2.23, 2.01,                                                             // Fr, Ra
+    1.86, 1.75, 1.69, 1.70, 1.71, 1.72, 1.66, 1.66, 1.68, 1.68, 1.65, 1.67, 1.73, 1.76, // Ac-No
+    1.61, 1.57, 1.49, 1.43, 1.41, 1.34, 1.29, 1.28, 1.21, 1.22,             // Lr-Cn
+    1.36, 1.43, 1.62, 1.75, 1.65, 1.57,                                     // Nh-Og
+];
+
+/// Get the covalent radius of D3 (scaled Pyykkö radii, in Bohr) for an atomic number
+pub fn get_covalent_rad(number: usize) -> Option<f64> {
+    match number {
+        1..=118 => Some(COVALENT_RAD_2009[number - 1] * AATOAU * 4.0 / 3.0),
+        _ => None,
+    }
+}
+
+/// Conversion factor from atomic mass unit to electron mass
+pub const AMUTOAU: f64 = 1822.888486209;
+
+/// Conversion factor from Hartree to wavenumber (cm^-1)
+pub const AUTORCM: f64 = 219474.6313632;
+
+/// Element symbols
+#[rustfmt::skip]
+pub const ELEMENT_SYMBOLS: [&str; 118] = [
+    "H", "He",
+    "Li", "Be", "B", "C", "N", "O", "F", "Ne",
+    "Na", "Mg", "Al", "Si", "P", "S", "Cl", "Ar",
+    "K", "Ca",
+    "Sc", "Ti", "V", "Cr", "Mn", "Fe", "Co", "Ni", "Cu", "Zn",
+    "Ga", "Ge", "As", "Se", "Br", "Kr",
+    "Rb", "Sr",
+    "Y", "Zr", "Nb", "Mo", "Tc", "Ru", "Rh", "Pd", "Ag", "Cd",
+    "In", "Sn", "Sb", "Te", "I", "Xe",
+    "Cs", "Ba",
+    "La", "Ce", "Pr", "Nd", "Pm", "Sm", "Eu", "Gd", "Tb", "Dy", "Ho", "Er", "Tm", "Yb",
+    "Lu", "Hf", "Ta", "W", "Re", "Os", "Ir", "Pt", "Au", "Hg",
+    "Tl", "Pb", "Bi", "Po", "At", "Rn",
+    "Fr", "Ra",
+    "Ac", "Th", "Pa", "U", "Np", "Pu", "Am", "Cm", "Bk", "Cf", "Es", "Fm", "Md", "No",
+    "Lr", "Rf", "Db", "Sg", "Bh", "Hs", "Mt", "Ds", "Rg", "Cn",
+    "Nh", "Fl", "Mc", "Lv", "Ts", "Og",
+];
+
+/// Standard atomic weights in atomic mass units (IUPAC conventional values; mass number of the
+/// most stable isotope for elements without stable isotopes)
+#[rustfmt::skip]
+pub const ATOMIC_MASS: [f64; 118] = [
+    1.008, 4.0026,                                                                    // H, He
+    6.94, 9.0122, 10.81, 12.011, 14.007, 15.999, 18.998, 20.180,                      // Li-Ne
+    22.990, 24.305, 26.982, 28.085, 30.974, 32.06, 35.45, 39.948,                     // Na-Ar
+    39.098, 40.078,                                                                   // K, Ca
+    44.956, 47.867, 50.942, 51.996, 54.938, 55.845, 58.933, 58.693, 63.546, 65.38,    // Sc-Zn
+    69.723, 72.630, 74.922, 78.971, 79.904, 83.798,                                   // Ga-Kr
+    85.468, 87.62,                                                                    // Rb, Sr
+    88.906, 91.224, 92.906, 95.95, 98.0, 101.07, 102.91, 106.42, 107.87, 112.41,      // Y-Cd
+    114.82, 118.71, 121.76, 127.60, 126.90, 131.29,                                   // In-Xe
+    132.91, 137.33,                                                                   // Cs, Ba
+    138.91, 140.12, 140.91, 144.24, 145.0, 150.36, 151.96, 157.25, 158.93, 162.50,
+    164.93, 167.26, 168.93, 173.05,                                                   // La-Yb
+    174.97, 178.49, 180.95, 183.84, 186.21, 190.23, 192.22, 195.08, 196.97, 200.59,   // Lu-Hg
+    204.38, 207.2, 208.98, 209.0, 210.0, 222.0,                                       // Tl-Rn
+    223.0, 226.0,                                                                     // Fr, Ra
+    227.0, 232.04, 231.04, 238.03, 237.0, 244.0, 243.0, 247.0, 247.0, 251.0,
+    252.0, 257.0, 258.0, 259.0,                                                       // Ac-No
+    266.0, 267.0, 268.0, 269.0, 270.0, 277.0, 278.0, 281.0, 282.0, 285.0,             // Lr-Cn
+    286.0, 289.0, 290.0, 293.0, 294.0, 294.0,                                         // Nh-Og
+];
+
+/// Get the element symbol for an atomic number
+pub fn get_element_symbol(number: usize) -> Option<&'static str> {
+    match number {
+        1..=118 => Some(ELEMENT_SYMBOLS[number - 1]),
+        _ => None,
+    }
+}
+
+/// Get the atomic number for an element symbol (case-insensitive)
+pub fn get_atomic_number(symbol: &str) -> Option<usize> {
+    ELEMENT_SYMBOLS
+        .iter()
+        .position(|s| s.eq_ignore_ascii_case(symbol))
+        .map(|i| i + 1)
+}
+
+/// Get the standard atomic weight (in atomic mass units) for an atomic number
+pub fn get_atomic_mass(number: usize) -> Option<f64> {
+    match number {
+        1..=118 => Some(ATOMIC_MASS[number - 1]),
+        _ => None,
+    }
+}
+
+/// Conversion factor from atomic unit of time to femtosecond
+pub const AUTOFS: f64 = 2.4188843265857e-2;
+
+/// Conversion factor from Hartree to kcal/mol
+pub const AUTOKCAL: f64 = 627.5094740631;
+
+/// Boltzmann constant in Hartree/K
+pub const BOLTZMANN: f64 = 3.166811563e-6;
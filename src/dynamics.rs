@@ -0,0 +1,526 @@
+//! Molecular dynamics driven by D4 forces.
+//!
+//! Velocity Verlet integration with optional Berendsen or Langevin thermostat. As in the
+//! optimizer, forces come from a pluggable energy function that returns energy and gradient;
+//! [`run_structure_md_f`] runs dynamics of a [`DFTD4Structure`] with D4 dispersion plus an
+//! optional user-defined energy term. Positions are wrapped into the cell in periodic directions
+//! if a lattice is given.
+//!
+//! Positions are in Bohr, velocities in Bohr per atomic unit of time, masses in atomic mass units;
+//! time step and time of trajectory frames are in femtoseconds.
+
+use crate::data::{get_element_symbol, AATOAU, AMUTOAU, AUTOFS, BOLTZMANN};
+use crate::lattice::{validate_lattice_f, wrap_positions_f};
+use crate::library::*;
+use crate::optimizer::ExtraFn;
+
+/// Thermostat of molecular dynamics
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Thermostat {
+    /// Microcanonical (NVE) dynamics
+    None,
+    /// Berendsen velocity rescaling, with target temperature (K) and coupling time (fs)
+    Berendsen { temperature: f64, tau: f64 },
+    /// Langevin dynamics, with target temperature (K) and friction coefficient (1/fs)
+    Langevin { temperature: f64, friction: f64 },
+}
+
+/// Settings of molecular dynamics
+#[derive(Clone, Debug)]
+pub struct MDConfig {
+    /// time step (fs)
+    pub timestep: f64,
+    /// number of steps
+    pub nsteps: usize,
+    pub thermostat: Thermostat,
+    /// interval of steps between trajectory frames (0 for no trajectory)
+    pub trajectory_interval: usize,
+    /// seed of random numbers (Langevin thermostat)
+    pub seed: u64,
+}
+
+impl Default for MDConfig {
+    fn default() -> Self {
+        Self {
+            timestep: 1.0,
+            nsteps: 1000,
+            thermostat: Thermostat::None,
+            trajectory_interval: 10,
+            seed: 42,
+        }
+    }
+}
+
+/// Frame of molecular dynamics trajectory
+#[derive(Clone, Debug)]
+pub struct MDFrame {
+    /// step number
+    pub step: usize,
+    /// time (fs)
+    pub time: f64,
+    /// positions [natoms][3]
+    pub positions: Vec<f64>,
+    /// velocities [natoms][3]
+    pub velocities: Vec<f64>,
+    /// potential energy
+    pub potential: f64,
+    /// kinetic energy
+    pub kinetic: f64,
+    /// instantaneous temperature (K)
+    pub temperature: f64,
+}
+
+/// Result of molecular dynamics
+#[derive(Clone, Debug)]
+pub struct MDResult {
+    /// final positions [natoms][3]
+    pub positions: Vec<f64>,
+    /// final velocities [natoms][3]
+    pub velocities: Vec<f64>,
+    /// trajectory frames, including the initial and final frames
+    pub trajectory: Vec<MDFrame>,
+}
+
+/// Random number generator (splitmix64) with normal distribution by Box-Muller transform.
+struct Random {
+    state: u64,
+    spare: Option<f64>,
+}
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            spare: None,
+        }
+    }
+
+    /// Uniform random number in (0, 1]
+    fn uniform(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    }
+
+    /// Standard normal random number
+    fn normal(&mut self) -> f64 {
+        if let Some(x) = self.spare.take() {
+            return x;
+        }
+        let r = (-2.0 * self.uniform().ln()).sqrt();
+        let phi = 2.0 * std::f64::consts::PI * self.uniform();
+        self.spare = Some(r * phi.sin());
+        r * phi.cos()
+    }
+}
+
+/// Number of degrees of freedom; center-of-mass motion is conserved for more than one atom,
+/// except with Langevin thermostat
+fn get_ndof(natoms: usize, thermostat: &Thermostat) -> f64 {
+    match (natoms, thermostat) {
+        (1, _) | (_, Thermostat::Langevin { .. }) => 3.0 * natoms as f64,
+        _ => 3.0 * natoms as f64 - 3.0,
+    }
+}
+
+/// Kinetic energy and temperature (K)
+fn get_kinetic(velocities: &[f64], masses: &[f64], ndof: f64) -> (f64, f64) {
+    let kinetic = velocities
+        .chunks(3)
+        .zip(masses)
+        .map(|(v, m)| 0.5 * m * AMUTOAU * v.iter().map(|x| x * x).sum::<f64>())
+        .sum::<f64>();
+    (kinetic, 2.0 * kinetic / (ndof * BOLTZMANN))
+}
+
+/// Generate Maxwell-Boltzmann velocities [natoms][3] at temperature (K)
+///
+/// Center-of-mass motion is removed, and velocities are rescaled to the exact temperature.
+pub fn get_initial_velocities(masses: &[f64], temperature: f64, seed: u64) -> Vec<f64> {
+    let natoms = masses.len();
+    let mut random = Random::new(seed);
+    let mut velocities = (0..3 * natoms)
+        .map(|k| (BOLTZMANN * temperature / (masses[k / 3] * AMUTOAU)).sqrt() * random.normal())
+        .collect::<Vec<f64>>();
+    if natoms > 1 {
+        let total = masses.iter().sum::<f64>();
+        let vcom = [0, 1, 2].map(|k| {
+            (0..natoms)
+                .map(|i| masses[i] * velocities[3 * i + k])
+                .sum::<f64>()
+                / total
+        });
+        velocities
+            .iter_mut()
+            .enumerate()
+            .for_each(|(k, v)| *v -= vcom[k % 3]);
+    }
+    let ndof = get_ndof(natoms, &Thermostat::None);
+    let (_, current) = get_kinetic(&velocities, masses, ndof);
+    if current > 0.0 {
+        let scale = (temperature / current).sqrt();
+        velocities.iter_mut().for_each(|v| *v *= scale);
+    }
+    velocities
+}
+
+/// Run molecular dynamics with a user-defined energy function (failable)
+///
+/// # Arguments
+///
+/// * `positions` - initial positions [natoms][3]
+/// * `velocities` - initial velocities [natoms][3]; zero if `None`
+/// * `masses` - atomic masses in atomic mass units [natoms]
+/// * `lattice` - lattice [3][3], positions are wrapped into the cell if given
+/// * `periodic` - periodic directions [3]; all directions if `None`
+/// * `energy_fn` - function of positions [natoms][3], returning energy and gradient [natoms][3]
+pub fn run_md_f<F>(
+    positions: &[f64],
+    velocities: Option<&[f64]>,
+    masses: &[f64],
+    lattice: Option<&[f64]>,
+    periodic: Option<&[bool]>,
+    config: &MDConfig,
+    mut energy_fn: F,
+) -> Result<MDResult, DFTD4Error>
+where
+    F: FnMut(&[f64]) -> Result<(f64, Vec<f64>), DFTD4Error>,
+{
+    let natoms = masses.len();
+    if positions.len() != 3 * natoms {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for positions, expected {}, got {}",
+            3 * natoms,
+            positions.len()
+        )));
+    }
+    if velocities.is_some_and(|v| v.len() != 3 * natoms) {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for velocities, expected {}, got {}",
+            3 * natoms,
+            velocities.unwrap().len()
+        )));
+    }
+    if let Some(m) = masses.iter().find(|&&m| m <= 0.0) {
+        return Err(DFTD4Error::Rust(format!("Invalid atomic mass {}", m)));
+    }
+    let dt = config.timestep / AUTOFS;
+    if let Some(lattice) = lattice {
+        validate_lattice_f(lattice, periodic)?;
+    }
+    let mass = (0..3 * natoms)
+        .map(|k| masses[k / 3] * AMUTOAU)
+        .collect::<Vec<f64>>();
+    let ndof = get_ndof(natoms, &config.thermostat);
+    let mut random = Random::new(config.seed);
+
+    let mut x = match lattice {
+        Some(lattice) => wrap_positions_f(positions, lattice, periodic)?,
+        None => positions.to_vec(),
+    };
+    let mut v = velocities.map_or(vec![0.0; 3 * natoms], |v| v.to_vec());
+    let (mut potential, mut gradient) = energy_fn(&x)?;
+
+    let mut trajectory = vec![];
+    let mut record = |step: usize, x: &[f64], v: &[f64], potential: f64| {
+        let (kinetic, temperature) = get_kinetic(v, masses, ndof);
+        trajectory.push(MDFrame {
+            step,
+            time: step as f64 * config.timestep,
+            positions: x.to_vec(),
+            velocities: v.to_vec(),
+            potential,
+            kinetic,
+            temperature,
+        });
+    };
+    if config.trajectory_interval > 0 {
+        record(0, &x, &v, potential);
+    }
+
+    // Langevin thermostat is applied in two half steps around velocity Verlet (OBABO)
+    let mut langevin = |v: &mut [f64]| {
+        if let Thermostat::Langevin {
+            temperature,
+            friction,
+        } = config.thermostat
+        {
+            let c1 = (-0.5 * friction * config.timestep).exp();
+            let c2 = (1.0 - c1 * c1).sqrt();
+            for (vk, mk) in v.iter_mut().zip(&mass) {
+                *vk = c1 * *vk + c2 * (BOLTZMANN * temperature / mk).sqrt() * random.normal();
+            }
+        }
+    };
+
+    for step in 1..=config.nsteps {
+        langevin(&mut v);
+        for k in 0..3 * natoms {
+            v[k] -= 0.5 * dt * gradient[k] / mass[k];
+            x[k] += dt * v[k];
+        }
+        if let Some(lattice) = lattice {
+            x = wrap_positions_f(&x, lattice, periodic)?;
+        }
+        (potential, gradient) = energy_fn(&x)?;
+        for k in 0..3 * natoms {
+            v[k] -= 0.5 * dt * gradient[k] / mass[k];
+        }
+        langevin(&mut v);
+        if let Thermostat::Berendsen { temperature, tau } = config.thermostat {
+            let (_, current) = get_kinetic(&v, masses, ndof);
+            if current > 0.0 {
+                let scale = (1.0 + config.timestep / tau * (temperature / current - 1.0))
+                    .max(0.0)
+                    .sqrt();
+                v.iter_mut().for_each(|vk| *vk *= scale);
+            }
+        }
+        let last = step == config.nsteps;
+        if config.trajectory_interval > 0 && (step % config.trajectory_interval == 0 || last) {
+            record(step, &x, &v, potential);
+        }
+    }
+    Ok(MDResult {
+        positions: x,
+        velocities: v,
+        trajectory,
+    })
+}
+
+/// Run molecular dynamics of structure with D4 dispersion plus an optional user-defined energy
+/// (failable)
+///
+/// The structure is updated in place by `DFTD4Structure::update_f`, and holds the final positions
+/// on return. Lattice and periodicity of the structure are used for wrapping.
+///
+/// # Arguments
+///
+/// * `velocities` - initial velocities [natoms][3]; zero if `None`, see [`get_initial_velocities`]
+/// * `masses` - atomic masses in atomic mass units [natoms]; standard atomic weights if `None`
+/// * `extra_fn` - additional energy term: function of positions [natoms][3], returning energy and
+///   gradient [natoms][3]
+pub fn run_structure_md_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    velocities: Option<&[f64]>,
+    masses: Option<&[f64]>,
+    config: &MDConfig,
+    mut extra_fn: Option<&mut ExtraFn>,
+) -> Result<MDResult, DFTD4Error> {
+    let masses = match masses {
+        Some(masses) => masses.to_vec(),
+        None => crate::vibration::get_masses_f(&structure.get_numbers())?,
+    };
+    let lattice = structure.get_lattice();
+    let periodic = structure.get_periodic();
+    let result = run_md_f(
+        &structure.get_positions(),
+        velocities,
+        &masses,
+        lattice.as_deref(),
+        periodic.as_deref(),
+        config,
+        |x| {
+            structure.update_f(x, None)?;
+            let (mut energy, gradient, _) = get_dispersion_f(structure, model, param, true, false)?;
+            let mut gradient = gradient.unwrap();
+            if let Some(extra_fn) = extra_fn.as_mut() {
+                let (extra_energy, extra_gradient) = extra_fn(x)?;
+                energy += extra_energy;
+                gradient
+                    .iter_mut()
+                    .zip(&extra_gradient)
+                    .for_each(|(g, e)| *g += e);
+            }
+            Ok((energy, gradient))
+        },
+    )?;
+    structure.update_f(&result.positions, None)?;
+    Ok(result)
+}
+
+/// Run molecular dynamics of structure with D4 dispersion plus an optional user-defined energy
+pub fn run_structure_md(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    velocities: Option<&[f64]>,
+    masses: Option<&[f64]>,
+    config: &MDConfig,
+    extra_fn: Option<&mut ExtraFn>,
+) -> MDResult {
+    run_structure_md_f(
+        structure, model, param, velocities, masses, config, extra_fn,
+    )
+    .unwrap()
+}
+
+/// Format trajectory as multi-frame XYZ (positions in Angstrom) (failable)
+pub fn get_xyz_trajectory_f(
+    numbers: &[usize],
+    trajectory: &[MDFrame],
+    multiplicity: Option<usize>,
+) -> Result<String, DFTD4Error> {
+    let symbols = numbers
+        .iter()
+        .map(|&z| {
+            get_element_symbol(z)
+                .ok_or_else(|| DFTD4Error::Rust(format!("Unsupported atomic number {}", z)))
+        })
+        .collect::<Result<Vec<&str>, DFTD4Error>>()?;
+    let mut xyz = String::new();
+    for frame in trajectory {
+        xyz += &format!("{}\n", numbers.len());
+        xyz += &format!(
+            "step {} time {:.3} fs energy {:.10} temperature {:.3}",
+            frame.step,
+            frame.time,
+            frame.potential + frame.kinetic,
+            frame.temperature
+        );
+        match multiplicity {
+            Some(multiplicity) => xyz += &format!(" multiplicity {}\n", multiplicity),
+            None => xyz += "\n",
+        }
+        for (symbol, r) in symbols.iter().zip(frame.positions.chunks(3)) {
+            let r = r.iter().map(|x| x / AATOAU).collect::<Vec<f64>>();
+            xyz += &format!("{:<3}{:16.8}{:16.8}{:16.8}\n", symbol, r[0], r[1], r[2]);
+        }
+    }
+    Ok(xyz)
+}
+
+/// Format trajectory as multi-frame XYZ (positions in Angstrom)
+///
+/// The comment line of each frame holds step, time, total energy and temperature, followed by the
+/// spin multiplicity if given (e.g. from `DFTD4Structure::get_multiplicity`).
+pub fn get_xyz_trajectory(
+    numbers: &[usize],
+    trajectory: &[MDFrame],
+    multiplicity: Option<usize>,
+) -> String {
+    get_xyz_trajectory_f(numbers, trajectory, multiplicity).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lennard-Jones energy and gradient of argon (epsilon = 4.5e-4, sigma = 6.4)
+    fn argon(x: &[f64]) -> Result<(f64, Vec<f64>), DFTD4Error> {
+        let (eps, sig) = (4.5e-4, 6.4);
+        let natoms = x.len() / 3;
+        let mut energy = 0.0;
+        let mut gradient = vec![0.0; x.len()];
+        for i in 0..natoms {
+            for j in 0..i {
+                let v = [0, 1, 2].map(|k| x[3 * i + k] - x[3 * j + k]);
+                let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                let s6 = (sig * sig / r2).powi(3);
+                energy += 4.0 * eps * (s6 * s6 - s6);
+                let de = 4.0 * eps * (-12.0 * s6 * s6 + 6.0 * s6) / r2;
+                for k in 0..3 {
+                    gradient[3 * i + k] += de * v[k];
+                    gradient[3 * j + k] -= de * v[k];
+                }
+            }
+        }
+        Ok((energy, gradient))
+    }
+
+    #[test]
+    fn test_md() {
+        // argon tetramer
+        #[rustfmt::skip]
+        let positions = [
+            0.0, 0.0, 0.0,  7.2, 0.0, 0.0,
+            3.6, 6.2, 0.0,  3.6, 2.1, 5.9,
+        ];
+        let masses = [39.948; 4];
+        let velocities = get_initial_velocities(&masses, 30.0, 1);
+        let (_, temperature) = get_kinetic(&velocities, &masses, 9.0);
+        assert!((temperature - 30.0).abs() < 1e-10);
+
+        // energy conservation of NVE
+        let config = MDConfig {
+            timestep: 2.0,
+            nsteps: 2000,
+            trajectory_interval: 100,
+            ..Default::default()
+        };
+        let result = run_md_f(
+            &positions,
+            Some(&velocities),
+            &masses,
+            None,
+            None,
+            &config,
+            argon,
+        )
+        .unwrap();
+        assert_eq!(result.trajectory.len(), 21);
+        let total = |f: &MDFrame| f.potential + f.kinetic;
+        let e0 = total(&result.trajectory[0]);
+        for frame in &result.trajectory {
+            assert!((total(frame) - e0).abs() < 1e-7);
+        }
+
+        // thermostats drive temperature towards target
+        for thermostat in [
+            Thermostat::Berendsen {
+                temperature: 10.0,
+                tau: 50.0,
+            },
+            Thermostat::Langevin {
+                temperature: 10.0,
+                friction: 0.02,
+            },
+        ] {
+            let config = MDConfig {
+                nsteps: 5000,
+                thermostat,
+                ..config.clone()
+            };
+            let result = run_md_f(
+                &positions,
+                Some(&velocities),
+                &masses,
+                None,
+                None,
+                &config,
+                argon,
+            )
+            .unwrap();
+            let frames = &result.trajectory[20..];
+            let average = frames.iter().map(|f| f.temperature).sum::<f64>() / frames.len() as f64;
+            assert!((average - 10.0).abs() < 3.0);
+        }
+
+        // positions are wrapped into the cell
+        let lattice = [20.0, 0.0, 0.0, 0.0, 20.0, 0.0, 0.0, 0.0, 20.0];
+        let shifted = positions.map(|x| x - 1.0);
+        let config = MDConfig {
+            nsteps: 10,
+            ..config.clone()
+        };
+        let result = run_md_f(
+            &shifted,
+            None,
+            &masses,
+            Some(&lattice),
+            None,
+            &config,
+            argon,
+        )
+        .unwrap();
+        assert!(result.positions.iter().all(|&x| (0.0..20.0).contains(&x)));
+        let xyz = get_xyz_trajectory(&[18; 4], &result.trajectory, Some(1));
+        assert!(xyz.starts_with("4\nstep 0 time 0.000 fs"));
+        assert!(xyz.lines().nth(1).unwrap().ends_with(" multiplicity 1"));
+    }
+}
@@ -0,0 +1,526 @@
+//! Electronegativity equilibration (EEQ) charges in pure Rust.
+//!
+//! This follows the EEQ model of `multicharge` (EEQ 2019), which is also the charge model used by
+//! DFT-D4. Parameters are shipped in `param::eeq2019`.
+
+use crate::data::get_covalent_rad;
+use crate::lattice::get_lattice_points;
+use crate::library::DFTD4Error;
+use crate::math::{det3, erf, inv3, LU};
+use crate::param::eeq2019;
+use std::f64::consts::PI;
+
+/// Steepness of the error function counting function
+const CN_KCN: f64 = 7.5;
+/// Real-space cutoff for the coordination number (Bohr)
+const CN_CUTOFF: f64 = 25.0;
+/// Maximum coordination number
+const CN_MAX: f64 = 8.0;
+/// Regularization of the square root in the coordination number dependence
+const REG: f64 = 1e-14;
+
+/// Derivatives with respect to positions [natoms][natoms][3] and strain [natoms][3][3]
+pub(crate) type GeometryDerivs = (Vec<f64>, Vec<f64>);
+
+/// Evaluate the error function based coordination number, capped at `CN_MAX`.
+///
+/// Returns coordination numbers [natoms] and optionally derivatives with respect to positions
+/// [natoms][natoms][3] and strain [natoms][3][3], where `dcndr[i][j][k]` is the derivative of
+/// `cn[i]` with respect to coordinate `k` of atom `j`. Images of atoms are given by lattice
+/// translations `trans` [ntrans][3].
+pub(crate) fn get_coordination_number(
+    numbers: &[usize],
+    positions: &[f64],
+    trans: &[[f64; 3]],
+    eval_grad: bool,
+) -> (Vec<f64>, Option<GeometryDerivs>) {
+    let natoms = numbers.len();
+    let cutoff2 = CN_CUTOFF * CN_CUTOFF;
+    let mut cn = vec![0.0; natoms];
+    let mut derivs = match eval_grad {
+        true => Some((vec![0.0; natoms * natoms * 3], vec![0.0; natoms * 9])),
+        false => None,
+    };
+    for i in 0..natoms {
+        for j in 0..=i {
+            // images of an atom count once to its own coordination number
+            let scale = if i == j { 0.5 } else { 1.0 };
+            let rc = get_covalent_rad(numbers[i]).unwrap() + get_covalent_rad(numbers[j]).unwrap();
+            for t in trans {
+                let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k] - t[k]);
+                let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                if r2 > cutoff2 || r2 < f64::EPSILON {
+                    continue;
+                }
+                let r = r2.sqrt();
+                let arg = -CN_KCN * (r - rc) / rc;
+                let count = scale * 0.5 * (1.0 + erf(arg));
+                cn[i] += count;
+                cn[j] += count;
+                if let Some((dcndr, dcnde)) = derivs.as_mut() {
+                    let dcount = -scale * CN_KCN / (rc * PI.sqrt()) * (-arg * arg).exp();
+                    for k in 0..3 {
+                        let d = dcount * v[k] / r;
+                        dcndr[(i * natoms + i) * 3 + k] += d;
+                        dcndr[(i * natoms + j) * 3 + k] -= d;
+                        dcndr[(j * natoms + j) * 3 + k] -= d;
+                        dcndr[(j * natoms + i) * 3 + k] += d;
+                    }
+                    for a in 0..3 {
+                        for b in 0..3 {
+                            let s = dcount * v[a] * v[b] / r;
+                            dcnde[9 * i + 3 * a + b] += s;
+                            dcnde[9 * j + 3 * a + b] += s;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // smooth cap of the coordination number
+    for i in 0..natoms {
+        let dcut = 1.0 / (1.0 + (cn[i] - CN_MAX).exp());
+        cn[i] = (1.0 + CN_MAX.exp()).ln() - (1.0 + (CN_MAX - cn[i]).exp()).ln();
+        if let Some((dcndr, dcnde)) = derivs.as_mut() {
+            dcndr[i * natoms * 3..(i + 1) * natoms * 3]
+                .iter_mut()
+                .for_each(|x| *x *= dcut);
+            dcnde[9 * i..9 * i + 9].iter_mut().for_each(|x| *x *= dcut);
+        }
+    }
+    (cn, derivs)
+}
+
+/// Lattice sums of the Coulomb interaction of gaussian charges
+///
+/// For periodic structures, the interaction is split by Ewald summation into real-space sums of
+/// `(erf(gam r) - erf(alpha r)) / r` over lattice translations, and reciprocal-space sums of point
+/// charges screened by `alpha`, over the full (3D) lattice. The constant term of the Ewald sum is
+/// dropped, as it does not change charges under the total charge constraint. For molecules,
+/// `alpha` is zero and only the zero translation contributes.
+struct CoulombSums {
+    alpha: f64,
+    /// real-space cutoff (Bohr)
+    cutoff: f64,
+    /// real-space translations [ntrans][3]
+    trans: Vec<[f64; 3]>,
+    /// reciprocal lattice vectors [ngvec][3], and their weights `4 pi / V exp(-G^2/4alpha^2) / G^2`
+    gvec: Vec<([f64; 3], f64)>,
+}
+
+impl CoulombSums {
+    /// Lattice sums of a structure, with `gam_min` the smallest width parameter of atom pairs
+    fn new(lattice: Option<&[f64]>, periodic: &[bool], positions: &[f64], gam_min: f64) -> Self {
+        let lattice = lattice.filter(|_| periodic.iter().any(|&p| p));
+        let Some((lattice, inv)) = lattice.and_then(|l| inv3(l).map(|inv| (l, inv))) else {
+            return Self {
+                alpha: 0.0,
+                cutoff: f64::INFINITY,
+                trans: vec![[0.0; 3]],
+                gvec: vec![],
+            };
+        };
+        let volume = det3(lattice).abs();
+        let alpha = PI.sqrt() / volume.cbrt();
+        // erfc(6) and exp(-36) are below machine precision relative to unity
+        let cutoff = 6.0 / alpha.min(gam_min);
+        let trans = get_lattice_points(Some(lattice), &[true; 3], positions, cutoff);
+        let gmax = 12.0 * alpha;
+        let nmax = [0, 1, 2].map(|k| {
+            let length = (0..3)
+                .map(|a| lattice[3 * k + a].powi(2))
+                .sum::<f64>()
+                .sqrt();
+            (gmax * length / (2.0 * PI)).ceil() as i32
+        });
+        let mut gvec = vec![];
+        for i in -nmax[0]..=nmax[0] {
+            for j in -nmax[1]..=nmax[1] {
+                for k in -nmax[2]..=nmax[2] {
+                    // reciprocal lattice vectors are 2 pi times the columns of the inverse lattice
+                    let n = [i as f64, j as f64, k as f64];
+                    let g = [0, 1, 2]
+                        .map(|a| 2.0 * PI * (0..3).map(|b| n[b] * inv[3 * a + b]).sum::<f64>());
+                    let g2 = g.iter().map(|x| x * x).sum::<f64>();
+                    if g2 < f64::EPSILON || g2 > gmax * gmax {
+                        continue;
+                    }
+                    let weight = 4.0 * PI / volume * (-g2 / (4.0 * alpha * alpha)).exp() / g2;
+                    gvec.push((g, weight));
+                }
+            }
+        }
+        Self {
+            alpha,
+            cutoff,
+            trans,
+            gvec,
+        }
+    }
+
+    /// Interaction of gaussian charges of width parameter `gam` at distance vector `d`, and
+    /// optionally its derivatives with respect to `d` [3] and strain [3][3]
+    ///
+    /// `diagonal` selects the interaction of an atom with its own images.
+    fn get_interaction(
+        &self,
+        d: [f64; 3],
+        gam: f64,
+        diagonal: bool,
+        eval_grad: bool,
+    ) -> (f64, [f64; 3], [f64; 9]) {
+        let alpha = self.alpha;
+        let (mut value, mut grad, mut sigma) = (0.0, [0.0; 3], [0.0; 9]);
+        for t in &self.trans {
+            let w = [0, 1, 2].map(|k| d[k] - t[k]);
+            let r2 = w.iter().map(|x| x * x).sum::<f64>();
+            if r2 < f64::EPSILON || r2 > self.cutoff * self.cutoff {
+                continue;
+            }
+            let r = r2.sqrt();
+            let val = (erf(gam * r) - erf(alpha * r)) / r;
+            value += val;
+            if eval_grad {
+                let dval = 2.0 / PI.sqrt()
+                    * (gam * (-(gam * r).powi(2)).exp() - alpha * (-(alpha * r).powi(2)).exp())
+                    / r
+                    - val / r;
+                for a in 0..3 {
+                    grad[a] += dval * w[a] / r;
+                    for b in 0..3 {
+                        sigma[3 * a + b] += dval * w[a] * w[b] / r;
+                    }
+                }
+            }
+        }
+        for (g, weight) in &self.gvec {
+            let gd = (0..3).map(|k| g[k] * d[k]).sum::<f64>();
+            let (sin, cos) = gd.sin_cos();
+            value += weight * cos;
+            if eval_grad {
+                let g2 = g.iter().map(|x| x * x).sum::<f64>();
+                let dg2 = 2.0 * (1.0 / (4.0 * alpha * alpha) + 1.0 / g2);
+                for a in 0..3 {
+                    grad[a] -= weight * sin * g[a];
+                    for b in 0..3 {
+                        let delta = if a == b { 1.0 } else { 0.0 };
+                        sigma[3 * a + b] += weight * cos * (dg2 * g[a] * g[b] - delta);
+                    }
+                }
+            }
+        }
+        if diagonal {
+            value -= 2.0 * alpha / PI.sqrt();
+        }
+        (value, grad, sigma)
+    }
+}
+
+/// Electronegativity equilibration charge model
+pub struct EEQModel {
+    chi: Vec<f64>,
+    eta: Vec<f64>,
+    kcn: Vec<f64>,
+    rad: Vec<f64>,
+}
+
+impl EEQModel {
+    /// Create new EEQ model with EEQ 2019 parameters (failable)
+    pub fn new_f() -> Result<Self, DFTD4Error> {
+        Self::custom_f(
+            eeq2019::EEQ_CHI,
+            eeq2019::EEQ_ETA,
+            eeq2019::EEQ_KCN,
+            eeq2019::EEQ_RAD,
+        )
+    }
+
+    /// Create new EEQ model with EEQ 2019 parameters
+    pub fn new() -> Self {
+        Self::new_f().unwrap()
+    }
+
+    /// Create new EEQ model with custom element parameters (failable)
+    ///
+    /// # Arguments
+    ///
+    /// * `chi` - electronegativities [nelem]
+    /// * `eta` - chemical hardnesses [nelem]
+    /// * `kcn` - coordination number scaling of electronegativities [nelem]
+    /// * `rad` - charge widths [nelem]
+    ///
+    /// Element parameters are indexed by atomic number minus one.
+    pub fn custom_f(
+        chi: &[f64],
+        eta: &[f64],
+        kcn: &[f64],
+        rad: &[f64],
+    ) -> Result<Self, DFTD4Error> {
+        if [eta.len(), kcn.len(), rad.len()]
+            .iter()
+            .any(|&n| n != chi.len())
+        {
+            return Err(DFTD4Error::Rust(format!(
+                "Inconsistent number of elements in EEQ parameters, got {}, {}, {}, {}",
+                chi.len(),
+                eta.len(),
+                kcn.len(),
+                rad.len()
+            )));
+        }
+        Ok(Self {
+            chi: chi.to_vec(),
+            eta: eta.to_vec(),
+            kcn: kcn.to_vec(),
+            rad: rad.to_vec(),
+        })
+    }
+
+    /// Create new EEQ model with custom element parameters
+    pub fn custom(chi: &[f64], eta: &[f64], kcn: &[f64], rad: &[f64]) -> Self {
+        Self::custom_f(chi, eta, kcn, rad).unwrap()
+    }
+
+    /// Get number of elements parametrized by this model
+    pub fn get_nelem(&self) -> usize {
+        self.chi.len()
+    }
+
+    /// Evaluate EEQ partial charges and their derivatives (quantities in Bohr) (failable)
+    ///
+    /// Returns charges [natoms], and optionally derivatives [natoms][natoms][3], where
+    /// `dqdr[i][j][k]` is the derivative of the charge of atom `i` with respect to coordinate `k`
+    /// of atom `j`. The charges sum up to the total charge (zero if `charge` is None).
+    pub fn get_charges_f(
+        &self,
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        eval_grad: bool,
+    ) -> Result<(Vec<f64>, Option<Vec<f64>>), DFTD4Error> {
+        // check dimension
+        if numbers.len() != natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for numbers, expected {}, got {}",
+                natoms,
+                numbers.len()
+            )));
+        }
+        if positions.len() != 3 * natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for positions, expected {}, got {}",
+                3 * natoms,
+                positions.len()
+            )));
+        }
+        let (q, derivs) =
+            self.get_charges_lattice_f(numbers, positions, None, &[false; 3], charge, eval_grad)?;
+        Ok((q, derivs.map(|(dqdr, _)| dqdr)))
+    }
+
+    /// Evaluate EEQ partial charges of a molecular or periodic structure (quantities in Bohr)
+    /// (failable)
+    ///
+    /// Returns charges [natoms], and optionally derivatives with respect to positions
+    /// [natoms][natoms][3] and strain [natoms][3][3]. Coordination numbers include lattice images
+    /// in periodic directions; the Coulomb interaction of periodic structures is summed over the
+    /// full lattice, i.e. 1D and 2D periodic structures are repeated along vacuum vectors.
+    pub(crate) fn get_charges_lattice_f(
+        &self,
+        numbers: &[usize],
+        positions: &[f64],
+        lattice: Option<&[f64]>,
+        periodic: &[bool],
+        charge: Option<f64>,
+        eval_grad: bool,
+    ) -> Result<(Vec<f64>, Option<GeometryDerivs>), DFTD4Error> {
+        if let Some(&number) = numbers.iter().find(|&&z| z == 0 || z > self.get_nelem()) {
+            return Err(DFTD4Error::Rust(format!(
+                "No EEQ parameters for atomic number {}",
+                number
+            )));
+        }
+
+        let n = numbers.len();
+        let m = n + 1;
+        let trans = get_lattice_points(lattice, periodic, positions, CN_CUTOFF);
+        let (cn, dcn) = get_coordination_number(numbers, positions, &trans, eval_grad);
+
+        // right-hand side: electronegativities and total charge constraint
+        let mut xvec = vec![0.0; m];
+        let mut dxdcn = vec![0.0; n];
+        for i in 0..n {
+            let z = numbers[i] - 1;
+            let tmp = self.kcn[z] / (cn[i] + REG).sqrt();
+            xvec[i] = -self.chi[z] + tmp * cn[i];
+            dxdcn[i] = 0.5 * tmp;
+        }
+        xvec[n] = charge.unwrap_or(0.0);
+
+        // coulomb matrix of gaussian charges, bordered by the charge constraint, and derivatives
+        // dadr[i][j] of A[i][j] with respect to positions of atom i, and dade[i][j] with respect
+        // to strain
+        let rad = |i: usize| self.rad[numbers[i] - 1];
+        let gam = |i: usize, j: usize| 1.0 / (rad(i).powi(2) + rad(j).powi(2)).sqrt();
+        let rad_max = (0..n).map(rad).fold(0.0, f64::max);
+        let sums = CoulombSums::new(
+            lattice,
+            periodic,
+            positions,
+            1.0 / (2.0f64.sqrt() * rad_max),
+        );
+        let mut amat = vec![0.0; m * m];
+        let mut dadr = vec![0.0; if eval_grad { n * n * 3 } else { 0 }];
+        let mut dade = vec![0.0; if eval_grad { n * n * 9 } else { 0 }];
+        for i in 0..n {
+            for j in 0..=i {
+                let d = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k]);
+                let (value, grad, sigma) = sums.get_interaction(d, gam(i, j), i == j, eval_grad);
+                amat[i * m + j] += value;
+                if i != j {
+                    amat[j * m + i] += value;
+                }
+                if eval_grad {
+                    for k in 0..3 {
+                        dadr[(i * n + j) * 3 + k] = grad[k];
+                        dadr[(j * n + i) * 3 + k] = -grad[k];
+                    }
+                    dade[(i * n + j) * 9..(i * n + j + 1) * 9].copy_from_slice(&sigma);
+                    dade[(j * n + i) * 9..(j * n + i + 1) * 9].copy_from_slice(&sigma);
+                }
+            }
+            amat[i * m + i] += self.eta[numbers[i] - 1] + (2.0 / PI).sqrt() / rad(i);
+            amat[i * m + n] = 1.0;
+            amat[n * m + i] = 1.0;
+        }
+        let lu =
+            LU::new(m, &amat).ok_or_else(|| DFTD4Error::Rust("Singular EEQ matrix".to_string()))?;
+        let qvec = lu.solve(&xvec);
+        let q = qvec[..n].to_vec();
+
+        // response of charges: A dq/dr = dx/dr - dA/dr q
+        let derivs = dcn.map(|(dcndr, dcnde)| {
+            let mut dqdr = vec![0.0; n * n * 3];
+            for c in 0..n {
+                for d in 0..3 {
+                    let mut rhs = vec![0.0; m];
+                    for l in 0..n {
+                        rhs[l] = dxdcn[l] * dcndr[(l * n + c) * 3 + d];
+                    }
+                    for l in (0..n).filter(|&l| l != c) {
+                        let dadx = dadr[(c * n + l) * 3 + d];
+                        rhs[c] -= dadx * q[l];
+                        rhs[l] -= dadx * q[c];
+                    }
+                    let sol = lu.solve(&rhs);
+                    for i in 0..n {
+                        dqdr[(i * n + c) * 3 + d] = sol[i];
+                    }
+                }
+            }
+            let mut dqde = vec![0.0; n * 9];
+            for ab in 0..9 {
+                let mut rhs = vec![0.0; m];
+                for i in 0..n {
+                    rhs[i] = dxdcn[i] * dcnde[9 * i + ab]
+                        - (0..n)
+                            .map(|j| dade[(i * n + j) * 9 + ab] * q[j])
+                            .sum::<f64>();
+                }
+                let sol = lu.solve(&rhs);
+                for i in 0..n {
+                    dqde[9 * i + ab] = sol[i];
+                }
+            }
+            (dqdr, dqde)
+        });
+        Ok((q, derivs))
+    }
+
+    /// Evaluate EEQ partial charges and their derivatives (quantities in Bohr)
+    ///
+    /// # Arguments
+    ///
+    /// * `numbers` - numbers [natoms]
+    /// * `positions` - positions [natoms][3]
+    /// * `charge` - total charge of the system
+    /// * `eval_grad` - whether to evaluate charge derivatives [natoms][natoms][3]
+    pub fn get_charges(
+        &self,
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        eval_grad: bool,
+    ) -> (Vec<f64>, Option<Vec<f64>>) {
+        self.get_charges_f(natoms, numbers, positions, charge, eval_grad)
+            .unwrap()
+    }
+}
+
+impl Default for EEQModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Evaluate EEQ 2019 partial charges and their derivatives (quantities in Bohr) (failable)
+pub fn get_eeq_charges_f(
+    natoms: usize,
+    numbers: &[usize],
+    positions: &[f64],
+    charge: Option<f64>,
+    eval_grad: bool,
+) -> Result<(Vec<f64>, Option<Vec<f64>>), DFTD4Error> {
+    EEQModel::new_f()?.get_charges_f(natoms, numbers, positions, charge, eval_grad)
+}
+
+/// Evaluate EEQ 2019 partial charges and their derivatives (quantities in Bohr)
+pub fn get_eeq_charges(
+    natoms: usize,
+    numbers: &[usize],
+    positions: &[f64],
+    charge: Option<f64>,
+    eval_grad: bool,
+) -> (Vec<f64>, Option<Vec<f64>>) {
+    get_eeq_charges_f(natoms, numbers, positions, charge, eval_grad).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charge_derivatives() {
+        // custom parameters for H to O, to check derivatives independent of upstream data
+        let model = EEQModel::custom(
+            &[1.2, 1.3, 0.5, 1.0, 1.3, 1.4, 1.6, 1.6],
+            &[-0.35, 1.0, 0.1, 0.1, 0.3, 0.2, 0.05, 0.03],
+            &[0.05, 0.1, -0.1, 0.0, 0.0, 0.06, 0.09, 0.12],
+            &[0.55, 0.66, 0.9, 1.5, 2.9, 1.9, 1.3, 1.2],
+        );
+        let numbers = [8, 1, 1];
+        #[rustfmt::skip]
+        let positions = [
+             0.00000000000000, 0.00000000000000, -0.73578586109551,
+             1.44183152868459, 0.00000000000000,  0.36789293054775,
+            -1.44183152868459, 0.20000000000000,  0.36789293054775,
+        ];
+        let (q, dqdr) = model.get_charges(3, &numbers, &positions, Some(-1.0), true);
+        assert!((q.iter().sum::<f64>() - -1.0).abs() < 1e-12);
+        let dqdr = dqdr.unwrap();
+        let step = 1e-5;
+        for j in 0..9 {
+            let mut pos = positions;
+            pos[j] += step;
+            let (qp, _) = model.get_charges(3, &numbers, &pos, Some(-1.0), false);
+            pos[j] -= 2.0 * step;
+            let (qm, _) = model.get_charges(3, &numbers, &pos, Some(-1.0), false);
+            for i in 0..3 {
+                let num = (qp[i] - qm[i]) / (2.0 * step);
+                assert!((num - dqdr[i * 9 + j]).abs() < 1e-8);
+            }
+        }
+    }
+}
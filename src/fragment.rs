@@ -0,0 +1,196 @@
+//! Fragment-based dispersion interaction energies.
+
+use crate::library::*;
+
+/// Check that fragments [nfrag][..] are a partition of atoms.
+fn check_partition(natoms: usize, fragments: &[Vec<usize>]) -> Result<(), DFTD4Error> {
+    let mut assigned = vec![false; natoms];
+    for (ifrag, fragment) in fragments.iter().enumerate() {
+        if fragment.is_empty() {
+            return Err(DFTD4Error::Rust(format!("Fragment {} is empty", ifrag)));
+        }
+        for &i in fragment {
+            if i >= natoms {
+                return Err(DFTD4Error::Rust(format!(
+                    "Invalid atom index {} in fragment {}, number of atoms is {}",
+                    i, ifrag, natoms
+                )));
+            }
+            if assigned[i] {
+                return Err(DFTD4Error::Rust(format!(
+                    "Atom {} is assigned to more than one fragment",
+                    i
+                )));
+            }
+            assigned[i] = true;
+        }
+    }
+    if let Some(i) = assigned.iter().position(|&x| !x) {
+        return Err(DFTD4Error::Rust(format!(
+            "Atom {} is not assigned to any fragment",
+            i
+        )));
+    }
+    Ok(())
+}
+
+/// Create structure data of a fragment, with the same lattice as the whole structure (failable)
+pub fn get_fragment_structure_f(
+    structure: &DFTD4Structure,
+    fragment: &[usize],
+    charge: Option<f64>,
+    multiplicity: Option<usize>,
+) -> Result<DFTD4Structure, DFTD4Error> {
+    let numbers = structure.get_numbers();
+    if let Some(&i) = fragment.iter().find(|&&i| i >= numbers.len()) {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid atom index {} in fragment, number of atoms is {}",
+            i,
+            numbers.len()
+        )));
+    }
+    let positions = structure.get_positions();
+    let lattice = structure.get_lattice();
+    let periodic = structure.get_periodic();
+    let numbers = fragment.iter().map(|&i| numbers[i]).collect::<Vec<usize>>();
+    let positions = fragment
+        .iter()
+        .flat_map(|&i| positions[3 * i..3 * i + 3].to_vec())
+        .collect::<Vec<f64>>();
+    let ghosts = structure
+        .get_ghosts()
+        .map(|ghosts| fragment.iter().map(|&i| ghosts[i]).collect::<Vec<bool>>());
+    DFTD4Structure::new_with_ghosts_f(
+        fragment.len(),
+        &numbers,
+        &positions,
+        charge,
+        lattice.as_deref(),
+        periodic.as_deref(),
+        ghosts.as_deref(),
+    )?
+    .with_multiplicity_f(multiplicity)
+}
+
+/// Create structure data of a fragment, with the same lattice as the whole structure
+///
+/// Ghost atoms of the structure stay ghost atoms in the fragment; a fragment of ghost atoms only
+/// is not a valid structure.
+///
+/// # Arguments
+///
+/// * `fragment` - atom indices of fragment [nfrag_atoms]
+/// * `charge` - total charge of fragment
+/// * `multiplicity` - spin multiplicity of fragment; lowest multiplicity for the number of
+///   electrons of the fragment if `None`
+pub fn get_fragment_structure(
+    structure: &DFTD4Structure,
+    fragment: &[usize],
+    charge: Option<f64>,
+    multiplicity: Option<usize>,
+) -> DFTD4Structure {
+    get_fragment_structure_f(structure, fragment, charge, multiplicity).unwrap()
+}
+
+/// Evaluate dispersion interaction energy of fragments (failable)
+pub fn get_fragment_dispersion_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    fragments: &[Vec<usize>],
+    charges: Option<&[f64]>,
+) -> Result<(f64, f64, Vec<f64>, Vec<f64>), DFTD4Error> {
+    let natoms = structure.get_natoms();
+    let nfrag = fragments.len();
+    check_partition(natoms, fragments)?;
+    if let Some(charges) = charges {
+        if charges.len() != nfrag {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for charges, expected {}, got {}",
+                nfrag,
+                charges.len()
+            )));
+        }
+    }
+    let total_charge = structure.get_charge().unwrap_or(0.0);
+    let fragment_charge = charges.map_or(0.0, |charges| charges.iter().sum());
+    if (total_charge - fragment_charge).abs() > 1e-8 {
+        return Err(DFTD4Error::Rust(format!(
+            "Sum of fragment charges {} differs from total charge {}",
+            fragment_charge, total_charge
+        )));
+    }
+
+    // supermolecule
+    let (energy, _, _) = get_dispersion_f(structure, model, param, false, false)?;
+    let (pair_energy2, pair_energy3) = get_pairwise_dispersion_f(structure, model, param)?;
+
+    // fragments; fragments of ghost atoms only (e.g. basis functions of counterpoise corrections)
+    // have no dispersion energy
+    let ghosts = structure.get_ghosts();
+    let mut fragment_energies = vec![];
+    for (ifrag, fragment) in fragments.iter().enumerate() {
+        let charge = charges.map(|charges| charges[ifrag]);
+        if let Some(ghosts) = &ghosts {
+            if fragment.iter().all(|&i| ghosts[i]) {
+                if charge.is_some_and(|q| q != 0.0) {
+                    return Err(DFTD4Error::Rust(format!(
+                        "Fragment {} of ghost atoms should be neutral",
+                        ifrag
+                    )));
+                }
+                fragment_energies.push(0.0);
+                continue;
+            }
+        }
+        let fragment_structure = get_fragment_structure_f(structure, fragment, charge, None)?;
+        let fragment_model = model.new_derived_f(&fragment_structure, fragment)?;
+        let (fragment_energy, _, _) =
+            get_dispersion_f(&fragment_structure, &fragment_model, param, false, false)?;
+        fragment_energies.push(fragment_energy);
+    }
+    let interaction = energy - fragment_energies.iter().sum::<f64>();
+
+    // pairwise energies summed by fragments
+    let mut fragment_pairs = vec![0.0; nfrag * nfrag];
+    for (a, fragment_a) in fragments.iter().enumerate() {
+        for (b, fragment_b) in fragments.iter().enumerate() {
+            for &i in fragment_a {
+                for &j in fragment_b {
+                    fragment_pairs[a * nfrag + b] +=
+                        pair_energy2[i * natoms + j] + pair_energy3[i * natoms + j];
+                }
+            }
+        }
+    }
+    Ok((interaction, energy, fragment_energies, fragment_pairs))
+}
+
+/// Evaluate dispersion interaction energy of fragments
+///
+/// The interaction energy is E(AB) - E(A) - E(B) - ..., where fragments are evaluated as
+/// isolated structures (with the lattice of the whole structure), with their own coordination
+/// numbers and charges. Models of fragments keep the settings of `model` (see
+/// `DFTD4Model::new_derived`), e.g. cutoffs and custom charges of the fragment atoms. Fragments of
+/// ghost atoms only have zero energy; they should be neutral.
+///
+/// # Arguments
+///
+/// * `fragments` - atom indices of fragments [nfrag][..], should be a partition of all atoms
+/// * `charges` - total charges of fragments [nfrag]; fragments are neutral if `None`
+///
+/// # Returns
+///
+/// Interaction energy, energy of the whole structure, energies of fragments [nfrag], and
+/// pairwise (two- and three-body) energies summed by fragments [nfrag][nfrag]. Off-diagonal sums
+/// are the pairwise estimate of interaction; they differ from the interaction energy by the change
+/// of coordination numbers and charges upon fragmentation.
+pub fn get_fragment_dispersion(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    fragments: &[Vec<usize>],
+    charges: Option<&[f64]>,
+) -> (f64, f64, Vec<f64>, Vec<f64>) {
+    get_fragment_dispersion_f(structure, model, param, fragments, charges).unwrap()
+}
@@ -0,0 +1,874 @@
+//! Lattice utilities for periodic structures.
+//!
+//! Lattice is [3][3] in row-major order, with lattice vectors as rows (quantities in Bohr), as in
+//! [`DFTD4Structure`]. This module validates cells, converts between lattice vectors and cell
+//! parameters, reduces cells (Niggli, Minkowski), builds supercells and wraps atoms into the cell.
+//!
+//! 1D (wire) and 2D (slab) periodic structures use a full lattice with vacuum vectors in
+//! non-periodic directions, orthogonal to the periodic lattice vectors ([`get_vacuum_lattice`]).
+//! Other vacuum vectors are replaced by orthogonal ones of the same length
+//! ([`orthogonalize_vacuum_f`]).
+
+use crate::library::*;
+use crate::math::{det3, inv3, matmul3};
+
+/// Minimum ratio of volume to product of lattice vector lengths, below which cells are badly
+/// skewed
+const SKEW_THRESHOLD: f64 = 0.1;
+
+/// Maximum cosine between lattice vectors of non-periodic and periodic directions
+const VACUUM_THRESHOLD: f64 = 1e-6;
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Ratio of volume to product of lattice vector lengths (1 for orthogonal cells, 0 for singular)
+fn get_orthogonality(lattice: &[f64]) -> f64 {
+    let lengths = (0..3)
+        .map(|k| norm(&lattice[3 * k..3 * k + 3]))
+        .product::<f64>();
+    match lengths > 0.0 {
+        true => det3(lattice) / lengths,
+        false => 0.0,
+    }
+}
+
+/// Check dimensions of lattice and periodic directions, and that the cell is not singular
+/// (failable)
+///
+/// A lattice without periodic directions is not checked for singularity.
+pub fn validate_lattice_f(lattice: &[f64], periodic: Option<&[bool]>) -> Result<(), DFTD4Error> {
+    if lattice.len() != 9 {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for lattice, expected 9, got {}",
+            lattice.len()
+        )));
+    }
+    validate_periodic_f(periodic)?;
+    if lattice.iter().any(|x| !x.is_finite()) {
+        return Err(DFTD4Error::Rust("Lattice is not finite".to_string()));
+    }
+    let is_periodic = periodic.is_none_or(|periodic| periodic.iter().any(|&p| p));
+    if is_periodic && get_orthogonality(lattice).abs() < 1e-8 {
+        return Err(DFTD4Error::Rust("Lattice is singular".to_string()));
+    }
+    Ok(())
+}
+
+/// Replace lattice vectors of non-periodic directions by vectors of the same length orthogonal to
+/// the periodic lattice vectors (failable)
+///
+/// Lattices whose vacuum vectors are already orthogonal, and lattices of 3D periodic or
+/// non-periodic structures, are returned unchanged. This is applied to lattices of 1D and 2D
+/// periodic structures passed to `DFTD4Structure`, see [`get_vacuum_lattice`].
+pub fn orthogonalize_vacuum_f(
+    lattice: &[f64],
+    periodic: Option<&[bool]>,
+) -> Result<Vec<f64>, DFTD4Error> {
+    validate_lattice_f(lattice, periodic)?;
+    let Some(periodic) = periodic.filter(|p| p.iter().any(|&x| x) && p.iter().any(|&x| !x)) else {
+        return Ok(lattice.to_vec());
+    };
+    let v = |k: usize| &lattice[3 * k..3 * k + 3];
+    let orthogonal = (0..3).filter(|&i| !periodic[i]).all(|i| {
+        (0..3)
+            .filter(|&j| periodic[j])
+            .all(|j| dot(v(i), v(j)).abs() <= VACUUM_THRESHOLD * norm(v(i)) * norm(v(j)))
+    });
+    if orthogonal {
+        return Ok(lattice.to_vec());
+    }
+    let mut result = get_vacuum_lattice_f(lattice, periodic, 1.0)?;
+    for k in (0..3).filter(|&k| !periodic[k]) {
+        let length = norm(v(k));
+        result[3 * k..3 * k + 3]
+            .iter_mut()
+            .for_each(|x| *x *= length);
+    }
+    Ok(result)
+}
+
+/// Check dimension of periodic directions (failable)
+pub fn validate_periodic_f(periodic: Option<&[bool]>) -> Result<(), DFTD4Error> {
+    match periodic {
+        Some(periodic) if periodic.len() != 3 => Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for periodic, expected 3, got {}",
+            periodic.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check lattice strictly: valid, right-handed and not badly skewed (failable)
+///
+/// This is the check applied to lattices of periodic structures passed to `DFTD4Structure`.
+/// Badly skewed cells (volume less than 0.1 of the product of lattice vector lengths) need many
+/// lattice images within cutoffs; they can be fixed by [`niggli_reduce`].
+pub fn check_lattice_f(lattice: &[f64], periodic: Option<&[bool]>) -> Result<(), DFTD4Error> {
+    validate_lattice_f(lattice, periodic)?;
+    let orthogonality = get_orthogonality(lattice);
+    if orthogonality < 0.0 {
+        return Err(DFTD4Error::Rust("Lattice is left-handed".to_string()));
+    }
+    if orthogonality < SKEW_THRESHOLD {
+        return Err(DFTD4Error::Rust(format!(
+            "Lattice is badly skewed (orthogonality {:.4}), consider Niggli reduction",
+            orthogonality
+        )));
+    }
+    Ok(())
+}
+
+/// Convert lattice to cell parameters [a, b, c, alpha, beta, gamma] (Bohr, degrees)
+pub fn get_cell_parameters(lattice: &[f64]) -> [f64; 6] {
+    let v = |k: usize| &lattice[3 * k..3 * k + 3];
+    let (a, b, c) = (norm(v(0)), norm(v(1)), norm(v(2)));
+    let angle = |x: &[f64], y: &[f64], lx: f64, ly: f64| {
+        (dot(x, y) / (lx * ly)).clamp(-1.0, 1.0).acos().to_degrees()
+    };
+    [
+        a,
+        b,
+        c,
+        angle(v(1), v(2), b, c),
+        angle(v(0), v(2), a, c),
+        angle(v(0), v(1), a, b),
+    ]
+}
+
+/// Convert cell parameters [a, b, c, alpha, beta, gamma] (Bohr, degrees) to lattice (failable)
+pub fn get_lattice_from_parameters_f(parameters: &[f64; 6]) -> Result<Vec<f64>, DFTD4Error> {
+    let [a, b, c, alpha, beta, gamma] = *parameters;
+    if a <= 0.0 || b <= 0.0 || c <= 0.0 {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid lengths of cell parameters {:?}",
+            parameters
+        )));
+    }
+    let (cos_a, cos_b, cos_g) = (
+        alpha.to_radians().cos(),
+        beta.to_radians().cos(),
+        gamma.to_radians().cos(),
+    );
+    let sin_g = gamma.to_radians().sin();
+    let cx = cos_b;
+    let cy = (cos_a - cos_b * cos_g) / sin_g;
+    let cz2 = 1.0 - cx * cx - cy * cy;
+    if sin_g <= 0.0 || cz2 <= 0.0 {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid angles of cell parameters {:?}",
+            parameters
+        )));
+    }
+    Ok(vec![
+        a,
+        0.0,
+        0.0,
+        b * cos_g,
+        b * sin_g,
+        0.0,
+        c * cx,
+        c * cy,
+        c * cz2.sqrt(),
+    ])
+}
+
+/// Convert cell parameters [a, b, c, alpha, beta, gamma] (Bohr, degrees) to lattice
+///
+/// The first lattice vector is along x, and the second one in the xy plane.
+pub fn get_lattice_from_parameters(parameters: &[f64; 6]) -> Vec<f64> {
+    get_lattice_from_parameters_f(parameters).unwrap()
+}
+
+/// Niggli reduction of lattice (failable)
+pub fn niggli_reduce_f(lattice: &[f64]) -> Result<Vec<f64>, DFTD4Error> {
+    validate_lattice_f(lattice, None)?;
+    let mut lat = [0, 1, 2].map(|k| [0, 1, 2].map(|x| lattice[3 * k + x]));
+    let eps = 1e-5 * det3(lattice).abs().powf(2.0 / 3.0);
+    let lt = |x: f64, y: f64| x < y - eps;
+    let gt = |x: f64, y: f64| lt(y, x);
+    let eq = |x: f64, y: f64| !(lt(x, y) || lt(y, x));
+    let sign = |x: f64| if x > 0.0 { 1.0 } else { -1.0 };
+    let combine = |v: [f64; 3], w: [f64; 3], f: f64| [0, 1, 2].map(|x| v[x] + f * w[x]);
+
+    // algorithm of Krivy and Gruber, Acta Cryst. A32, 297 (1976), as formulated by
+    // Grosse-Kunstleve et al., Acta Cryst. A60, 1 (2004)
+    for _ in 0..1000 {
+        let [va, vb, vc] = lat;
+        let (a, b, c) = (dot(&va, &va), dot(&vb, &vb), dot(&vc, &vc));
+        let (xi, eta, zeta) = (
+            2.0 * dot(&vb, &vc),
+            2.0 * dot(&va, &vc),
+            2.0 * dot(&va, &vb),
+        );
+        // step 1
+        if gt(a, b) || (eq(a, b) && gt(xi.abs(), eta.abs())) {
+            lat = [vb.map(|x| -x), va.map(|x| -x), vc.map(|x| -x)];
+            continue;
+        }
+        // step 2
+        if gt(b, c) || (eq(b, c) && gt(eta.abs(), zeta.abs())) {
+            lat = [va.map(|x| -x), vc.map(|x| -x), vb.map(|x| -x)];
+            continue;
+        }
+        // steps 3 and 4: signs of off-diagonal terms
+        let signs = [xi, eta, zeta].map(|x| {
+            if lt(x, 0.0) {
+                -1
+            } else if gt(x, 0.0) {
+                1
+            } else {
+                0
+            }
+        });
+        if signs.iter().product::<i32>() > 0 {
+            lat = [0, 1, 2].map(|k| lat[k].map(|x| x * signs[k] as f64));
+        } else {
+            let mut flip = signs.map(|s| if s > 0 { -1.0 } else { 1.0 });
+            if flip.iter().product::<f64>() < 0.0 {
+                if let Some(k) = signs.iter().position(|&s| s == 0) {
+                    flip[k] = -1.0;
+                }
+            }
+            if flip.iter().product::<f64>() < 0.0 {
+                return Err(DFTD4Error::Rust("Niggli reduction failed".to_string()));
+            }
+            lat = [0, 1, 2].map(|k| lat[k].map(|x| x * flip[k]));
+        }
+        let [va, vb, vc] = lat;
+        let (xi, eta, zeta) = (
+            2.0 * dot(&vb, &vc),
+            2.0 * dot(&va, &vc),
+            2.0 * dot(&va, &vb),
+        );
+        // step 5
+        if gt(xi.abs(), b) || (eq(xi, b) && lt(2.0 * eta, zeta)) || (eq(xi, -b) && lt(zeta, 0.0)) {
+            lat[2] = combine(vc, vb, -sign(xi));
+            continue;
+        }
+        // step 6
+        if gt(eta.abs(), a) || (eq(eta, a) && lt(2.0 * xi, zeta)) || (eq(eta, -a) && lt(zeta, 0.0))
+        {
+            lat[2] = combine(vc, va, -sign(eta));
+            continue;
+        }
+        // step 7
+        if gt(zeta.abs(), a) || (eq(zeta, a) && lt(2.0 * xi, eta)) || (eq(zeta, -a) && lt(eta, 0.0))
+        {
+            lat[1] = combine(vb, va, -sign(zeta));
+            continue;
+        }
+        // step 8
+        let sum = xi + eta + zeta + a + b;
+        if lt(sum, 0.0) || (eq(sum, 0.0) && gt(2.0 * (a + eta) + zeta, 0.0)) {
+            lat[2] = combine(combine(vc, va, 1.0), vb, 1.0);
+            continue;
+        }
+        return Ok(lat.concat());
+    }
+    Err(DFTD4Error::Rust(
+        "Niggli reduction did not converge".to_string(),
+    ))
+}
+
+/// Niggli reduction of lattice
+///
+/// The reduced cell spans the same lattice, with shortest lattice vectors in a unique standard
+/// form; handedness of the lattice is kept. All directions are treated as periodic.
+pub fn niggli_reduce(lattice: &[f64]) -> Vec<f64> {
+    niggli_reduce_f(lattice).unwrap()
+}
+
+/// Minkowski reduction of lattice vectors in periodic directions (failable)
+pub fn minkowski_reduce_f(
+    lattice: &[f64],
+    periodic: Option<&[bool]>,
+) -> Result<Vec<f64>, DFTD4Error> {
+    validate_lattice_f(lattice, periodic)?;
+    let periodic = periodic.unwrap_or(&[true; 3]);
+    let mut lat = [0, 1, 2].map(|k| [0, 1, 2].map(|x| lattice[3 * k + x]));
+    let active = (0..3).filter(|&k| periodic[k]).collect::<Vec<usize>>();
+    let len2 = |v: &[f64; 3]| dot(v, v);
+    let combine = |v: [f64; 3], w: [f64; 3], f: f64| [0, 1, 2].map(|x| v[x] + f * w[x]);
+
+    // reduce each vector by integer combinations (-1, 0, 1) of the other periodic vectors,
+    // after a Gauss reduction of pairs, until no vector can be shortened
+    for _ in 0..1000 {
+        let mut changed = false;
+        for &i in &active {
+            for &j in &active {
+                if i == j {
+                    continue;
+                }
+                let n = (dot(&lat[i], &lat[j]) / len2(&lat[j])).round();
+                if n != 0.0 {
+                    let reduced = combine(lat[i], lat[j], -n);
+                    if len2(&reduced) < len2(&lat[i]) * (1.0 - 1e-12) {
+                        lat[i] = reduced;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        if active.len() == 3 {
+            for i in 0..3 {
+                let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+                for nj in [-1.0, 1.0] {
+                    for nk in [-1.0, 1.0] {
+                        let reduced = combine(combine(lat[i], lat[j], nj), lat[k], nk);
+                        if len2(&reduced) < len2(&lat[i]) * (1.0 - 1e-12) {
+                            lat[i] = reduced;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+        if !changed {
+            return Ok(lat.concat());
+        }
+    }
+    Err(DFTD4Error::Rust(
+        "Minkowski reduction did not converge".to_string(),
+    ))
+}
+
+/// Minkowski reduction of lattice vectors in periodic directions
+///
+/// Each periodic lattice vector is shortened by integer combinations of the other periodic
+/// vectors; vectors of non-periodic directions are kept.
+pub fn minkowski_reduce(lattice: &[f64], periodic: Option<&[bool]>) -> Vec<f64> {
+    minkowski_reduce_f(lattice, periodic).unwrap()
+}
+
+/// Wrap positions into the cell in periodic directions (failable)
+pub fn wrap_positions_f(
+    positions: &[f64],
+    lattice: &[f64],
+    periodic: Option<&[bool]>,
+) -> Result<Vec<f64>, DFTD4Error> {
+    validate_lattice_f(lattice, periodic)?;
+    let periodic = periodic.unwrap_or(&[true; 3]);
+    let inv = inv3(lattice).ok_or_else(|| DFTD4Error::Rust("Lattice is singular".to_string()))?;
+    let mut positions = positions.to_vec();
+    for r in positions.chunks_mut(3) {
+        // fractional coordinates: r = f L
+        let mut frac = [0, 1, 2].map(|b| (0..3).map(|a| r[a] * inv[3 * a + b]).sum::<f64>());
+        for k in 0..3 {
+            if periodic[k] {
+                frac[k] -= frac[k].floor();
+            }
+        }
+        for (b, x) in r.iter_mut().enumerate() {
+            *x = (0..3).map(|a| frac[a] * lattice[3 * a + b]).sum();
+        }
+    }
+    Ok(positions)
+}
+
+/// Wrap positions into the cell in periodic directions
+///
+/// Fractional coordinates of periodic directions are wrapped into [0, 1). Energies and forces are
+/// invariant, but molecules may be split across the cell boundary.
+pub fn wrap_positions(positions: &[f64], lattice: &[f64], periodic: Option<&[bool]>) -> Vec<f64> {
+    wrap_positions_f(positions, lattice, periodic).unwrap()
+}
+
+/// Build lattice with vacuum vectors for 1D and 2D periodic structures (failable)
+pub fn get_vacuum_lattice_f(
+    lattice: &[f64],
+    periodic: &[bool],
+    vacuum: f64,
+) -> Result<Vec<f64>, DFTD4Error> {
+    if lattice.len() != 9 {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for lattice, expected 9, got {}",
+            lattice.len()
+        )));
+    }
+    validate_periodic_f(Some(periodic))?;
+    if vacuum <= 0.0 {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid length of vacuum vectors {}",
+            vacuum
+        )));
+    }
+    let v = |k: usize| [0, 1, 2].map(|x| lattice[3 * k + x]);
+    let unit = |x: [f64; 3]| -> Result<[f64; 3], DFTD4Error> {
+        let n = norm(&x);
+        match n > 1e-8 {
+            true => Ok(x.map(|y| y / n)),
+            false => Err(DFTD4Error::Rust(
+                "Lattice vectors of periodic directions are singular".to_string(),
+            )),
+        }
+    };
+    let mut result = lattice.to_vec();
+    let mut set =
+        |k: usize, x: [f64; 3]| result[3 * k..3 * k + 3].copy_from_slice(&x.map(|y| y * vacuum));
+    let periodic_idx = (0..3).filter(|&k| periodic[k]).collect::<Vec<usize>>();
+    match periodic_idx.len() {
+        0 => (0..3).for_each(|k| set(k, [0, 1, 2].map(|x| (x == k) as usize as f64))),
+        1 => {
+            // vectors (p, i, j) in cyclic order are right-handed
+            let p = periodic_idx[0];
+            let (i, j) = ((p + 1) % 3, (p + 2) % 3);
+            let a = unit(v(p))?;
+            // any axis not parallel to the periodic vector
+            let k = (0..3)
+                .min_by(|&x, &y| a[x].abs().total_cmp(&a[y].abs()))
+                .unwrap();
+            let mut axis = [0.0; 3];
+            axis[k] = 1.0;
+            let u = unit(cross(&axis, &a))?;
+            set(i, u);
+            set(j, unit(cross(&a, &u))?);
+        }
+        2 => {
+            let k = (0..3).find(|&k| !periodic[k]).unwrap();
+            set(k, unit(cross(&v((k + 1) % 3), &v((k + 2) % 3)))?);
+        }
+        _ => (),
+    }
+    Ok(result)
+}
+
+/// Build lattice with vacuum vectors for 1D and 2D periodic structures
+///
+/// Lattice vectors of non-periodic directions are replaced by orthogonal vectors of length
+/// `vacuum`, forming a right-handed cell with the periodic lattice vectors. This is the lattice
+/// convention of low-dimensional periodic structures in `DFTD4Structure`; since interactions are
+/// not repeated along non-periodic directions, results do not depend on `vacuum`.
+///
+/// # Arguments
+///
+/// * `lattice` - lattice [3][3]; only vectors of periodic directions are used
+/// * `periodic` - periodic [3]
+/// * `vacuum` - length of lattice vectors of non-periodic directions (Bohr)
+pub fn get_vacuum_lattice(lattice: &[f64], periodic: &[bool], vacuum: f64) -> Vec<f64> {
+    get_vacuum_lattice_f(lattice, periodic, vacuum).unwrap()
+}
+
+/// Restrict virial to periodic directions
+///
+/// The virial is projected as `P sigma P`, where `P` projects onto the span of lattice vectors of
+/// periodic directions. Components along vacuum directions, which have no meaning for strain of
+/// 1D and 2D periodic structures, are removed; 3D periodic and molecular virials are unchanged.
+///
+/// # Arguments
+///
+/// * `sigma` - virial [3][3]
+/// * `lattice` - lattice [3][3]
+/// * `periodic` - periodic [3]
+pub fn restrict_sigma(sigma: &[f64], lattice: &[f64], periodic: &[bool]) -> Vec<f64> {
+    let proj = get_periodic_projector(lattice, periodic);
+    matmul3(&matmul3(&proj, sigma), &proj).to_vec()
+}
+
+/// Projector [3][3] onto the span of lattice vectors of periodic directions
+///
+/// Identity for molecules and 3D periodic structures.
+pub(crate) fn get_periodic_projector(lattice: &[f64], periodic: &[bool]) -> [f64; 9] {
+    let nperiodic = periodic.iter().filter(|&&p| p).count();
+    if nperiodic == 0 || nperiodic == 3 {
+        return [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+    }
+    // orthonormal basis of periodic directions by Gram-Schmidt
+    let mut basis: Vec<[f64; 3]> = vec![];
+    for k in (0..3).filter(|&k| periodic[k]) {
+        let mut x = [0, 1, 2].map(|a| lattice[3 * k + a]);
+        for e in &basis {
+            let d = dot(&x, e);
+            (0..3).for_each(|a| x[a] -= d * e[a]);
+        }
+        let n = norm(&x);
+        if n > 1e-8 {
+            basis.push(x.map(|y| y / n));
+        }
+    }
+    let mut proj = [0.0; 9];
+    for e in &basis {
+        for a in 0..3 {
+            for b in 0..3 {
+                proj[3 * a + b] += e[a] * e[b];
+            }
+        }
+    }
+    proj
+}
+
+/// Translation vectors [ntrans][3] of lattice points in periodic directions, covering all images
+/// of atoms within `cutoff` (Bohr) of each other
+///
+/// The zero translation is the first one, and the only one without lattice or periodic
+/// directions. The spread of atoms in fractional coordinates is taken into account, so positions
+/// need not be wrapped into the cell.
+pub(crate) fn get_lattice_points(
+    lattice: Option<&[f64]>,
+    periodic: &[bool],
+    positions: &[f64],
+    cutoff: f64,
+) -> Vec<[f64; 3]> {
+    let (Some(lattice), true) = (lattice, periodic.iter().any(|&p| p)) else {
+        return vec![[0.0; 3]];
+    };
+    let Some(inv) = inv3(lattice) else {
+        return vec![[0.0; 3]];
+    };
+    let nimg = [0, 1, 2].map(|k| {
+        if !periodic[k] {
+            return 0;
+        }
+        // norm of column k of the inverse is the inverse distance between lattice planes
+        let height = 1.0 / norm(&[inv[k], inv[3 + k], inv[6 + k]]);
+        let (lo, hi) = positions
+            .chunks(3)
+            .map(|r| (0..3).map(|a| r[a] * inv[3 * a + k]).sum::<f64>())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), f| {
+                (lo.min(f), hi.max(f))
+            });
+        (cutoff / height + (hi - lo).max(0.0)).ceil() as i32
+    });
+    // translations beyond cutoff and extent of atoms are not needed
+    let extent = (0..3)
+        .map(|a| {
+            let x = positions.iter().skip(a).step_by(3);
+            let (lo, hi) = x.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| {
+                (lo.min(x), hi.max(x))
+            });
+            (hi - lo).max(0.0).powi(2)
+        })
+        .sum::<f64>()
+        .sqrt();
+    let mut points = vec![[0.0; 3]];
+    for i in -nimg[0]..=nimg[0] {
+        for j in -nimg[1]..=nimg[1] {
+            for k in -nimg[2]..=nimg[2] {
+                if (i, j, k) == (0, 0, 0) {
+                    continue;
+                }
+                let (i, j, k) = (i as f64, j as f64, k as f64);
+                let t = [0, 1, 2].map(|x| i * lattice[x] + j * lattice[3 + x] + k * lattice[6 + x]);
+                if norm(&t) <= cutoff + extent {
+                    points.push(t);
+                }
+            }
+        }
+    }
+    points
+}
+
+/// Cells `n` [ncells][3] inside the supercell of `matrix`, i.e. `n M^-1` in [0, 1)
+fn get_supercell_cells(matrix: &[[i32; 3]; 3]) -> Vec<[i32; 3]> {
+    let m = matrix
+        .concat()
+        .iter()
+        .map(|&x| x as f64)
+        .collect::<Vec<f64>>();
+    let Some(minv) = inv3(&m) else {
+        return vec![];
+    };
+    let bound = [0, 1, 2].map(|l| (0..3).map(|k| matrix[k][l].abs()).sum::<i32>());
+    let mut cells = vec![];
+    for n0 in -bound[0]..=bound[0] {
+        for n1 in -bound[1]..=bound[1] {
+            for n2 in -bound[2]..=bound[2] {
+                let n = [n0, n1, n2].map(|x| x as f64);
+                let frac = [0, 1, 2].map(|b| (0..3).map(|a| n[a] * minv[3 * a + b]).sum::<f64>());
+                if frac.iter().all(|&f| (-1e-8..1.0 - 1e-8).contains(&f)) {
+                    cells.push([n0, n1, n2]);
+                }
+            }
+        }
+    }
+    cells
+}
+
+/// Build supercell (failable)
+pub fn get_supercell_f(
+    structure: &DFTD4Structure,
+    matrix: &[[i32; 3]; 3],
+) -> Result<DFTD4Structure, DFTD4Error> {
+    let lattice = structure
+        .get_lattice()
+        .ok_or_else(|| DFTD4Error::Rust("Lattice is required for supercell".to_string()))?;
+    let periodic = structure.get_periodic();
+    let periodic_mask = periodic.clone().unwrap_or(vec![true; 3]);
+    for k in 0..3 {
+        for l in 0..3 {
+            if !periodic_mask[k] && matrix[k][l] != (k == l) as i32 {
+                return Err(DFTD4Error::Rust(format!(
+                    "Supercell cannot extend non-periodic direction {}",
+                    k
+                )));
+            }
+            if !periodic_mask[l] && k != l && matrix[k][l] != 0 {
+                return Err(DFTD4Error::Rust(format!(
+                    "Supercell cannot mix non-periodic direction {}",
+                    l
+                )));
+            }
+        }
+    }
+    let m = matrix
+        .concat()
+        .iter()
+        .map(|&x| x as f64)
+        .collect::<Vec<f64>>();
+    let ncells = det3(&m).round();
+    if ncells < 1.0 {
+        return Err(DFTD4Error::Rust(format!(
+            "Supercell matrix should have positive determinant, got {}",
+            ncells
+        )));
+    }
+    let ncells = ncells as usize;
+
+    // new lattice: rows of M L
+    let mut new_lattice = vec![0.0; 9];
+    for k in 0..3 {
+        for x in 0..3 {
+            new_lattice[3 * k + x] = (0..3).map(|l| m[3 * k + l] * lattice[3 * l + x]).sum();
+        }
+    }
+    let translations = get_supercell_cells(matrix)
+        .iter()
+        .map(|n| {
+            [0, 1, 2].map(|x| {
+                (0..3)
+                    .map(|a| n[a] as f64 * lattice[3 * a + x])
+                    .sum::<f64>()
+            })
+        })
+        .collect::<Vec<[f64; 3]>>();
+    if translations.len() != ncells {
+        return Err(DFTD4Error::Rust(
+            "Inconsistent number of cells in supercell".to_string(),
+        ));
+    }
+
+    let numbers = structure.get_numbers();
+    let positions = structure.get_positions();
+    let mut new_numbers = vec![];
+    let mut new_positions = vec![];
+    for t in &translations {
+        new_numbers.extend_from_slice(&numbers);
+        for r in positions.chunks(3) {
+            new_positions.extend((0..3).map(|x| r[x] + t[x]));
+        }
+    }
+    let new_ghosts = structure.get_ghosts().map(|ghosts| ghosts.repeat(ncells));
+    DFTD4Structure::new_with_ghosts_f(
+        new_numbers.len(),
+        &new_numbers,
+        &new_positions,
+        structure.get_charge().map(|q| q * ncells as f64),
+        Some(&new_lattice),
+        periodic.as_deref(),
+        new_ghosts.as_deref(),
+    )?
+    // unpaired electrons of an explicit multiplicity are replicated for all cells
+    .with_multiplicity_f(
+        structure
+            .get_explicit_multiplicity()
+            .map(|m| (m - 1) * ncells + 1),
+    )
+}
+
+/// Build supercell
+///
+/// Lattice vectors of the supercell are rows of `matrix * lattice`; atoms are replicated for all
+/// cells within the supercell, and total charge is scaled by the number of cells, as are the
+/// unpaired electrons of a spin multiplicity given at construction of the structure.
+///
+/// # Arguments
+///
+/// * `matrix` - integer supercell matrix [3][3] with positive determinant, e.g. diagonal for
+///   repetitions along lattice vectors; non-periodic directions cannot be extended
+pub fn get_supercell(structure: &DFTD4Structure, matrix: &[[i32; 3]; 3]) -> DFTD4Structure {
+    get_supercell_f(structure, matrix).unwrap()
+}
+
+/// Create dispersion model of a supercell (failable)
+pub fn get_supercell_model_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    supercell: &DFTD4Structure,
+) -> Result<DFTD4Model, DFTD4Error> {
+    let natoms = structure.get_natoms();
+    let ncells = supercell.get_natoms() / natoms;
+    if ncells * natoms != supercell.get_natoms() {
+        return Err(DFTD4Error::Rust(format!(
+            "Number of atoms of supercell {} is not a multiple of {}",
+            supercell.get_natoms(),
+            natoms
+        )));
+    }
+    let atoms = (0..ncells * natoms)
+        .map(|i| i % natoms)
+        .collect::<Vec<usize>>();
+    model.new_derived_f(supercell, &atoms)
+}
+
+/// Create dispersion model of a supercell
+///
+/// The model of a supercell built by `get_supercell` keeps the settings of the model of the unit
+/// cell (see `DFTD4Model::new_derived`), with custom charges replicated for all cells.
+pub fn get_supercell_model(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    supercell: &DFTD4Structure,
+) -> DFTD4Model {
+    get_supercell_model_f(structure, model, supercell).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_parameters() {
+        let parameters = [5.0, 6.0, 7.0, 80.0, 95.0, 110.0];
+        let lattice = get_lattice_from_parameters(&parameters);
+        let result = get_cell_parameters(&lattice);
+        for k in 0..6 {
+            assert!((result[k] - parameters[k]).abs() < 1e-10);
+        }
+        assert!(check_lattice_f(&lattice, None).is_ok());
+        assert!(get_lattice_from_parameters_f(&[5.0, 5.0, 5.0, 150.0, 150.0, 150.0]).is_err());
+
+        // invalid cells
+        assert!(validate_lattice_f(&lattice, Some(&[true, true])).is_err());
+        assert!(validate_lattice_f(&[1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0], None).is_err());
+        let left = [0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+        assert!(validate_lattice_f(&left, None).is_ok());
+        assert!(check_lattice_f(&left, None).is_err());
+    }
+
+    #[test]
+    fn test_reduction() {
+        // skewed cell of a hexagonal lattice
+        let cell = get_lattice_from_parameters(&[3.0, 3.0, 5.0, 90.0, 90.0, 120.0]);
+        let v = |k: usize| [0, 1, 2].map(|x| cell[3 * k + x]);
+        let (a, b, c) = (v(0), v(1), v(2));
+        #[rustfmt::skip]
+        let skewed = [
+            a[0] + 3.0 * b[0], a[1] + 3.0 * b[1], a[2] + 3.0 * b[2],
+            2.0 * a[0] + 7.0 * b[0], 2.0 * a[1] + 7.0 * b[1], 2.0 * a[2] + 7.0 * b[2],
+            c[0] - a[0] + 2.0 * b[0], c[1] - a[1] + 2.0 * b[1], c[2] - a[2] + 2.0 * b[2],
+        ];
+        assert!((det3(&skewed) - det3(&cell)).abs() < 1e-8);
+        assert!(check_lattice_f(&skewed, None).is_err());
+
+        let niggli = niggli_reduce(&skewed);
+        let parameters = get_cell_parameters(&niggli);
+        let expected = [3.0, 3.0, 5.0, 90.0, 90.0, 120.0];
+        for k in 0..6 {
+            assert!((parameters[k] - expected[k]).abs() < 1e-8);
+        }
+        assert!((det3(&niggli) - det3(&cell)).abs() < 1e-8);
+
+        let minkowski = minkowski_reduce(&skewed, None);
+        let mut lengths = get_cell_parameters(&minkowski)[..3].to_vec();
+        lengths.sort_by(f64::total_cmp);
+        assert!((lengths[0] - 3.0).abs() < 1e-8 && (lengths[1] - 3.0).abs() < 1e-8);
+        assert!((lengths[2] - 5.0).abs() < 1e-8);
+        assert!((det3(&minkowski).abs() - det3(&cell)).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_low_dimensional() {
+        // slab: vacuum vector orthogonal to periodic vectors, right-handed
+        let slab = [4.0, 0.0, 0.0, -2.0, 3.5, 0.0, 1.0, 1.0, 1.0];
+        let periodic = [true, true, false];
+        let lattice = get_vacuum_lattice(&slab, &periodic, 30.0);
+        assert_eq!(&lattice[..6], &slab[..6]);
+        assert!((det3(&lattice) - 4.0 * 3.5 * 30.0).abs() < 1e-10);
+        assert!(validate_lattice_f(&lattice, Some(&periodic)).is_ok());
+        // vacuum vectors are made orthogonal, keeping their length
+        let orthogonal = orthogonalize_vacuum_f(&slab, Some(&periodic)).unwrap();
+        assert_eq!(&orthogonal[..6], &slab[..6]);
+        assert!(orthogonal[6..8].iter().all(|x| x.abs() < 1e-14));
+        assert!((orthogonal[8] - 3.0_f64.sqrt()).abs() < 1e-14);
+        assert_eq!(
+            orthogonalize_vacuum_f(&lattice, Some(&periodic)).unwrap(),
+            lattice
+        );
+        assert_eq!(orthogonalize_vacuum_f(&slab, None).unwrap(), slab);
+
+        // wire along any lattice vector
+        for p in 0..3 {
+            let mut periodic = [false; 3];
+            periodic[p] = true;
+            let mut wire = [0.0; 9];
+            wire[3 * p..3 * p + 3].copy_from_slice(&[1.0, 2.0, 2.0]);
+            let lattice = get_vacuum_lattice(&wire, &periodic, 20.0);
+            assert!((det3(&lattice) - 3.0 * 20.0 * 20.0).abs() < 1e-8);
+            assert!(validate_lattice_f(&lattice, Some(&periodic)).is_ok());
+        }
+        assert!(get_vacuum_lattice_f(&[0.0; 9], &[true, false, false], 20.0).is_err());
+
+        // virial has no components along vacuum direction
+        let sigma = [1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0];
+        let restricted = restrict_sigma(&sigma, &lattice, &periodic);
+        assert_eq!(restricted, [1.0, 2.0, 0.0, 2.0, 4.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(restrict_sigma(&sigma, &lattice, &[true; 3]), sigma);
+    }
+
+    #[test]
+    fn test_supercell_cells() {
+        assert_eq!(
+            get_supercell_cells(&[[2, 0, 0], [0, 3, 0], [0, 0, 1]]).len(),
+            6
+        );
+        let matrix = [[1, 1, 0], [-1, 1, 0], [0, 1, 2]];
+        let cells = get_supercell_cells(&matrix);
+        assert_eq!(cells.len(), 4);
+        // cells are distinct modulo supercell translations: n M^-1 differ by non-integers
+        let m = matrix
+            .concat()
+            .iter()
+            .map(|&x| x as f64)
+            .collect::<Vec<f64>>();
+        let minv = inv3(&m).unwrap();
+        for (i, a) in cells.iter().enumerate() {
+            for b in &cells[..i] {
+                let d = [0, 1, 2].map(|k| (a[k] - b[k]) as f64);
+                let frac = [0, 1, 2].map(|y| (0..3).map(|x| d[x] * minv[3 * x + y]).sum::<f64>());
+                assert!(frac.iter().any(|f| (f - f.round()).abs() > 1e-8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrap_positions() {
+        let lattice = [4.0, 0.0, 0.0, 1.0, 4.0, 0.0, 0.0, 0.0, 5.0];
+        let positions = [-1.0, 2.0, 6.0, 9.5, -3.0, -0.5];
+        let wrapped = wrap_positions(&positions, &lattice, None);
+        let inv = inv3(&lattice).unwrap();
+        for (r, w) in positions.chunks(3).zip(wrapped.chunks(3)) {
+            let frac = [0, 1, 2].map(|b| (0..3).map(|a| w[a] * inv[3 * a + b]).sum::<f64>());
+            assert!(frac.iter().all(|&f| (0.0..1.0).contains(&f)));
+            // difference is a lattice translation
+            let diff =
+                [0, 1, 2].map(|b| (0..3).map(|a| (r[a] - w[a]) * inv[3 * a + b]).sum::<f64>());
+            assert!(diff.iter().all(|d| (d - d.round()).abs() < 1e-12));
+        }
+        // non-periodic directions are not wrapped
+        let wrapped = wrap_positions(&positions, &lattice, Some(&[true, true, false]));
+        assert!((wrapped[2] - 6.0).abs() < 1e-12);
+    }
+}
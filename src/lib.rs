@@ -1,8 +1,32 @@
 #![allow(non_camel_case_types)]
 
+pub mod backend;
+pub mod conformer;
+pub mod connectivity;
+pub mod cutoff;
+pub mod data;
+pub mod dynamics;
+pub mod eeq;
 pub mod ffi;
+pub mod fragment;
+pub mod lattice;
 pub mod library;
+mod math;
+pub mod native;
+pub mod optimizer;
+mod param;
+pub mod polarizability;
+pub mod qmmm;
 pub mod rest_interface;
+pub mod scd4;
+pub mod screening;
+pub mod terms;
+pub mod thermo;
+pub mod validation;
+pub mod vibration;
 pub mod prelude {
+    pub use crate::connectivity::*;
+    pub use crate::eeq::*;
+    pub use crate::fragment::*;
     pub use crate::library::*;
 }
@@ -1,11 +1,35 @@
+use crate::backend::{DefaultBackend, DispersionBackend};
+#[cfg(not(feature = "pure-rust"))]
 use crate::ffi;
+use crate::lattice::{
+    check_lattice_f, orthogonalize_vacuum_f, restrict_sigma, validate_lattice_f,
+    validate_periodic_f, wrap_positions_f,
+};
+use std::cell::RefCell;
+#[cfg(not(feature = "pure-rust"))]
 use std::ffi::{c_char, c_int, CStr};
-use std::ptr::{null, null_mut};
 use std::result::Result;
 
+/// Coordination numbers [natoms], charges [natoms], C6 coefficients [natoms][natoms] and static
+/// polarizabilities [natoms]
+pub type DFTD4Properties = (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>);
+
+/// Dispersion energy, and optionally gradient [natoms][3] and virial [3][3]
+pub type DFTD4Dispersion = (f64, Option<Vec<f64>>, Option<Vec<f64>>);
+
+/// Dispersion energy, and optionally gradient [natoms][3]
+pub type DFTD4EnergyGradient = (f64, Option<Vec<f64>>);
+
+/// Two-body, three-body and total atomic energies [natoms], and optionally gradient norms [natoms]
+pub type DFTD4AtomicDispersion = (Vec<f64>, Vec<f64>, Vec<f64>, Option<Vec<f64>>);
+
+fn get_version() -> usize {
+    DefaultBackend::get_version()
+}
+
 /// Get the version of the DFTD4 library.
 pub fn get_api_version() -> String {
-    let version = unsafe { ffi::dftd4_get_version() };
+    let version = get_version();
     format!(
         "{}.{}.{}",
         version / 10000,
@@ -16,15 +40,17 @@ pub fn get_api_version() -> String {
 
 /// Get the version of the DFTD4 library in list of integers.
 pub fn get_api_version_compact() -> [usize; 3] {
-    let version = unsafe { ffi::dftd4_get_version() } as usize;
+    let version = get_version();
     [version / 10000, version / 100 % 100, version % 100]
 }
 
 pub enum DFTD4Error {
+    #[cfg(not(feature = "pure-rust"))]
     C(ffi::dftd4_error),
     Rust(String),
 }
 
+#[cfg(not(feature = "pure-rust"))]
 impl Drop for DFTD4Error {
     fn drop(&mut self) {
         match self {
@@ -35,6 +61,7 @@ impl Drop for DFTD4Error {
 }
 
 impl DFTD4Error {
+    #[cfg(not(feature = "pure-rust"))]
     pub fn new() -> Self {
         let ptr = unsafe { ffi::dftd4_new_error() };
         DFTD4Error::C(ptr)
@@ -42,11 +69,13 @@ impl DFTD4Error {
 
     pub fn check(&self) -> bool {
         match self {
+            #[cfg(not(feature = "pure-rust"))]
             DFTD4Error::C(ptr) => unsafe { ffi::dftd4_check_error(*ptr) != 0 },
             DFTD4Error::Rust(_) => true,
         }
     }
 
+    #[cfg(not(feature = "pure-rust"))]
     pub fn get_c_ptr(&mut self) -> ffi::dftd4_error {
         match self {
             DFTD4Error::C(ptr) => *ptr,
@@ -56,6 +85,7 @@ impl DFTD4Error {
 
     pub fn get_message(&self) -> String {
         match self {
+            #[cfg(not(feature = "pure-rust"))]
             DFTD4Error::C(ptr) => {
                 const LEN_BUFFER: usize = 512;
                 let buffer = [0u8; LEN_BUFFER];
@@ -64,13 +94,20 @@ impl DFTD4Error {
                     ffi::dftd4_get_error(*ptr, raw, &(LEN_BUFFER as c_int));
                     CStr::from_ptr(raw)
                 };
-                return msg.to_string_lossy().to_string();
+                msg.to_string_lossy().to_string()
             }
             DFTD4Error::Rust(msg) => msg.clone(),
         }
     }
 }
 
+#[cfg(not(feature = "pure-rust"))]
+impl Default for DFTD4Error {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl std::fmt::Debug for DFTD4Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         if self.check() {
@@ -93,81 +130,336 @@ impl std::fmt::Display for DFTD4Error {
 
 impl std::error::Error for DFTD4Error {}
 
-pub struct DFTD4Structure {
-    ptr: ffi::dftd4_structure,
-    natoms: usize,
-}
-
-impl Drop for DFTD4Structure {
-    fn drop(&mut self) {
-        unsafe { ffi::dftd4_delete_structure(&mut self.ptr) };
-    }
+/// Molecular structure data, evaluated by backend `B` (`DefaultBackend` if omitted)
+pub struct DFTD4Structure<B: DispersionBackend = DefaultBackend> {
+    inner: B::Structure,
+    numbers: Vec<usize>,
+    positions: RefCell<Vec<f64>>,
+    charge: Option<f64>,
+    lattice: RefCell<Option<Vec<f64>>>,
+    periodic: Option<Vec<bool>>,
+    multiplicity: Option<usize>,
+    ghosts: Option<Vec<bool>>,
 }
 
-impl DFTD4Structure {
+impl<B: DispersionBackend> DFTD4Structure<B> {
     /// Get number of atoms
     pub fn get_natoms(&self) -> usize {
-        self.natoms
+        self.numbers.len()
     }
 
-    /// Create new molecular structure data (quantities in Bohr) (failable)
-    pub fn new_f(
+    /// Get atomic numbers [natoms]
+    pub fn get_numbers(&self) -> Vec<usize> {
+        self.numbers.clone()
+    }
+
+    /// Get positions [natoms][3] (quantities in Bohr)
+    pub fn get_positions(&self) -> Vec<f64> {
+        self.positions.borrow().clone()
+    }
+
+    /// Get total charge
+    pub fn get_charge(&self) -> Option<f64> {
+        self.charge
+    }
+
+    /// Get lattice [3][3] (quantities in Bohr)
+    pub fn get_lattice(&self) -> Option<Vec<f64>> {
+        self.lattice.borrow().clone()
+    }
+
+    /// Get periodic directions [3]
+    pub fn get_periodic(&self) -> Option<Vec<bool>> {
+        self.periodic.clone()
+    }
+
+    /// Get ghost atoms [natoms]
+    pub fn get_ghosts(&self) -> Option<Vec<bool>> {
+        self.ghosts.clone()
+    }
+
+    /// Get number of electrons, from atomic numbers (excluding ghost atoms) and total charge
+    pub fn get_nelectrons(&self) -> f64 {
+        let ghost = |i: usize| self.ghosts.as_ref().is_some_and(|ghosts| ghosts[i]);
+        let nuclear = (0..self.numbers.len())
+            .filter(|&i| !ghost(i))
+            .map(|i| self.numbers[i])
+            .sum::<usize>();
+        nuclear as f64 - self.charge.unwrap_or(0.0)
+    }
+
+    /// Get spin multiplicity
+    ///
+    /// Returns the multiplicity given to [`Self::new_with_multiplicity_f`], or otherwise the lowest
+    /// multiplicity for the parity of the number of electrons (singlet or doublet).
+    pub fn get_multiplicity(&self) -> usize {
+        self.multiplicity
+            .unwrap_or(1 + (self.get_nelectrons().round() as i64).rem_euclid(2) as usize)
+    }
+
+    /// Spin multiplicity given at construction, if any
+    pub(crate) fn get_explicit_multiplicity(&self) -> Option<usize> {
+        self.multiplicity
+    }
+
+    /// Validate and record spin multiplicity, keeping the lowest multiplicity if `None`
+    pub(crate) fn with_multiplicity_f(
+        mut self,
+        multiplicity: Option<usize>,
+    ) -> Result<Self, DFTD4Error> {
+        if let Some(multiplicity) = multiplicity {
+            let nelectrons = self.get_nelectrons();
+            if (nelectrons - nelectrons.round()).abs() > 1e-8 {
+                return Err(DFTD4Error::Rust(format!(
+                    "Spin multiplicity requires integer number of electrons, got {}",
+                    nelectrons
+                )));
+            }
+            let nelectrons = nelectrons.round() as i64;
+            let nunpaired = multiplicity as i64 - 1;
+            if multiplicity == 0 || nunpaired > nelectrons || (nelectrons - nunpaired) % 2 != 0 {
+                return Err(DFTD4Error::Rust(format!(
+                    "Spin multiplicity {} is inconsistent with {} electrons",
+                    multiplicity, nelectrons
+                )));
+            }
+        }
+        self.multiplicity = multiplicity;
+        Ok(self)
+    }
+
+    /// Create new molecular structure data with ghost atoms, evaluated by backend `B`
+    /// (quantities in Bohr) (failable)
+    ///
+    /// Same as [`DFTD4Structure::new_with_ghosts_f`] for other backends than `DefaultBackend`,
+    /// e.g. `DFTD4Structure::<MockBackend>::new_with_backend_f(...)`.
+    pub fn new_with_backend_f(
         natoms: usize,
         numbers: &[usize],
         positions: &[f64],
         charge: Option<f64>,
         lattice: Option<&[f64]>,
         periodic: Option<&[bool]>,
+        ghosts: Option<&[bool]>,
     ) -> Result<Self, DFTD4Error> {
-        // check dimension
-        if numbers.len() != natoms {
-            return Err(DFTD4Error::Rust(format!(
-                "Invalid dimension for numbers, expected {}, got {}",
-                natoms,
-                numbers.len()
-            )));
+        if let Some(ghosts) = ghosts {
+            check_ghosts(natoms, numbers, positions, ghosts)?;
         }
-        if positions.len() != 3 * natoms {
-            return Err(DFTD4Error::Rust(format!(
-                "Invalid dimension for positions, expected {}, got {}",
-                3 * natoms,
-                positions.len()
-            )));
+        if lattice.is_none() {
+            validate_periodic_f(periodic)?;
         }
-        if lattice.is_some_and(|lattice| lattice.len() != 9) {
-            return Err(DFTD4Error::Rust(format!(
-                "Invalid dimension for lattice, expected 9, got {}",
-                lattice.unwrap().len()
-            )));
+        let lattice = lattice
+            .map(|lattice| orthogonalize_vacuum_f(lattice, periodic))
+            .transpose()?;
+        let lattice = lattice.as_deref();
+        let wrapped = wrap_into_cell_f(positions, lattice, periodic)?;
+        let inner = match ghosts {
+            None => B::new_structure_f(natoms, numbers, &wrapped, charge, lattice, periodic)?,
+            Some(ghosts) => {
+                let real = (0..natoms).filter(|&i| !ghosts[i]).collect::<Vec<usize>>();
+                let real_numbers = real.iter().map(|&i| numbers[i]).collect::<Vec<usize>>();
+                let real_positions = gather(&real, &wrapped, 3);
+                B::new_structure_f(
+                    real.len(),
+                    &real_numbers,
+                    &real_positions,
+                    charge,
+                    lattice,
+                    periodic,
+                )?
+            }
+        };
+        Ok(Self {
+            inner,
+            numbers: numbers.to_vec(),
+            positions: RefCell::new(positions.to_vec()),
+            charge,
+            lattice: RefCell::new(lattice.map(|x| x.to_vec())),
+            periodic: periodic.map(|x| x.to_vec()),
+            multiplicity: None,
+            ghosts: ghosts.map(|x| x.to_vec()),
+        })
+    }
+
+    /// Update coordinates and lattice parameters (quantities in Bohr) (failable)
+    pub fn update_f(&self, positions: &[f64], lattice: Option<&[f64]>) -> Result<(), DFTD4Error> {
+        let lattice = lattice
+            .map(|lattice| orthogonalize_vacuum_f(lattice, self.periodic.as_deref()))
+            .transpose()?;
+        let lattice = lattice.as_deref();
+        let wrapped = wrap_into_cell_f(
+            positions,
+            lattice.or(self.lattice.borrow().as_deref()),
+            self.periodic.as_deref(),
+        )?;
+        match self.get_real_atoms() {
+            None => B::update_structure_f(&self.inner, &wrapped, lattice)?,
+            Some(real) => {
+                if positions.len() != 3 * self.numbers.len() {
+                    return Err(DFTD4Error::Rust(format!(
+                        "Invalid dimension for positions, expected {}, got {}",
+                        3 * self.numbers.len(),
+                        positions.len()
+                    )));
+                }
+                let real_positions = gather(&real, &wrapped, 3);
+                B::update_structure_f(&self.inner, &real_positions, lattice)?
+            }
         }
-        // unwrap optional values
-        let charge_ptr = charge.map_or(null(), |x| &x as *const f64);
-        let lattice_ptr = lattice.map_or(null(), |x| x.as_ptr());
-        let periodic_ptr = periodic.map_or(null(), |x| x.as_ptr());
-        // type conversion from usual definitions
-        let natoms_c_int = natoms as c_int;
-        let atomic_numbers = numbers.iter().map(|&x| x as c_int).collect::<Vec<c_int>>();
-        // actual driver for creating the structure
-        let mut error = DFTD4Error::new();
-        let ptr = unsafe {
-            ffi::dftd4_new_structure(
-                error.get_c_ptr(),
-                natoms_c_int,
-                atomic_numbers.as_ptr(),
-                positions.as_ptr(),
-                charge_ptr,
-                lattice_ptr,
-                periodic_ptr,
-            )
+        self.positions.borrow_mut().copy_from_slice(positions);
+        if let Some(lattice) = lattice {
+            *self.lattice.borrow_mut() = Some(lattice.to_vec());
+        }
+        Ok(())
+    }
+
+    /// Update coordinates and lattice parameters (quantities in Bohr)
+    ///
+    /// # Arguments
+    ///
+    /// * `positions` - positions [natoms][3]
+    /// * `lattice` - lattice [3][3]
+    pub fn update(&self, positions: &[f64], lattice: Option<&[f64]>) {
+        self.update_f(positions, lattice).unwrap()
+    }
+
+    /// Indices of atoms that are not ghosts, if any atom is ghost
+    fn get_real_atoms(&self) -> Option<Vec<usize>> {
+        let ghosts = self.ghosts.as_ref()?;
+        Some((0..ghosts.len()).filter(|&i| !ghosts[i]).collect())
+    }
+
+    /// Expand values of real atoms [nreal][width] to all atoms [natoms][width]
+    fn scatter_atoms(&self, values: Vec<f64>) -> Vec<f64> {
+        let Some(real) = self.get_real_atoms() else {
+            return values;
         };
-        match error.check() {
-            true => Err(error),
-            false => Ok(Self { ptr, natoms }),
+        let width = values.len() / real.len();
+        let mut result = vec![0.0; self.numbers.len() * width];
+        for (k, &i) in real.iter().enumerate() {
+            result[i * width..(i + 1) * width].copy_from_slice(&values[k * width..(k + 1) * width]);
         }
+        result
+    }
+
+    /// Expand matrix of real atoms [nreal][block][nreal][block] to all atoms
+    fn scatter_pairs(&self, values: Vec<f64>, block: usize) -> Vec<f64> {
+        let Some(real) = self.get_real_atoms() else {
+            return values;
+        };
+        let (nreal, natoms) = (real.len() * block, self.numbers.len() * block);
+        let index = |k: usize| real[k / block] * block + k % block;
+        let mut result = vec![0.0; natoms * natoms];
+        for k in 0..nreal {
+            for l in 0..nreal {
+                result[index(k) * natoms + index(l)] = values[k * nreal + l];
+            }
+        }
+        result
+    }
+}
+
+impl DFTD4Structure {
+    /// Create new molecular structure data (quantities in Bohr) (failable)
+    pub fn new_f(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+    ) -> Result<Self, DFTD4Error> {
+        Self::new_with_ghosts_f(natoms, numbers, positions, charge, lattice, periodic, None)
+    }
+
+    /// Create new molecular structure data with ghost atoms (quantities in Bohr) (failable)
+    pub fn new_with_ghosts_f(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+        ghosts: Option<&[bool]>,
+    ) -> Result<Self, DFTD4Error> {
+        Self::new_with_backend_f(
+            natoms, numbers, positions, charge, lattice, periodic, ghosts,
+        )
+    }
+
+    /// Create new molecular structure data with ghost atoms (quantities in Bohr)
+    ///
+    /// Ghost atoms (e.g. of counterpoise corrections) are kept in the structure, but contribute
+    /// nothing to dispersion energies and coordination numbers. Results of all atoms keep their
+    /// dimensions, with zeros for ghost atoms.
+    ///
+    /// # Arguments
+    ///
+    /// * `ghosts` - whether atoms are ghost atoms [natoms]; at least one atom should not be ghost
+    pub fn new_with_ghosts(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+        ghosts: Option<&[bool]>,
+    ) -> Self {
+        Self::new_with_ghosts_f(
+            natoms, numbers, positions, charge, lattice, periodic, ghosts,
+        )
+        .unwrap()
+    }
+
+    /// Create new molecular structure data with spin multiplicity (quantities in Bohr) (failable)
+    pub fn new_with_multiplicity_f(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        multiplicity: Option<usize>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+    ) -> Result<Self, DFTD4Error> {
+        Self::new_f(natoms, numbers, positions, charge, lattice, periodic)?
+            .with_multiplicity_f(multiplicity)
+    }
+
+    /// Create new molecular structure data with spin multiplicity (quantities in Bohr)
+    ///
+    /// Multiplicity is validated against the parity of the number of electrons, e.g. a doublet
+    /// requires an odd number of electrons; the lowest multiplicity (singlet or doublet) is used if
+    /// `None`. D4 dispersion does not depend on spin (and the C API of `libdftd4` has no spin
+    /// argument); multiplicity is recorded for thermochemistry and output files.
+    pub fn new_with_multiplicity(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        multiplicity: Option<usize>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+    ) -> Self {
+        Self::new_with_multiplicity_f(
+            natoms,
+            numbers,
+            positions,
+            charge,
+            multiplicity,
+            lattice,
+            periodic,
+        )
+        .unwrap()
     }
 
     /// Create new molecular structure data (quantities in Bohr)
     ///
+    /// Lattices of periodic structures should be right-handed and not badly skewed (see
+    /// `lattice::check_lattice_f`). Vacuum vectors of 1D and 2D periodic structures are made
+    /// orthogonal to the periodic lattice vectors (see `lattice::orthogonalize_vacuum_f`).
+    /// Positions are wrapped into the cell before they are passed to the backend, and are kept as
+    /// given in the structure.
+    ///
     /// # Arguments
     ///
     /// * `numbers` - numbers [natoms]
@@ -184,107 +476,319 @@ impl DFTD4Structure {
     ) -> Self {
         Self::new_f(natoms, numbers, positions, charge, lattice, periodic).unwrap()
     }
+}
 
-    /// Update coordinates and lattice parameters (quantities in Bohr) (failable)
-    pub fn update_f(&self, positions: &[f64], lattice: Option<&[f64]>) -> Result<(), DFTD4Error> {
-        // check dimension
-        if positions.len() != 3 * self.natoms {
-            return Err(DFTD4Error::Rust(format!(
-                "Invalid dimension for positions, expected {}, got {}",
-                3 * self.natoms,
-                positions.len()
-            )));
-        }
-        if lattice.is_some_and(|lattice| lattice.len() != 9) {
-            return Err(DFTD4Error::Rust(format!(
-                "Invalid dimension for lattice, expected 9, got {}",
-                lattice.unwrap().len()
-            )));
-        }
-        // unwrap optional values
-        let lattice_ptr = lattice.map_or(null(), |x| x.as_ptr());
-        // actual driver for updating the structure
-        let mut error = DFTD4Error::new();
-        unsafe {
-            ffi::dftd4_update_structure(
-                error.get_c_ptr(),
-                self.ptr,
-                positions.as_ptr(),
-                lattice_ptr,
-            )
-        };
-        match error.check() {
-            true => Err(error),
-            false => Ok(()),
-        }
+/// Check dimensions of atomic data and ghost atoms
+fn check_ghosts(
+    natoms: usize,
+    numbers: &[usize],
+    positions: &[f64],
+    ghosts: &[bool],
+) -> Result<(), DFTD4Error> {
+    if numbers.len() != natoms || positions.len() != 3 * natoms || ghosts.len() != natoms {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for numbers, positions or ghosts, expected {}, {} and {}, got {}, {} and {}",
+            natoms,
+            3 * natoms,
+            natoms,
+            numbers.len(),
+            positions.len(),
+            ghosts.len()
+        )));
     }
+    if ghosts.iter().all(|&g| g) {
+        return Err(DFTD4Error::Rust("All atoms are ghost atoms".to_string()));
+    }
+    Ok(())
+}
 
-    /// Update coordinates and lattice parameters (quantities in Bohr)
-    ///
-    /// # Arguments
-    ///
-    /// * `positions` - positions [natoms][3]
-    /// * `lattice` - lattice [3][3]
-    pub fn update(&self, positions: &[f64], lattice: Option<&[f64]>) {
-        self.update_f(positions, lattice).unwrap()
+/// Check lattice of periodic structures strictly, and wrap positions into the cell (failable)
+///
+/// Lattices without periodic directions are only validated, and positions are kept.
+fn wrap_into_cell_f(
+    positions: &[f64],
+    lattice: Option<&[f64]>,
+    periodic: Option<&[bool]>,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let Some(lattice) = lattice else {
+        return Ok(positions.to_vec());
+    };
+    if periodic.is_some_and(|periodic| periodic.iter().all(|&p| !p)) {
+        validate_lattice_f(lattice, periodic)?;
+        return Ok(positions.to_vec());
     }
+    check_lattice_f(lattice, periodic)?;
+    wrap_positions_f(positions, lattice, periodic)
 }
 
-pub struct DFTD4Model {
-    ptr: ffi::dftd4_model,
+/// Select values [n][width] of indices
+fn gather(indices: &[usize], values: &[f64], width: usize) -> Vec<f64> {
+    indices
+        .iter()
+        .flat_map(|&i| values[i * width..(i + 1) * width].iter().copied())
+        .collect()
 }
 
-impl Drop for DFTD4Model {
-    fn drop(&mut self) {
-        unsafe { ffi::dftd4_delete_model(&mut self.ptr) };
+/// Real-space cutoffs (Bohr) of the dispersion model
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DFTD4Cutoff {
+    /// cutoff of coordination numbers
+    pub cn: f64,
+    /// cutoff of two-body dispersion
+    pub disp2: f64,
+    /// cutoff of three-body dispersion
+    pub disp3: f64,
+}
+
+impl Default for DFTD4Cutoff {
+    /// Default cutoffs of upstream dftd4
+    fn default() -> Self {
+        Self {
+            cn: 30.0,
+            disp2: 60.0,
+            disp3: 40.0,
+        }
     }
 }
 
-impl DFTD4Model {
+/// D4 dispersion model, evaluated by backend `B` (`DefaultBackend` if omitted)
+pub struct DFTD4Model<B: DispersionBackend = DefaultBackend> {
+    inner: B::Model,
+    /// indices of atoms that are not ghosts, if any atom is ghost
+    real: Option<Vec<usize>>,
+    natoms: usize,
+    /// `[ga, gc, gf]`, if created with custom charge scaling and weighting
+    scaling: Option<[f64; 3]>,
+    /// custom atomic charges [natoms], if any
+    charges: Option<Vec<f64>>,
+}
+
+impl<B: DispersionBackend> DFTD4Model<B> {
     /// Create new D4 dispersion model (failable)
-    pub fn new_f(structure: &DFTD4Structure) -> Result<Self, DFTD4Error> {
-        let mut error = DFTD4Error::new();
-        let ptr = unsafe { ffi::dftd4_new_d4_model(error.get_c_ptr(), structure.ptr) };
-        match error.check() {
-            true => Err(error),
-            false => Ok(Self { ptr }),
-        }
+    pub fn new_f(structure: &DFTD4Structure<B>) -> Result<Self, DFTD4Error> {
+        let inner = B::new_model_f(&structure.inner)?;
+        Ok(Self {
+            inner,
+            real: structure.get_real_atoms(),
+            natoms: structure.get_natoms(),
+            scaling: None,
+            charges: None,
+        })
     }
 
     /// Create new D4 dispersion model
-    pub fn new(structure: &DFTD4Structure) -> Self {
+    pub fn new(structure: &DFTD4Structure<B>) -> Self {
         Self::new_f(structure).unwrap()
     }
 
     /// Create new D4 dispersion model (failable)
     pub fn custom_f(
-        structure: &DFTD4Structure,
+        structure: &DFTD4Structure<B>,
         ga: f64,
         gc: f64,
         gf: f64,
     ) -> Result<Self, DFTD4Error> {
-        let mut error = DFTD4Error::new();
-        let ptr =
-            unsafe { ffi::dftd4_custom_d4_model(error.get_c_ptr(), structure.ptr, ga, gc, gf) };
-        match error.check() {
-            true => Err(error),
-            false => Ok(Self { ptr }),
-        }
+        let inner = B::custom_model_f(&structure.inner, ga, gc, gf)?;
+        Ok(Self {
+            inner,
+            real: structure.get_real_atoms(),
+            natoms: structure.get_natoms(),
+            scaling: Some([ga, gc, gf]),
+            charges: None,
+        })
     }
 
     /// Create new D4 dispersion model
-    pub fn custom(structure: &DFTD4Structure, ga: f64, gc: f64, gf: f64) -> Self {
+    pub fn custom(structure: &DFTD4Structure<B>, ga: f64, gc: f64, gf: f64) -> Self {
         Self::custom_f(structure, ga, gc, gf).unwrap()
     }
+
+    /// Create new D4 dispersion model with custom atomic charges (failable)
+    pub fn new_with_charges_f(
+        structure: &DFTD4Structure<B>,
+        charges: &[f64],
+    ) -> Result<Self, DFTD4Error> {
+        let natoms = structure.get_natoms();
+        if charges.len() != natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for charges, expected {}, got {}",
+                natoms,
+                charges.len()
+            )));
+        }
+        let mut model = Self::new_f(structure)?;
+        model.set_charges_f(Some(charges))?;
+        Ok(model)
+    }
+
+    /// Create new D4 dispersion model with custom atomic charges
+    ///
+    /// Charges (e.g. Mulliken, Hirshfeld or CM5 from a SCF calculation) replace EEQ charges in
+    /// the charge scaling of reference polarizabilities, and are kept fixed when positions change.
+    /// Only supported by the pure-Rust implementation.
+    ///
+    /// # Arguments
+    ///
+    /// * `charges` - atomic partial charges [natoms]
+    pub fn new_with_charges(structure: &DFTD4Structure<B>, charges: &[f64]) -> Self {
+        Self::new_with_charges_f(structure, charges).unwrap()
+    }
+
+    /// Set custom atomic charges, or EEQ charges if `None` (failable)
+    pub fn set_charges_f(&mut self, charges: Option<&[f64]>) -> Result<(), DFTD4Error> {
+        if charges.is_some_and(|charges| charges.len() != self.natoms) {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for charges, expected {}, got {}",
+                self.natoms,
+                charges.unwrap().len()
+            )));
+        }
+        match (charges, &self.real) {
+            // charges of ghost atoms are ignored
+            (Some(charges), Some(real)) => {
+                let charges = gather(real, charges, 1);
+                B::set_charges_f(&mut self.inner, Some(&charges))?
+            }
+            _ => B::set_charges_f(&mut self.inner, charges)?,
+        }
+        self.charges = charges.map(|charges| charges.to_vec());
+        Ok(())
+    }
+
+    /// Set custom atomic charges [natoms], or EEQ charges if `None`
+    ///
+    /// Only the pure-Rust implementation supports custom charges.
+    pub fn set_charges(&mut self, charges: Option<&[f64]>) {
+        self.set_charges_f(charges).unwrap()
+    }
+
+    /// Get real-space cutoffs
+    pub fn get_cutoff(&self) -> DFTD4Cutoff {
+        B::get_cutoff(&self.inner)
+    }
+
+    /// Set real-space cutoffs (failable)
+    pub fn set_cutoff_f(&mut self, cutoff: DFTD4Cutoff) -> Result<(), DFTD4Error> {
+        if [cutoff.cn, cutoff.disp2, cutoff.disp3]
+            .iter()
+            .any(|&c| c <= 0.0)
+        {
+            return Err(DFTD4Error::Rust(format!(
+                "Cutoffs should be positive, got {:?}",
+                cutoff
+            )));
+        }
+        B::set_cutoff_f(&mut self.inner, cutoff)
+    }
+
+    /// Set real-space cutoffs
+    ///
+    /// Only the pure-Rust implementation supports cutoffs other than the defaults; `libdftd4`
+    /// through its C API always uses the defaults of upstream dftd4.
+    pub fn set_cutoff(&mut self, cutoff: DFTD4Cutoff) {
+        self.set_cutoff_f(cutoff).unwrap()
+    }
+
+    /// Create new D4 dispersion model of a structure derived from the structure of this model
+    /// (failable)
+    pub fn new_derived_f(
+        &self,
+        structure: &DFTD4Structure<B>,
+        atoms: &[usize],
+    ) -> Result<Self, DFTD4Error> {
+        if atoms.len() != structure.get_natoms() {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for atoms, expected {}, got {}",
+                structure.get_natoms(),
+                atoms.len()
+            )));
+        }
+        if let Some(&i) = atoms.iter().find(|&&i| i >= self.natoms) {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid atom index {}, number of atoms is {}",
+                i, self.natoms
+            )));
+        }
+        let mut model = match self.scaling {
+            Some([ga, gc, gf]) => Self::custom_f(structure, ga, gc, gf)?,
+            None => Self::new_f(structure)?,
+        };
+        model.set_cutoff_f(self.get_cutoff())?;
+        if let Some(charges) = &self.charges {
+            let charges = atoms.iter().map(|&i| charges[i]).collect::<Vec<f64>>();
+            model.set_charges_f(Some(&charges))?;
+        }
+        Ok(model)
+    }
+
+    /// Create new D4 dispersion model of a structure derived from the structure of this model
+    ///
+    /// The new model keeps the charge scaling and weighting, the cutoffs and the custom charges of
+    /// this model, e.g. for fragments or supercells.
+    ///
+    /// # Arguments
+    ///
+    /// * `atoms` - index of each atom of `structure` in the structure of this model [natoms]
+    pub fn new_derived(&self, structure: &DFTD4Structure<B>, atoms: &[usize]) -> Self {
+        self.new_derived_f(structure, atoms).unwrap()
+    }
 }
 
-pub struct DFTD4Param {
-    ptr: ffi::dftd4_param,
+/// Damping parameters, evaluated by backend `B` (`DefaultBackend` if omitted)
+pub struct DFTD4Param<B: DispersionBackend = DefaultBackend> {
+    inner: B::Param,
+    /// `[s6, s8, s9, a1, a2, alp]`, if created from explicit values
+    values: Option<[f64; 6]>,
 }
 
-impl Drop for DFTD4Param {
-    fn drop(&mut self) {
-        unsafe { ffi::dftd4_delete_param(&mut self.ptr) };
+impl<B: DispersionBackend> DFTD4Param<B> {
+    /// Create new rational damping parameters, evaluated by backend `B` (failable)
+    ///
+    /// Same as [`DFTD4Param::new_rational_damping_f`] for other backends than `DefaultBackend`.
+    pub fn new_rational_damping_with_backend_f(
+        s6: f64,
+        s8: f64,
+        s9: f64,
+        a1: f64,
+        a2: f64,
+        alp: f64,
+    ) -> Result<Self, DFTD4Error> {
+        let inner = B::new_rational_damping_f(s6, s8, s9, a1, a2, alp)?;
+        Ok(Self {
+            inner,
+            values: Some([s6, s8, s9, a1, a2, alp]),
+        })
+    }
+
+    /// Load rational damping parameters from internal storage, evaluated by backend `B`
+    /// (failable)
+    ///
+    /// Same as [`DFTD4Param::load_rational_damping_f`] for other backends than `DefaultBackend`.
+    pub fn load_rational_damping_with_backend_f(
+        method: &str,
+        mdb: bool,
+    ) -> Result<Self, DFTD4Error> {
+        let inner = B::load_rational_damping_f(method, mdb)?;
+        Ok(Self {
+            inner,
+            values: None,
+        })
+    }
+
+    /// Get rational damping parameters `[s6, s8, s9, a1, a2, alp]` (failable)
+    pub fn get_rational_damping_f(&self) -> Result<[f64; 6], DFTD4Error> {
+        match self.values {
+            Some(values) => Ok(values),
+            None => B::get_rational_damping_f(&self.inner),
+        }
+    }
+
+    /// Get rational damping parameters `[s6, s8, s9, a1, a2, alp]`
+    ///
+    /// Values of loaded parameters are only available in the pure-Rust implementation; `libdftd4`
+    /// through its C API only knows the values of parameters created by `new_rational_damping`.
+    pub fn get_rational_damping(&self) -> [f64; 6] {
+        self.get_rational_damping_f().unwrap()
     }
 }
 
@@ -298,13 +802,7 @@ impl DFTD4Param {
         a2: f64,
         alp: f64,
     ) -> Result<Self, DFTD4Error> {
-        let mut error = DFTD4Error::new();
-        let ptr =
-            unsafe { ffi::dftd4_new_rational_damping(error.get_c_ptr(), s6, s8, s9, a1, a2, alp) };
-        match error.check() {
-            true => Err(error),
-            false => Ok(Self { ptr }),
-        }
+        Self::new_rational_damping_with_backend_f(s6, s8, s9, a1, a2, alp)
     }
 
     /// Create new rational damping parameters
@@ -314,15 +812,7 @@ impl DFTD4Param {
 
     /// Load rational damping parameters from internal storage (failble)
     pub fn load_rational_damping_f(method: &str, mdb: bool) -> Result<Self, DFTD4Error> {
-        let mut error = DFTD4Error::new();
-        let name_c = std::ffi::CString::new(method).unwrap();
-        let ptr = unsafe {
-            ffi::dftd4_load_rational_damping(error.get_c_ptr(), name_c.as_ptr() as *mut c_char, mdb)
-        };
-        match error.check() {
-            true => Err(error),
-            false => Ok(Self { ptr }),
-        }
+        Self::load_rational_damping_with_backend_f(method, mdb)
     }
 
     /// Load rational damping parameters from internal storage
@@ -332,124 +822,249 @@ impl DFTD4Param {
 }
 
 /// Evaluate properties related to the dispersion model (failable)
-pub fn get_properties_f(
-    structure: &DFTD4Structure,
-    model: &DFTD4Model,
-) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>), DFTD4Error> {
-    let mut error = DFTD4Error::new();
-    let natoms = structure.get_natoms();
-    let mut cn = vec![0.0; natoms];
-    let mut charges = vec![0.0; natoms];
-    let mut c6 = vec![0.0; natoms * natoms];
-    let mut alpha = vec![0.0; natoms];
-    unsafe {
-        ffi::dftd4_get_properties(
-            error.get_c_ptr(),
-            structure.ptr,
-            model.ptr,
-            cn.as_mut_ptr(),
-            charges.as_mut_ptr(),
-            c6.as_mut_ptr(),
-            alpha.as_mut_ptr(),
-        )
-    };
-    match error.check() {
-        true => Err(error),
-        false => Ok((cn, charges, c6, alpha)),
-    }
+pub fn get_properties_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+) -> Result<DFTD4Properties, DFTD4Error> {
+    let (cn, q, c6, alpha) = B::get_properties_f(&structure.inner, &model.inner)?;
+    Ok((
+        structure.scatter_atoms(cn),
+        structure.scatter_atoms(q),
+        structure.scatter_pairs(c6, 1),
+        structure.scatter_atoms(alpha),
+    ))
 }
 
 /// Evaluate properties related to the dispersion model
-pub fn get_properties(
-    structure: &DFTD4Structure,
-    model: &DFTD4Model,
+pub fn get_properties<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
 ) -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
     get_properties_f(structure, model).unwrap()
 }
 
+/// Evaluate the derivative of the dispersion energy with respect to atomic charges (failable)
+pub fn get_charge_derivatives_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let dedq = B::get_charge_derivatives_f(&structure.inner, &model.inner, &param.inner)?;
+    Ok(structure.scatter_atoms(dedq))
+}
+
+/// Evaluate the derivative of the dispersion energy with respect to atomic charges
+///
+/// Returns `dE/dq` [natoms] at fixed positions, as needed for self-consistent D4. Only the
+/// two-body term depends on charges. Only available in the pure-Rust implementation.
+pub fn get_charge_derivatives<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
+) -> Vec<f64> {
+    get_charge_derivatives_f(structure, model, param).unwrap()
+}
+
+/// Evaluate dynamic polarizabilities at imaginary frequencies (failable)
+pub fn get_dynamic_polarizabilities_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let alpha = B::get_dynamic_polarizabilities_f(&structure.inner, &model.inner)?;
+    Ok(structure.scatter_atoms(alpha))
+}
+
+/// Evaluate dynamic polarizabilities at imaginary frequencies
+///
+/// Returns polarizabilities [natoms][nfreq] at the imaginary frequencies `native::FREQ` of the
+/// D4 model. Only available with feature `pure-rust`, as `libdftd4` has no dynamic
+/// polarizabilities in its C API.
+pub fn get_dynamic_polarizabilities<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+) -> Vec<f64> {
+    get_dynamic_polarizabilities_f(structure, model).unwrap()
+}
+
+/// Get scaling factors of C8 coefficients (failable)
+pub fn get_r4r2_f(numbers: &[usize]) -> Result<Vec<f64>, DFTD4Error> {
+    crate::native::get_r4r2_f(numbers)
+}
+
+/// Get scaling factors of C8 coefficients
+///
+/// Returns `sqrt(0.5 * sqrt(Z) * <r4>/<r2>)` [natoms] of atomic numbers, such that
+/// `C8 = 3 C6 r4r2[i] r4r2[j]`. Values are taken from the reference data of dftd4 shipped with
+/// this crate, for all backends.
+pub fn get_r4r2(numbers: &[usize]) -> Vec<f64> {
+    get_r4r2_f(numbers).unwrap()
+}
+
 /// Evaluate the dispersion energy and its derivative (failable)
-pub fn get_dispersion_f(
-    structure: &DFTD4Structure,
-    model: &DFTD4Model,
-    param: &DFTD4Param,
+pub fn get_dispersion_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
     eval_grad: bool,
     eval_sigma: bool,
-) -> Result<(f64, Option<Vec<f64>>, Option<Vec<f64>>), DFTD4Error> {
-    let natoms = structure.get_natoms();
-    let mut energy = 0.0;
-    let mut grad = match eval_grad {
-        true => Some(vec![0.0; 3 * natoms]),
-        false => None,
-    };
-    let mut sigma = match eval_sigma {
-        true => Some(vec![0.0; 9]),
-        false => None,
+) -> Result<DFTD4Dispersion, DFTD4Error> {
+    let (energy, grad, sigma) = B::get_dispersion_f(
+        &structure.inner,
+        &model.inner,
+        &param.inner,
+        eval_grad,
+        eval_sigma,
+    )?;
+    // virial of 1D and 2D periodic structures is restricted to periodic directions
+    let sigma = match (structure.get_lattice(), structure.get_periodic()) {
+        (Some(lattice), Some(periodic)) => sigma.map(|s| restrict_sigma(&s, &lattice, &periodic)),
+        _ => sigma,
     };
-    let mut error = DFTD4Error::new();
-    unsafe {
-        ffi::dftd4_get_dispersion(
-            error.get_c_ptr(),
-            structure.ptr,
-            model.ptr,
-            param.ptr,
-            &mut energy,
-            grad.as_mut().map_or(null_mut(), |x| x.as_mut_ptr()),
-            sigma.as_mut().map_or(null_mut(), |x| x.as_mut_ptr()),
-        )
-    };
-    match error.check() {
-        true => Err(error),
-        false => Ok((energy, grad, sigma)),
-    }
+    Ok((energy, grad.map(|g| structure.scatter_atoms(g)), sigma))
 }
 
 /// Evaluate the dispersion energy and its derivative
-pub fn get_dispersion(
-    structure: &DFTD4Structure,
-    model: &DFTD4Model,
-    param: &DFTD4Param,
+///
+/// For 1D and 2D periodic structures, the virial is restricted to periodic directions (see
+/// `lattice::restrict_sigma`).
+pub fn get_dispersion<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
     eval_grad: bool,
     eval_sigma: bool,
 ) -> (f64, Option<Vec<f64>>, Option<Vec<f64>>) {
     get_dispersion_f(structure, model, param, eval_grad, eval_sigma).unwrap()
 }
 
+/// Evaluate the dispersion energy for several damping parameters (failable)
+pub fn get_dispersion_multi_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    params: &[&DFTD4Param<B>],
+    eval_grad: bool,
+) -> Result<Vec<DFTD4EnergyGradient>, DFTD4Error> {
+    let params = params.iter().map(|p| &p.inner).collect::<Vec<&B::Param>>();
+    let results = B::get_dispersion_multi_f(&structure.inner, &model.inner, &params, eval_grad)?;
+    Ok(results
+        .into_iter()
+        .map(|(energy, grad)| (energy, grad.map(|g| structure.scatter_atoms(g))))
+        .collect())
+}
+
+/// Evaluate the dispersion energy for several damping parameters
+///
+/// Equivalent to `get_dispersion` for each of `params`. The pure-Rust implementation evaluates
+/// the dispersion model (coordination numbers, charges and C6 coefficients) once; with
+/// `libdftd4`, whose C API evaluates the model for each parameter, this is a convenience loop over
+/// `get_dispersion` (unless the model has custom cutoffs or charges, and is evaluated in Rust).
+///
+/// # Returns
+///
+/// Dispersion energy and optionally gradient [natoms][3] for each of `params`.
+pub fn get_dispersion_multi<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    params: &[&DFTD4Param<B>],
+    eval_grad: bool,
+) -> Vec<(f64, Option<Vec<f64>>)> {
+    get_dispersion_multi_f(structure, model, params, eval_grad).unwrap()
+}
+
 /// Evaluate the pairwise representation of the dispersion energy (failable)
-pub fn get_pairwise_dispersion_f(
-    structure: &DFTD4Structure,
-    model: &DFTD4Model,
-    param: &DFTD4Param,
+pub fn get_pairwise_dispersion_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
 ) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
-    let natoms = structure.get_natoms();
-    let mut pair_energy2 = vec![0.0; natoms * natoms];
-    let mut pair_energy3 = vec![0.0; natoms * natoms];
-    let mut error = DFTD4Error::new();
-
-    unsafe {
-        ffi::dftd4_get_pairwise_dispersion(
-            error.get_c_ptr(),
-            structure.ptr,
-            model.ptr,
-            param.ptr,
-            pair_energy2.as_mut_ptr(),
-            pair_energy3.as_mut_ptr(),
-        )
-    };
-    match error.check() {
-        true => Err(error),
-        false => Ok((pair_energy2, pair_energy3)),
-    }
+    let (pair2, pair3) =
+        B::get_pairwise_dispersion_f(&structure.inner, &model.inner, &param.inner)?;
+    Ok((
+        structure.scatter_pairs(pair2, 1),
+        structure.scatter_pairs(pair3, 1),
+    ))
 }
 
 /// Evaluate the pairwise representation of the dispersion energy
-pub fn get_pairwise_dispersion(
-    structure: &DFTD4Structure,
-    model: &DFTD4Model,
-    param: &DFTD4Param,
+pub fn get_pairwise_dispersion<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
 ) -> (Vec<f64>, Vec<f64>) {
     get_pairwise_dispersion_f(structure, model, param).unwrap()
 }
 
+/// Evaluate the atom-resolved dispersion energy (failable)
+pub fn get_atomic_dispersion_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
+    eval_grad_norm: bool,
+) -> Result<DFTD4AtomicDispersion, DFTD4Error> {
+    let natoms = structure.get_natoms();
+    let (pair_energy2, pair_energy3) = get_pairwise_dispersion_f(structure, model, param)?;
+    let atomic_energy2 = pair_energy2
+        .chunks(natoms)
+        .map(|row| row.iter().sum())
+        .collect::<Vec<f64>>();
+    let atomic_energy3 = pair_energy3
+        .chunks(natoms)
+        .map(|row| row.iter().sum())
+        .collect::<Vec<f64>>();
+    let atomic_energy = atomic_energy2
+        .iter()
+        .zip(&atomic_energy3)
+        .map(|(e2, e3)| e2 + e3)
+        .collect();
+    let grad_norm = match eval_grad_norm {
+        true => {
+            let (_, grad, _) = get_dispersion_f(structure, model, param, true, false)?;
+            let grad = grad.unwrap();
+            Some(
+                grad.chunks(3)
+                    .map(|g| g.iter().map(|x| x * x).sum::<f64>().sqrt())
+                    .collect(),
+            )
+        }
+        false => None,
+    };
+    Ok((atomic_energy2, atomic_energy3, atomic_energy, grad_norm))
+}
+
+/// Evaluate the atom-resolved dispersion energy
+///
+/// Returns two-body, three-body and total energies of atoms [natoms], which sum to the dispersion
+/// energy; and optionally norm of the gradient of atoms [natoms].
+pub fn get_atomic_dispersion<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
+    eval_grad_norm: bool,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, Option<Vec<f64>>) {
+    get_atomic_dispersion_f(structure, model, param, eval_grad_norm).unwrap()
+}
+
+/// Evaluate the dispersion hessian numerically (failable)
+pub fn get_numerical_hessian_f<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let hessian = B::get_numerical_hessian_f(&structure.inner, &model.inner, &param.inner)?;
+    Ok(structure.scatter_pairs(hessian, 3))
+}
+
+/// Evaluate the dispersion hessian numerically
+///
+/// Returns hessian [natoms][3][natoms][3].
+pub fn get_numerical_hessian<B: DispersionBackend>(
+    structure: &DFTD4Structure<B>,
+    model: &DFTD4Model<B>,
+    param: &DFTD4Param<B>,
+) -> Vec<f64> {
+    get_numerical_hessian_f(structure, model, param).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
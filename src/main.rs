@@ -1,3 +1,136 @@
+use rest_dftd4::conformer::*;
+use rest_dftd4::library::DFTD4Error;
+
+const USAGE: &str = "\
+Usage: rest_dftd4 rank <ensemble.xyz> [options]
+
+Rank conformers of a multi-frame XYZ file by D4 dispersion energies.
+
+Options:
+  --method <name>       functional of damping parameters (default: PBE0)
+  --no-atm              skip the three-body term
+  --charge <charge>     total charge of conformers
+  --multiplicity <n>    spin multiplicity of conformers (default: singlet or doublet)
+  --energies <file>     base energies (Hartree) of frames, one per line
+  --threads <n>         number of threads (default: available parallelism)
+  --output <file>       sorted ensemble (default: <ensemble>.sorted.xyz)";
+
+/// Options of the `rank` subcommand
+struct RankOptions {
+    input: String,
+    energies: Option<String>,
+    output: Option<String>,
+    config: ConformerConfig,
+}
+
+fn parse_rank_options(args: &[String]) -> Result<RankOptions, DFTD4Error> {
+    let mut input = None;
+    let mut energies = None;
+    let mut output = None;
+    let mut config = ConformerConfig::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| DFTD4Error::Rust(format!("Missing value of option {}", arg)))
+        };
+        let invalid =
+            |value: &str| DFTD4Error::Rust(format!("Invalid value of {}: {}", arg, value));
+        match arg.as_str() {
+            "--method" => config.method = value()?.clone(),
+            "--no-atm" => config.mdb = false,
+            "--charge" => {
+                let value = value()?;
+                config.charge = Some(value.parse().map_err(|_| invalid(value))?);
+            }
+            "--multiplicity" => {
+                let value = value()?;
+                config.multiplicity = Some(value.parse().map_err(|_| invalid(value))?);
+            }
+            "--energies" => energies = Some(value()?.clone()),
+            "--threads" => {
+                let value = value()?;
+                config.nthreads = value.parse().map_err(|_| invalid(value))?;
+            }
+            "--output" => output = Some(value()?.clone()),
+            _ if arg.starts_with("--") => {
+                return Err(DFTD4Error::Rust(format!("Unknown option {}", arg)))
+            }
+            _ if input.is_none() => input = Some(arg.clone()),
+            _ => return Err(DFTD4Error::Rust(format!("Unexpected argument {}", arg))),
+        }
+    }
+    let input = input.ok_or_else(|| DFTD4Error::Rust("Missing ensemble file".to_string()))?;
+    Ok(RankOptions {
+        input,
+        energies,
+        output,
+        config,
+    })
+}
+
+fn read_file(path: &str) -> Result<String, DFTD4Error> {
+    std::fs::read_to_string(path)
+        .map_err(|e| DFTD4Error::Rust(format!("Cannot read file {}: {}", path, e)))
+}
+
+fn run_rank(args: &[String]) -> Result<(), DFTD4Error> {
+    let options = parse_rank_options(args)?;
+    let frames = read_xyz_frames_f(&read_file(&options.input)?)?;
+    let base_energies = match &options.energies {
+        Some(path) => Some(
+            read_file(path)?
+                .split_whitespace()
+                .map(|x| {
+                    x.parse::<f64>()
+                        .map_err(|_| DFTD4Error::Rust(format!("Invalid energy in {}: {}", path, x)))
+                })
+                .collect::<Result<Vec<f64>, DFTD4Error>>()?,
+        ),
+        None => None,
+    };
+    let ranking = rank_conformers_f(&frames, base_energies.as_deref(), &options.config)?;
+
+    let output = options.output.unwrap_or_else(|| {
+        let stem = options.input.strip_suffix(".xyz").unwrap_or(&options.input);
+        format!("{}.sorted.xyz", stem)
+    });
+    std::fs::write(&output, get_xyz_ensemble_f(&frames, &ranking)?)
+        .map_err(|e| DFTD4Error::Rust(format!("Cannot write file {}: {}", output, e)))?;
+
+    println!(
+        "{:>6} {:>6} {:>18} {:>18} {:>12}",
+        "rank", "frame", "E(total)/Eh", "E(disp)/Eh", "dE/kcal/mol"
+    );
+    for (rank, &k) in ranking.order.iter().enumerate() {
+        println!(
+            "{:>6} {:>6} {:>18.10} {:>18.10} {:>12.4}",
+            rank + 1,
+            k + 1,
+            ranking.total[k],
+            ranking.dispersion[k],
+            ranking.relative[k]
+        );
+    }
+    println!("Sorted ensemble written to {}", output);
+    Ok(())
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    let result = match args.first().map(|x| x.as_str()) {
+        Some("rank") => run_rank(&args[1..]),
+        Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            return;
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
 }
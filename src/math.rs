@@ -0,0 +1,244 @@
+//! Small numerical helpers (special functions, dense linear algebra in row-major storage).
+
+/// Error function.
+///
+/// Uses the series expansion for small arguments and the continued fraction of `erfc` for large
+/// arguments; both are accurate to machine precision.
+pub(crate) fn erf(x: f64) -> f64 {
+    let ax = x.abs();
+    let val = if ax < 3.0 {
+        // erf(x) = 2/sqrt(pi) exp(-x^2) sum_n 2^n x^(2n+1) / (2n+1)!!
+        let x2 = ax * ax;
+        let mut term = ax;
+        let mut sum = ax;
+        let mut n = 0.0;
+        while term > sum * f64::EPSILON {
+            n += 1.0;
+            term *= 2.0 * x2 / (2.0 * n + 1.0);
+            sum += term;
+        }
+        2.0 / std::f64::consts::PI.sqrt() * (-x2).exp() * sum
+    } else {
+        // erfc(x) = exp(-x^2)/sqrt(pi) / (x + (1/2)/(x + 1/(x + (3/2)/(x + ...))))
+        let mut frac = 0.0;
+        for k in (1..=60).rev() {
+            frac = (k as f64 / 2.0) / (ax + frac);
+        }
+        1.0 - (-ax * ax).exp() / std::f64::consts::PI.sqrt() / (ax + frac)
+    };
+    val.copysign(x)
+}
+
+/// LU decomposition with partial pivoting of a square matrix.
+pub(crate) struct LU {
+    n: usize,
+    lu: Vec<f64>,
+    piv: Vec<usize>,
+}
+
+impl LU {
+    /// Factorize a square matrix `a` [n][n]; returns `None` if the matrix is singular.
+    pub(crate) fn new(n: usize, a: &[f64]) -> Option<Self> {
+        let mut lu = a.to_vec();
+        let mut piv = (0..n).collect::<Vec<usize>>();
+        for k in 0..n {
+            let p = (k..n).max_by(|&i, &j| lu[i * n + k].abs().total_cmp(&lu[j * n + k].abs()))?;
+            if lu[p * n + k].abs() < f64::MIN_POSITIVE {
+                return None;
+            }
+            if p != k {
+                for j in 0..n {
+                    lu.swap(p * n + j, k * n + j);
+                }
+                piv.swap(p, k);
+            }
+            for i in k + 1..n {
+                let f = lu[i * n + k] / lu[k * n + k];
+                lu[i * n + k] = f;
+                for j in k + 1..n {
+                    lu[i * n + j] -= f * lu[k * n + j];
+                }
+            }
+        }
+        Some(Self { n, lu, piv })
+    }
+
+    /// Solve `a x = b` for a single right-hand side.
+    pub(crate) fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.n;
+        let mut x = self.piv.iter().map(|&p| b[p]).collect::<Vec<f64>>();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+            x[i] /= self.lu[i * n + i];
+        }
+        x
+    }
+}
+
+/// Product of 3x3 matrices
+pub(crate) fn matmul3(a: &[f64], b: &[f64]) -> [f64; 9] {
+    let mut c = [0.0; 9];
+    for i in 0..3 {
+        for j in 0..3 {
+            c[3 * i + j] = (0..3).map(|k| a[3 * i + k] * b[3 * k + j]).sum();
+        }
+    }
+    c
+}
+
+/// Transpose of 3x3 matrix
+pub(crate) fn transpose3(a: &[f64]) -> [f64; 9] {
+    [a[0], a[3], a[6], a[1], a[4], a[7], a[2], a[5], a[8]]
+}
+
+/// Determinant of 3x3 matrix
+pub(crate) fn det3(a: &[f64]) -> f64 {
+    a[0] * (a[4] * a[8] - a[5] * a[7]) - a[1] * (a[3] * a[8] - a[5] * a[6])
+        + a[2] * (a[3] * a[7] - a[4] * a[6])
+}
+
+/// Inverse of 3x3 matrix, `None` if singular
+pub(crate) fn inv3(a: &[f64]) -> Option<[f64; 9]> {
+    let det = det3(a);
+    if det.abs() < f64::EPSILON {
+        return None;
+    }
+    let cof = [
+        a[4] * a[8] - a[5] * a[7],
+        a[2] * a[7] - a[1] * a[8],
+        a[1] * a[5] - a[2] * a[4],
+        a[5] * a[6] - a[3] * a[8],
+        a[0] * a[8] - a[2] * a[6],
+        a[2] * a[3] - a[0] * a[5],
+        a[3] * a[7] - a[4] * a[6],
+        a[1] * a[6] - a[0] * a[7],
+        a[0] * a[4] - a[1] * a[3],
+    ];
+    Some(cof.map(|x| x / det))
+}
+
+/// Eigenvalues and eigenvectors of a symmetric matrix `a` [n][n] (cyclic Jacobi method).
+///
+/// Returns eigenvalues [n] in ascending order and eigenvectors [n][n], one eigenvector per row.
+pub(crate) fn eigh(n: usize, a: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut a = a.to_vec();
+    // rows of `v` are eigenvectors
+    let mut v = vec![0.0; n * n];
+    (0..n).for_each(|i| v[i * n + i] = 1.0);
+    let norm = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    for _ in 0..100 {
+        let off = (0..n)
+            .flat_map(|i| (0..i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i * n + j].powi(2))
+            .sum::<f64>()
+            .sqrt();
+        if off <= f64::EPSILON * norm {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq.abs() <= f64::MIN_POSITIVE {
+                    continue;
+                }
+                let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vpk, vqk) = (v[p * n + k], v[q * n + k]);
+                    v[p * n + k] = c * vpk - s * vqk;
+                    v[q * n + k] = s * vpk + c * vqk;
+                }
+            }
+        }
+    }
+    let mut order = (0..n).collect::<Vec<usize>>();
+    order.sort_by(|&i, &j| a[i * n + i].total_cmp(&a[j * n + j]));
+    let eigenvalues = order.iter().map(|&i| a[i * n + i]).collect();
+    let eigenvectors = order
+        .iter()
+        .flat_map(|&i| v[i * n..(i + 1) * n].to_vec())
+        .collect();
+    (eigenvalues, eigenvectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erf() {
+        assert!((erf(0.5) - 0.5204998778130465).abs() < 1e-15);
+        assert!((erf(-1.5) - -0.9661051464753108).abs() < 1e-15);
+        assert!((erf(2.9) - 0.9999589021219005).abs() < 1e-15);
+        assert!((erf(3.5) - 0.9999992569016276).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let a = [4.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 3.0];
+        let b = [1.0, 2.0, 3.0];
+        let x = LU::new(3, &a).unwrap().solve(&b);
+        for i in 0..3 {
+            let ax = (0..3).map(|j| a[i * 3 + j] * x[j]).sum::<f64>();
+            assert!((ax - b[i]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_inv3() {
+        let a = [2.0, 0.5, 0.1, 0.3, 3.0, -0.2, 0.0, 0.4, 1.5];
+        let inv = inv3(&a).unwrap();
+        let prod = matmul3(&a, &inv);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((prod[3 * i + j] - expected).abs() < 1e-14);
+            }
+        }
+        assert!((det3(&a) * det3(&inv) - 1.0).abs() < 1e-14);
+        assert_eq!(transpose3(&transpose3(&a)), a);
+    }
+
+    #[test]
+    fn test_eigh() {
+        let n = 4;
+        #[rustfmt::skip]
+        let a = [
+            4.0, 1.0, -2.0, 2.0,
+            1.0, 2.0, 0.0, 1.0,
+            -2.0, 0.0, 3.0, -2.0,
+            2.0, 1.0, -2.0, -1.0,
+        ];
+        let (w, v) = eigh(n, &a);
+        assert!(w.windows(2).all(|x| x[0] <= x[1]));
+        for k in 0..n {
+            for i in 0..n {
+                let av = (0..n).map(|j| a[i * n + j] * v[k * n + j]).sum::<f64>();
+                assert!((av - w[k] * v[k * n + i]).abs() < 1e-12);
+            }
+            for l in 0..n {
+                let dot = (0..n).map(|i| v[k * n + i] * v[l * n + i]).sum::<f64>();
+                assert!((dot - if k == l { 1.0 } else { 0.0 }).abs() < 1e-12);
+            }
+        }
+    }
+}
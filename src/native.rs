@@ -0,0 +1,628 @@
+//! Pure-Rust implementation of the D4 dispersion model.
+//!
+//! This implements D4 with rational damping of two-body dispersion and the three-body (ATM)
+//! term, following upstream dftd4, with reference data shipped in `param::d4`. Molecular and
+//! periodic (1D, 2D and 3D) systems are supported; coordination numbers and dispersion terms sum
+//! over lattice images within the real-space cutoffs, and EEQ charges of periodic systems are
+//! evaluated by Ewald summation.
+
+mod damping;
+mod model;
+mod ncoord;
+
+use crate::eeq::{EEQModel, GeometryDerivs};
+use crate::lattice::{get_lattice_points, validate_lattice_f, validate_periodic_f};
+use crate::library::{
+    DFTD4Cutoff, DFTD4Dispersion, DFTD4EnergyGradient, DFTD4Error, DFTD4Properties,
+};
+use crate::param::d4;
+use damping::{AtomicC6, DispersionDerivs, RationalDamping};
+use model::{get_electronegativity, get_r4r2, D4Model};
+pub use model::{FREQ, NFREQ};
+use std::cell::RefCell;
+
+/// Two-body and three-body pair energies [natoms][natoms], and optionally their derivatives
+type PairEnergies = (Vec<f64>, Vec<f64>, Option<DispersionDerivs>);
+
+/// Species, coordination numbers, charges and reference weights [natoms][MAX_REF] of atoms
+type ReferenceWeights = (Vec<usize>, Vec<f64>, Vec<f64>, Vec<f64>);
+
+/// Version of upstream dftd4 that this implementation follows
+pub const API_VERSION: usize = 30700;
+
+/// Scaling factors of C8 coefficients, `sqrt(0.5 * sqrt(Z) * <r4>/<r2>)` [natoms]
+pub fn get_r4r2_f(numbers: &[usize]) -> Result<Vec<f64>, DFTD4Error> {
+    numbers.iter().map(|&z| get_r4r2(z)).collect()
+}
+
+/// Positions [natoms][3] and lattice translations [ntrans][3] of images within a cutoff
+pub(crate) struct Images<'a> {
+    pub positions: &'a [f64],
+    pub trans: Vec<[f64; 3]>,
+    pub cutoff: f64,
+}
+
+/// Molecular or periodic structure data of the pure-Rust implementation
+#[derive(Clone)]
+pub struct NativeStructure {
+    numbers: Vec<usize>,
+    positions: RefCell<Vec<f64>>,
+    charge: f64,
+    lattice: RefCell<Option<Vec<f64>>>,
+    /// periodic directions, none for molecules
+    periodic: [bool; 3],
+}
+
+impl NativeStructure {
+    /// Create new molecular structure data (quantities in Bohr) (failable)
+    pub fn new_f(
+        natoms: usize,
+        numbers: &[usize],
+        positions: &[f64],
+        charge: Option<f64>,
+        lattice: Option<&[f64]>,
+        periodic: Option<&[bool]>,
+    ) -> Result<Self, DFTD4Error> {
+        if numbers.len() != natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for numbers, expected {}, got {}",
+                natoms,
+                numbers.len()
+            )));
+        }
+        if positions.len() != 3 * natoms {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for positions, expected {}, got {}",
+                3 * natoms,
+                positions.len()
+            )));
+        }
+        // without lattice, the structure is a molecule
+        let periodic = match lattice {
+            Some(lattice) => {
+                validate_lattice_f(lattice, periodic)?;
+                let periodic = periodic.unwrap_or(&[true; 3]);
+                [periodic[0], periodic[1], periodic[2]]
+            }
+            None => {
+                validate_periodic_f(periodic)?;
+                [false; 3]
+            }
+        };
+        Ok(Self {
+            numbers: numbers.to_vec(),
+            positions: RefCell::new(positions.to_vec()),
+            charge: charge.unwrap_or(0.0),
+            lattice: RefCell::new(lattice.map(|lattice| lattice.to_vec())),
+            periodic,
+        })
+    }
+
+    /// Update coordinates and lattice parameters (quantities in Bohr) (failable)
+    pub fn update_f(&self, positions: &[f64], lattice: Option<&[f64]>) -> Result<(), DFTD4Error> {
+        if positions.len() != 3 * self.numbers.len() {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for positions, expected {}, got {}",
+                3 * self.numbers.len(),
+                positions.len()
+            )));
+        }
+        if let Some(lattice) = lattice {
+            validate_lattice_f(lattice, Some(&self.periodic))?;
+            *self.lattice.borrow_mut() = Some(lattice.to_vec());
+        }
+        self.positions.borrow_mut().copy_from_slice(positions);
+        Ok(())
+    }
+
+    /// Get number of atoms
+    pub fn get_natoms(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Images of atoms within `cutoff` in periodic directions
+    fn get_images<'a>(&self, positions: &'a [f64], cutoff: f64) -> Images<'a> {
+        let lattice = self.lattice.borrow();
+        Images {
+            positions,
+            trans: get_lattice_points(lattice.as_deref(), &self.periodic, positions, cutoff),
+            cutoff,
+        }
+    }
+}
+
+/// D4 dispersion model of the pure-Rust implementation
+pub struct NativeModel {
+    model: D4Model,
+    eeq: EEQModel,
+    cutoff: DFTD4Cutoff,
+    /// custom atomic charges overriding EEQ charges
+    charges: Option<Vec<f64>>,
+}
+
+impl NativeModel {
+    /// Create new D4 dispersion model (failable)
+    pub fn new_f(structure: &NativeStructure) -> Result<Self, DFTD4Error> {
+        Self::custom_f(structure, 3.0, 2.0, 6.0)
+    }
+
+    /// Create new D4 dispersion model with custom charge scaling and weighting (failable)
+    pub fn custom_f(
+        structure: &NativeStructure,
+        ga: f64,
+        gc: f64,
+        wf: f64,
+    ) -> Result<Self, DFTD4Error> {
+        let model = D4Model::new_f(&structure.numbers, ga, gc, wf)?;
+        let eeq = EEQModel::new_f()?;
+        let cutoff = DFTD4Cutoff::default();
+        Ok(Self {
+            model,
+            eeq,
+            cutoff,
+            charges: None,
+        })
+    }
+
+    /// Get real-space cutoffs
+    pub fn get_cutoff(&self) -> DFTD4Cutoff {
+        self.cutoff
+    }
+
+    /// Set real-space cutoffs
+    pub fn set_cutoff(&mut self, cutoff: DFTD4Cutoff) {
+        self.cutoff = cutoff;
+    }
+
+    /// Set custom atomic charges [natoms], or EEQ charges if `None` (failable)
+    pub fn set_charges_f(&mut self, charges: Option<&[f64]>) -> Result<(), DFTD4Error> {
+        if charges.is_some_and(|charges| charges.iter().any(|q| !q.is_finite())) {
+            return Err(DFTD4Error::Rust("Charges are not finite".to_string()));
+        }
+        self.charges = charges.map(|charges| charges.to_vec());
+        Ok(())
+    }
+
+    /// Atomic charges [natoms], and optionally their derivatives with respect to positions
+    /// [natoms][natoms][3] and strain [natoms][3][3]
+    fn get_charges_f(
+        &self,
+        structure: &NativeStructure,
+        eval_grad: bool,
+    ) -> Result<(Vec<f64>, Option<GeometryDerivs>), DFTD4Error> {
+        let natoms = structure.numbers.len();
+        match &self.charges {
+            Some(charges) if charges.len() != natoms => Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for charges, expected {}, got {}",
+                natoms,
+                charges.len()
+            ))),
+            // custom charges do not depend on positions
+            Some(charges) => Ok((
+                charges.clone(),
+                eval_grad.then(|| (vec![0.0; 3 * natoms * natoms], vec![0.0; 9 * natoms])),
+            )),
+            None => self.eeq.get_charges_lattice_f(
+                &structure.numbers,
+                &structure.positions.borrow(),
+                structure.lattice.borrow().as_deref(),
+                &structure.periodic,
+                Some(structure.charge),
+                eval_grad,
+            ),
+        }
+    }
+
+    /// Evaluate pair energies [natoms][natoms] of two- and three-body dispersion, and optionally
+    /// the derivatives, with the gradient [natoms][3] and virial [3][3] including the chain rule
+    /// for coordination numbers and charges.
+    fn evaluate(
+        &self,
+        structure: &NativeStructure,
+        param: &RationalDamping,
+        eval_grad: bool,
+    ) -> Result<PairEnergies, DFTD4Error> {
+        let mut results = self.evaluate_multi(structure, &[param], eval_grad)?;
+        Ok(results.remove(0))
+    }
+
+    /// Evaluate pair energies and derivatives as `evaluate` for several damping parameters,
+    /// sharing coordination numbers, charges and C6 coefficients.
+    fn evaluate_multi(
+        &self,
+        structure: &NativeStructure,
+        params: &[&RationalDamping],
+        eval_grad: bool,
+    ) -> Result<Vec<PairEnergies>, DFTD4Error> {
+        let numbers = &structure.numbers;
+        let positions = structure.positions.borrow();
+        let natoms = numbers.len();
+        let id = self.model.get_species_ids(numbers)?;
+        let en = numbers
+            .iter()
+            .map(|&z| get_electronegativity(z))
+            .collect::<Result<Vec<_>, _>>()?;
+        let r4r2 = numbers
+            .iter()
+            .map(|&z| get_r4r2(z))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let images_cn = structure.get_images(&positions, self.cutoff.cn);
+        let (cn, dcn) = ncoord::get_coordination_number(numbers, &images_cn, &en, eval_grad);
+        let (q, dq) = self.get_charges_f(structure, eval_grad)?;
+
+        // charge-dependent C6 for two-body dispersion
+        let (gwvec, dgwdcn, dgwdq) = self.model.weight_references(&id, &cn, &q, eval_grad);
+        let (c6, dc6dcn, dc6dq) =
+            self.model
+                .get_atomic_c6(&id, &gwvec, dgwdcn.as_deref(), dgwdq.as_deref());
+        // C6 of neutral atoms for three-body dispersion
+        let q0 = vec![0.0; natoms];
+        let (gwvec0, dgwdcn0, _) = self.model.weight_references(&id, &cn, &q0, eval_grad);
+        let (c60, dc60dcn, _) = self
+            .model
+            .get_atomic_c6(&id, &gwvec0, dgwdcn0.as_deref(), None);
+
+        let c6 = AtomicC6 {
+            c6: &c6,
+            dc6dcn: dc6dcn.as_deref(),
+            dc6dq: dc6dq.as_deref(),
+        };
+        let c60 = AtomicC6 {
+            c6: &c60,
+            dc6dcn: dc60dcn.as_deref(),
+            dc6dq: None,
+        };
+        let images2 = structure.get_images(&positions, self.cutoff.disp2);
+        let images3 = structure.get_images(&positions, self.cutoff.disp3);
+
+        let mut results = vec![];
+        for param in params {
+            let mut derivs = eval_grad.then(|| DispersionDerivs::new(natoms));
+            let mut pair2 = vec![0.0; natoms * natoms];
+            damping::get_dispersion2(param, &images2, &r4r2, &c6, &mut pair2, derivs.as_mut());
+            let mut pair3 = vec![0.0; natoms * natoms];
+            damping::get_dispersion3(param, &images3, &r4r2, &c60, &mut pair3, derivs.as_mut());
+
+            // chain rule for coordination numbers and charges
+            let derivs = derivs.map(|mut derivs| {
+                let (dcndr, dcnde) = dcn.as_ref().unwrap();
+                let (dqdr, dqde) = dq.as_ref().unwrap();
+                for i in 0..natoms {
+                    for j in 0..3 * natoms {
+                        derivs.gradient[j] += derivs.dedcn[i] * dcndr[i * 3 * natoms + j]
+                            + derivs.dedq[i] * dqdr[i * 3 * natoms + j];
+                    }
+                    for ab in 0..9 {
+                        derivs.sigma[ab] +=
+                            derivs.dedcn[i] * dcnde[9 * i + ab] + derivs.dedq[i] * dqde[9 * i + ab];
+                    }
+                }
+                derivs
+            });
+            results.push((pair2, pair3, derivs));
+        }
+        Ok(results)
+    }
+}
+
+/// Damping parameters of the pure-Rust implementation
+pub struct NativeParam {
+    param: RationalDamping,
+}
+
+impl NativeParam {
+    /// Create new rational damping parameters (failable)
+    pub fn new_rational_damping_f(
+        s6: f64,
+        s8: f64,
+        s9: f64,
+        a1: f64,
+        a2: f64,
+        alp: f64,
+    ) -> Result<Self, DFTD4Error> {
+        let param = RationalDamping {
+            s6,
+            s8,
+            s9,
+            a1,
+            a2,
+            alp,
+        };
+        Ok(Self { param })
+    }
+
+    /// Load rational damping parameters from internal storage (failable)
+    ///
+    /// `mdb` selects parameters fitted together with the three-body term; three-body dispersion
+    /// is switched off (`s9 = 0`) otherwise.
+    pub fn load_rational_damping_f(method: &str, mdb: bool) -> Result<Self, DFTD4Error> {
+        let name = method.trim().to_lowercase();
+        let id = d4::FUNCTIONAL_IDS
+            .iter()
+            .find(|(functional, _)| *functional == name)
+            .map_or(name.as_str(), |(_, id)| id);
+        let (_, _, values) = d4::RATIONAL_DAMPING
+            .iter()
+            .find(|(i, fit, _)| *i == id && fit.is_none_or(|fit| fit == mdb))
+            .ok_or_else(|| {
+                DFTD4Error::Rust(format!(
+                    "No rational damping parameters for method {}",
+                    method
+                ))
+            })?;
+        let [s6, s8, s9, a1, a2, alp] = *values;
+        let s9 = if mdb { s9 } else { 0.0 };
+        Self::new_rational_damping_f(s6, s8, s9, a1, a2, alp)
+    }
+
+    /// Get rational damping parameters `[s6, s8, s9, a1, a2, alp]`
+    pub fn get_rational_damping(&self) -> [f64; 6] {
+        let p = &self.param;
+        [p.s6, p.s8, p.s9, p.a1, p.a2, p.alp]
+    }
+}
+
+/// Evaluate properties related to the dispersion model (failable)
+pub fn get_properties_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+) -> Result<DFTD4Properties, DFTD4Error> {
+    let (id, cn, q, gwvec) = get_reference_weights_f(structure, model)?;
+    let (c6, _, _) = model.model.get_atomic_c6(&id, &gwvec, None, None);
+    let alpha = model.model.get_polarizabilities(&id, &gwvec);
+    Ok((cn, q, c6, alpha))
+}
+
+/// Evaluate dynamic polarizabilities at imaginary frequencies [natoms][NFREQ] (failable)
+///
+/// Frequencies are given by [`FREQ`].
+pub fn get_dynamic_polarizabilities_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let (id, _, _, gwvec) = get_reference_weights_f(structure, model)?;
+    Ok(model.model.get_dynamic_polarizabilities(&id, &gwvec))
+}
+
+/// Species, coordination numbers, charges and reference weights of atoms
+fn get_reference_weights_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+) -> Result<ReferenceWeights, DFTD4Error> {
+    let numbers = &structure.numbers;
+    let positions = structure.positions.borrow();
+    let id = model.model.get_species_ids(numbers)?;
+    let en = numbers
+        .iter()
+        .map(|&z| get_electronegativity(z))
+        .collect::<Result<Vec<_>, _>>()?;
+    let images = structure.get_images(&positions, model.cutoff.cn);
+    let (cn, _) = ncoord::get_coordination_number(numbers, &images, &en, false);
+    let (q, _) = model.get_charges_f(structure, false)?;
+    let (gwvec, _, _) = model.model.weight_references(&id, &cn, &q, false);
+    Ok((id, cn, q, gwvec))
+}
+
+/// Evaluate the dispersion energy and its derivative (failable)
+///
+/// The virial is the derivative with respect to strain, for molecules and periodic structures.
+pub fn get_dispersion_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+    param: &NativeParam,
+    eval_grad: bool,
+    eval_sigma: bool,
+) -> Result<DFTD4Dispersion, DFTD4Error> {
+    let (pair2, pair3, derivs) =
+        model.evaluate(structure, &param.param, eval_grad || eval_sigma)?;
+    let energy = pair2.iter().sum::<f64>() + pair3.iter().sum::<f64>();
+    let (gradient, sigma) = match derivs {
+        Some(derivs) => (
+            eval_grad.then_some(derivs.gradient),
+            eval_sigma.then_some(derivs.sigma),
+        ),
+        None => (None, None),
+    };
+    Ok((energy, gradient, sigma))
+}
+
+/// Evaluate the dispersion energy and optionally its gradient for several damping parameters
+/// (failable)
+///
+/// Coordination numbers, charges and C6 coefficients are evaluated once for all parameters.
+pub fn get_dispersion_multi_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+    params: &[&NativeParam],
+    eval_grad: bool,
+) -> Result<Vec<DFTD4EnergyGradient>, DFTD4Error> {
+    let params = params.iter().map(|p| &p.param).collect::<Vec<_>>();
+    let results = model.evaluate_multi(structure, &params, eval_grad)?;
+    Ok(results
+        .into_iter()
+        .map(|(pair2, pair3, derivs)| {
+            let energy = pair2.iter().sum::<f64>() + pair3.iter().sum::<f64>();
+            (energy, derivs.map(|derivs| derivs.gradient))
+        })
+        .collect())
+}
+
+/// Evaluate the pairwise representation of the dispersion energy (failable)
+pub fn get_pairwise_dispersion_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+    param: &NativeParam,
+) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
+    let (pair2, pair3, _) = model.evaluate(structure, &param.param, false)?;
+    Ok((pair2, pair3))
+}
+
+/// Evaluate the derivative of the dispersion energy with respect to atomic charges (failable)
+///
+/// Returns `dE/dq` [natoms] at fixed positions and coordination numbers; only the two-body term
+/// depends on charges.
+pub fn get_charge_derivatives_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+    param: &NativeParam,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let (_, _, derivs) = model.evaluate(structure, &param.param, true)?;
+    Ok(derivs.unwrap().dedq)
+}
+
+/// Evaluate the dispersion hessian numerically (failable)
+///
+/// Central finite differences of the analytical gradient. Returns hessian [natoms][3][natoms][3].
+pub fn get_numerical_hessian_f(
+    structure: &NativeStructure,
+    model: &NativeModel,
+    param: &NativeParam,
+) -> Result<Vec<f64>, DFTD4Error> {
+    const STEP: f64 = 1.0e-4;
+    let displaced = structure.clone();
+    let ncoord = 3 * structure.numbers.len();
+    let mut hessian = vec![0.0; ncoord * ncoord];
+    let positions = structure.positions.borrow();
+    let gradient = |i: usize, step: f64| {
+        displaced.positions.borrow_mut()[i] = positions[i] + step;
+        let result = model.evaluate(&displaced, &param.param, true);
+        displaced.positions.borrow_mut()[i] = positions[i];
+        result.map(|(_, _, derivs)| derivs.unwrap().gradient)
+    };
+    for i in 0..ncoord {
+        let (gr, gl) = (gradient(i, STEP)?, gradient(i, -STEP)?);
+        for j in 0..ncoord {
+            hessian[i * ncoord + j] = (gr[j] - gl[j]) / (2.0 * STEP);
+        }
+    }
+    Ok(hessian)
+}
+
+#[cfg(all(test, feature = "pure-rust"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        let numbers = vec![8, 1, 1, 6, 1, 1, 1];
+        #[rustfmt::skip]
+        let positions = vec![
+             0.00,  0.00,  0.20,   1.45,  0.00, -0.75,  -1.40,  0.10, -0.80,
+             0.30,  0.20,  5.40,   1.90,  0.30,  6.20,  -0.40,  1.80,  5.90,
+            -0.80, -1.20,  6.00,
+        ];
+        let natoms = numbers.len();
+        let structure =
+            NativeStructure::new_f(natoms, &numbers, &positions, Some(0.0), None, None).unwrap();
+        // soft weighting of references for sizeable derivatives by coordination numbers
+        let model = NativeModel::custom_f(&structure, 3.0, 2.0, 0.5).unwrap();
+        // enhanced three-body term to make its derivatives visible
+        let param = NativeParam::new_rational_damping_f(1.0, 1.2, 20.0, 0.4, 5.0, 16.0).unwrap();
+        let (energy, gradient, sigma) =
+            get_dispersion_f(&structure, &model, &param, true, true).unwrap();
+        let (gradient, sigma) = (gradient.unwrap(), sigma.unwrap());
+
+        // energy is the sum of pairwise energies
+        let (pair2, pair3) = get_pairwise_dispersion_f(&structure, &model, &param).unwrap();
+        let pair_sum = pair2.iter().sum::<f64>() + pair3.iter().sum::<f64>();
+        assert!((energy - pair_sum).abs() < 1e-12);
+
+        // gradient by finite differences
+        let step = 1.0e-5;
+        for i in 0..3 * natoms {
+            let mut displaced = positions.clone();
+            displaced[i] += step;
+            structure.update_f(&displaced, None).unwrap();
+            let (ep, _, _) = get_dispersion_f(&structure, &model, &param, false, false).unwrap();
+            displaced[i] -= 2.0 * step;
+            structure.update_f(&displaced, None).unwrap();
+            let (em, _, _) = get_dispersion_f(&structure, &model, &param, false, false).unwrap();
+            let numerical = (ep - em) / (2.0 * step);
+            assert!((gradient[i] - numerical).abs() < 1e-9);
+        }
+
+        // translational invariance and symmetric virial
+        for k in 0..3 {
+            let sum = (0..natoms).map(|i| gradient[3 * i + k]).sum::<f64>();
+            assert!(sum.abs() < 1e-10);
+        }
+        for a in 0..3 {
+            for b in 0..3 {
+                assert!((sigma[a + 3 * b] - sigma[b + 3 * a]).abs() < 1e-10);
+            }
+        }
+
+        // numerical hessian is symmetric
+        structure.update_f(&positions, None).unwrap();
+        let hessian = get_numerical_hessian_f(&structure, &model, &param).unwrap();
+        let ncoord = 3 * natoms;
+        for i in 0..ncoord {
+            for j in 0..i {
+                assert!((hessian[i * ncoord + j] - hessian[j * ncoord + i]).abs() < 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_charges() {
+        let numbers = vec![7, 1, 1, 1, 17];
+        #[rustfmt::skip]
+        let positions = vec![
+             0.00,  0.00,  0.00,   1.90,  0.00,  0.40,  -0.95,  1.65,  0.40,
+            -0.95, -1.65,  0.40,   0.00,  0.00,  5.50,
+        ];
+        let natoms = numbers.len();
+        let structure =
+            NativeStructure::new_f(natoms, &numbers, &positions, Some(0.0), None, None).unwrap();
+        let mut model = NativeModel::new_f(&structure).unwrap();
+        let param = NativeParam::new_rational_damping_f(1.0, 1.2, 1.0, 0.4, 5.0, 16.0).unwrap();
+        let (_, eeq, _, _) = get_properties_f(&structure, &model).unwrap();
+        let (energy, _, _) = get_dispersion_f(&structure, &model, &param, false, false).unwrap();
+
+        // EEQ charges as custom charges give the same energy
+        model.set_charges_f(Some(&eeq)).unwrap();
+        let (energy_q, gradient, _) =
+            get_dispersion_f(&structure, &model, &param, true, false).unwrap();
+        assert!((energy - energy_q).abs() < 1e-12);
+
+        // ionic charges, derivatives by finite differences of charges and positions
+        let charges = vec![0.4, 0.2, 0.2, 0.2, -1.0];
+        model.set_charges_f(Some(&charges)).unwrap();
+        assert_eq!(get_properties_f(&structure, &model).unwrap().1, charges);
+        let dedq = get_charge_derivatives_f(&structure, &model, &param).unwrap();
+        let step = 1.0e-5;
+        for i in 0..natoms {
+            let mut q = charges.clone();
+            q[i] += step;
+            model.set_charges_f(Some(&q)).unwrap();
+            let (ep, _, _) = get_dispersion_f(&structure, &model, &param, false, false).unwrap();
+            q[i] -= 2.0 * step;
+            model.set_charges_f(Some(&q)).unwrap();
+            let (em, _, _) = get_dispersion_f(&structure, &model, &param, false, false).unwrap();
+            assert!((dedq[i] - (ep - em) / (2.0 * step)).abs() < 1e-9);
+        }
+        model.set_charges_f(Some(&charges)).unwrap();
+        let (_, gradient_q, _) = get_dispersion_f(&structure, &model, &param, true, false).unwrap();
+        let gradient_q = gradient_q.unwrap();
+        assert!(gradient_q
+            .iter()
+            .zip(gradient.unwrap())
+            .any(|(a, b)| (a - b).abs() > 1e-8));
+        for i in 0..3 * natoms {
+            let mut displaced = positions.clone();
+            displaced[i] += step;
+            structure.update_f(&displaced, None).unwrap();
+            let (ep, _, _) = get_dispersion_f(&structure, &model, &param, false, false).unwrap();
+            displaced[i] -= 2.0 * step;
+            structure.update_f(&displaced, None).unwrap();
+            let (em, _, _) = get_dispersion_f(&structure, &model, &param, false, false).unwrap();
+            assert!((gradient_q[i] - (ep - em) / (2.0 * step)).abs() < 1e-9);
+        }
+
+        // invalid charges
+        assert!(model.set_charges_f(Some(&[f64::NAN; 5])).is_err());
+        model.set_charges_f(Some(&[0.0; 3])).unwrap();
+        assert!(get_dispersion_f(&structure, &model, &param, false, false).is_err());
+    }
+}
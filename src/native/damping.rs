@@ -0,0 +1,222 @@
+//! Rational damping of two-body dispersion and zero damping of three-body (ATM) dispersion.
+
+use super::Images;
+
+/// Rational damping parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RationalDamping {
+    pub s6: f64,
+    pub s8: f64,
+    pub s9: f64,
+    pub a1: f64,
+    pub a2: f64,
+    pub alp: f64,
+}
+
+/// Atomic C6 coefficients [natoms][natoms], and optionally their derivatives [natoms][natoms] with
+/// respect to coordination numbers and charges (see `D4Model::get_atomic_c6`)
+pub(crate) struct AtomicC6<'a> {
+    pub c6: &'a [f64],
+    pub dc6dcn: Option<&'a [f64]>,
+    pub dc6dq: Option<&'a [f64]>,
+}
+
+/// Derivatives of the dispersion energy, accumulated during evaluation
+pub(crate) struct DispersionDerivs {
+    /// derivative with respect to coordination numbers [natoms]
+    pub dedcn: Vec<f64>,
+    /// derivative with respect to charges [natoms]
+    pub dedq: Vec<f64>,
+    /// derivative with respect to positions [natoms][3]
+    pub gradient: Vec<f64>,
+    /// derivative with respect to strain [3][3]
+    pub sigma: Vec<f64>,
+}
+
+impl DispersionDerivs {
+    pub(crate) fn new(natoms: usize) -> Self {
+        Self {
+            dedcn: vec![0.0; natoms],
+            dedq: vec![0.0; natoms],
+            gradient: vec![0.0; 3 * natoms],
+            sigma: vec![0.0; 9],
+        }
+    }
+}
+
+/// Distance vector `r_i - r_j - t` and its squared length
+fn distance(positions: &[f64], i: usize, j: usize, t: &[f64; 3]) -> ([f64; 3], f64) {
+    let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k] - t[k]);
+    (v, v.iter().map(|x| x * x).sum())
+}
+
+/// Evaluate the two-body dispersion energy with rational damping.
+///
+/// Pair energies are accumulated to `energy` [natoms][natoms]; each pair contributes half of its
+/// energy to both `energy[i][j]` and `energy[j][i]`, including pairs with lattice images. Derivatives
+/// are accumulated to `derivs` if given, in which case the derivatives of C6 coefficients `dc6dcn`
+/// and `dc6dq` are required.
+pub(crate) fn get_dispersion2(
+    param: &RationalDamping,
+    images: &Images,
+    r4r2: &[f64],
+    c6: &AtomicC6,
+    energy: &mut [f64],
+    mut derivs: Option<&mut DispersionDerivs>,
+) {
+    let natoms = r4r2.len();
+    let AtomicC6 { c6, dc6dcn, dc6dq } = *c6;
+    let cutoff2 = images.cutoff * images.cutoff;
+    for i in 0..natoms {
+        for j in 0..=i {
+            // pairs of an atom with its own images are counted twice
+            let scale = if i == j { 0.5 } else { 1.0 };
+            let r4r2ij = 3.0 * r4r2[i] * r4r2[j];
+            let r0 = param.a1 * r4r2ij.sqrt() + param.a2;
+            for t in &images.trans {
+                let (v, r2) = distance(images.positions, i, j, t);
+                if r2 > cutoff2 || r2 < f64::EPSILON {
+                    continue;
+                }
+                let t6 = 1.0 / (r2.powi(3) + r0.powi(6));
+                let t8 = 1.0 / (r2.powi(4) + r0.powi(8));
+                let edisp = scale * (param.s6 * t6 + param.s8 * r4r2ij * t8);
+                let de = -c6[i * natoms + j] * edisp;
+                energy[i * natoms + j] += 0.5 * de;
+                energy[j * natoms + i] += 0.5 * de;
+
+                if let Some(derivs) = derivs.as_deref_mut() {
+                    let d6 = -6.0 * r2 * r2 * t6 * t6;
+                    let d8 = -8.0 * r2.powi(3) * t8 * t8;
+                    let gdisp = scale * (param.s6 * d6 + param.s8 * r4r2ij * d8);
+                    for (k, vk) in v.iter().enumerate() {
+                        let dg = -c6[i * natoms + j] * gdisp * vk;
+                        derivs.gradient[3 * i + k] += dg;
+                        derivs.gradient[3 * j + k] -= dg;
+                        for (l, vl) in v.iter().enumerate() {
+                            derivs.sigma[k + 3 * l] += dg * vl;
+                        }
+                    }
+                    if let Some(dc6dcn) = dc6dcn {
+                        derivs.dedcn[i] -= dc6dcn[i * natoms + j] * edisp;
+                        derivs.dedcn[j] -= dc6dcn[j * natoms + i] * edisp;
+                    }
+                    if let Some(dc6dq) = dc6dq {
+                        derivs.dedq[i] -= dc6dq[i * natoms + j] * edisp;
+                        derivs.dedq[j] -= dc6dq[j * natoms + i] * edisp;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Derivative of `(a+b-c)(a-b+c)(-a+b+c)` with respect to `a`
+fn dtriangle(a: f64, b: f64, c: f64) -> f64 {
+    (a - b + c) * (-a + b + c) + (a + b - c) * (-a + b + c) - (a + b - c) * (a - b + c)
+}
+
+/// Evaluate the three-body (Axilrod-Teller-Muto) dispersion energy with zero damping.
+///
+/// Triple energies are accumulated to `energy` [natoms][natoms]; each triple contributes a sixth
+/// of its energy to each of its six ordered pairs. Triples of atoms with lattice images are
+/// counted once per cell. Derivatives are accumulated to `derivs` if given, in which case the
+/// derivatives of C6 coefficients `dc6dcn` are required.
+pub(crate) fn get_dispersion3(
+    param: &RationalDamping,
+    images: &Images,
+    r4r2: &[f64],
+    c6: &AtomicC6,
+    energy: &mut [f64],
+    mut derivs: Option<&mut DispersionDerivs>,
+) {
+    let natoms = r4r2.len();
+    let AtomicC6 { c6, dc6dcn, .. } = *c6;
+    let positions = images.positions;
+    let cutoff2 = images.cutoff * images.cutoff;
+    let r0 = |i: usize, j: usize| param.a1 * (3.0 * r4r2[i] * r4r2[j]).sqrt() + param.a2;
+    for i in 0..natoms {
+        for j in 0..=i {
+            for k in 0..=j {
+                // triples with repeated atoms are found once for each permutation of images
+                let triple = match (i == j, j == k) {
+                    (true, true) => 1.0 / 6.0,
+                    (true, false) | (false, true) => 0.5,
+                    (false, false) => 1.0,
+                };
+                let (c6ij, c6jk, c6ik) =
+                    (c6[i * natoms + j], c6[j * natoms + k], c6[i * natoms + k]);
+                let c9 = -param.s9 * (c6ij * c6jk * c6ik).abs().sqrt();
+                if c9 == 0.0 {
+                    continue;
+                }
+                let r0ijk = r0(i, j) * r0(j, k) * r0(i, k);
+                for tj in &images.trans {
+                    let (vij, rij2) = distance(positions, i, j, tj);
+                    if rij2 > cutoff2 || rij2 < f64::EPSILON {
+                        continue;
+                    }
+                    for tk in &images.trans {
+                        let (vik, rik2) = distance(positions, i, k, tk);
+                        // r_j + tj - r_k - tk
+                        let vjk = [0, 1, 2].map(|l| vik[l] - vij[l]);
+                        let rjk2 = vjk.iter().map(|x| x * x).sum::<f64>();
+                        if rik2 > cutoff2
+                            || rjk2 > cutoff2
+                            || rik2 < f64::EPSILON
+                            || rjk2 < f64::EPSILON
+                        {
+                            continue;
+                        }
+                        let (a, b, c) = (rij2, rjk2, rik2);
+                        let abc = a * b * c;
+                        let r1 = abc.sqrt();
+                        let r3 = abc * r1;
+                        let r5 = r3 * abc;
+                        let x = (r0ijk / r1).powf(param.alp / 3.0);
+                        let fdmp = 1.0 / (1.0 + 6.0 * x);
+                        let tri = (a + b - c) * (a - b + c) * (-a + b + c);
+                        let ang = 0.375 * tri / r5 + 1.0 / r3;
+                        let e = -c9 * ang * fdmp * triple;
+                        for (p, q) in [(i, j), (j, i), (j, k), (k, j), (i, k), (k, i)] {
+                            energy[p * natoms + q] += e / 6.0;
+                        }
+
+                        if let Some(derivs) = derivs.as_deref_mut() {
+                            // derivatives with respect to squared distances
+                            let dedr2 = |a: f64, b: f64, c: f64| {
+                                let dang = 0.375 * (dtriangle(a, b, c) / r5 - 2.5 * tri / (r5 * a))
+                                    - 1.5 / (r3 * a);
+                                let dfdmp = fdmp * fdmp * param.alp * x / a;
+                                -c9 * (dang * fdmp + ang * dfdmp) * triple
+                            };
+                            let (dea, deb, dec) = (dedr2(a, b, c), dedr2(b, a, c), dedr2(c, a, b));
+                            for l in 0..3 {
+                                derivs.gradient[3 * i + l] += 2.0 * (dea * vij[l] + dec * vik[l]);
+                                derivs.gradient[3 * j + l] += 2.0 * (-dea * vij[l] + deb * vjk[l]);
+                                derivs.gradient[3 * k + l] += 2.0 * (-deb * vjk[l] - dec * vik[l]);
+                                for m in 0..3 {
+                                    derivs.sigma[l + 3 * m] += 2.0
+                                        * (dea * vij[l] * vij[m]
+                                            + deb * vjk[l] * vjk[m]
+                                            + dec * vik[l] * vik[m]);
+                                }
+                            }
+                            // derivatives with respect to C6 coefficients
+                            if let Some(dc6dcn) = dc6dcn {
+                                let de = |c6: f64| -0.5 * c9 / c6 * ang * fdmp * triple;
+                                let (deij, dejk, deik) = (de(c6ij), de(c6jk), de(c6ik));
+                                derivs.dedcn[i] +=
+                                    deij * dc6dcn[i * natoms + j] + deik * dc6dcn[i * natoms + k];
+                                derivs.dedcn[j] +=
+                                    deij * dc6dcn[j * natoms + i] + dejk * dc6dcn[j * natoms + k];
+                                derivs.dedcn[k] +=
+                                    deik * dc6dcn[k * natoms + i] + dejk * dc6dcn[k * natoms + j];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
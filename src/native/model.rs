@@ -0,0 +1,377 @@
+//! Reference polarizabilities, reference weighting and C6 coefficients of the D4 model.
+
+use crate::library::DFTD4Error;
+use crate::param::d4;
+use std::f64::consts::PI;
+
+/// Maximum number of reference systems per element
+pub(crate) const MAX_REF: usize = 7;
+/// Maximum number of elements with reference data
+pub(crate) const MAX_ELEM: usize = 118;
+/// Number of imaginary frequencies for Casimir-Polder integration
+pub const NFREQ: usize = 23;
+
+/// Imaginary frequencies for Casimir-Polder integration
+#[rustfmt::skip]
+pub const FREQ: [f64; NFREQ] = [
+    0.000001, 0.050000, 0.100000, 0.200000, 0.300000, 0.400000, 0.500000, 0.600000,
+    0.700000, 0.800000, 0.900000, 1.000000, 1.200000, 1.400000, 1.600000, 1.800000,
+    2.000000, 2.500000, 3.000000, 4.000000, 5.000000, 7.500000, 10.00000,
+];
+
+/// Trapezoidal integration weights of the imaginary frequency grid
+pub(crate) fn trapezoid_weights() -> [f64; NFREQ] {
+    let mut weights = [0.0; NFREQ];
+    for (w, weight) in weights.iter_mut().enumerate() {
+        let lo = FREQ[w.saturating_sub(1)];
+        let hi = FREQ[(w + 1).min(NFREQ - 1)];
+        let width = match w {
+            0 => hi - FREQ[0],
+            w if w == NFREQ - 1 => FREQ[NFREQ - 1] - lo,
+            _ => hi - lo,
+        };
+        *weight = 0.5 * width;
+    }
+    weights
+}
+
+/// Charge scaling function
+pub(crate) fn zeta(a: f64, c: f64, qref: f64, qmod: f64) -> f64 {
+    match qmod < 0.0 {
+        true => a.exp(),
+        false => (a * (1.0 - (c * (1.0 - qref / qmod)).exp())).exp(),
+    }
+}
+
+/// Derivative of charge scaling function with respect to `qmod`
+pub(crate) fn dzeta(a: f64, c: f64, qref: f64, qmod: f64) -> f64 {
+    match qmod < 0.0 {
+        true => 0.0,
+        false => {
+            -a * c * (c * (1.0 - qref / qmod)).exp() * zeta(a, c, qref, qmod) * qref / (qmod * qmod)
+        }
+    }
+}
+
+/// Get element data from a table indexed by atomic number minus one.
+fn get_element_data(table: &[f64], number: usize, what: &str) -> Result<f64, DFTD4Error> {
+    match number {
+        1.. if number <= table.len() => Ok(table[number - 1]),
+        _ => Err(DFTD4Error::Rust(format!(
+            "No {} for atomic number {}",
+            what, number
+        ))),
+    }
+}
+
+/// Pauling electronegativity used in the coordination number
+pub(crate) fn get_electronegativity(number: usize) -> Result<f64, DFTD4Error> {
+    get_element_data(d4::PAULING_EN, number, "electronegativity")
+}
+
+/// Expectation value based scaling factor `sqrt(0.5 * <r4>/<r2> * sqrt(Z))`
+pub(crate) fn get_r4r2(number: usize) -> Result<f64, DFTD4Error> {
+    let r4_over_r2 = get_element_data(d4::R4_OVER_R2, number, "r4/r2 expectation value")?;
+    Ok((0.5 * r4_over_r2 * (number as f64).sqrt()).sqrt())
+}
+
+/// D4 dispersion model: reference systems of all species in a structure
+pub(crate) struct D4Model {
+    /// charge scaling height
+    pub ga: f64,
+    /// charge scaling steepness
+    pub gc: f64,
+    /// weighting factor for coordination number interpolation
+    pub wf: f64,
+    /// atomic numbers of species
+    species: Vec<usize>,
+    /// effective nuclear charges of species
+    zeff: Vec<f64>,
+    /// chemical hardnesses of species
+    eta: Vec<f64>,
+    /// number of references of species
+    nref: Vec<usize>,
+    /// number of gaussian weights of references [nsp][MAX_REF]
+    ngw: Vec<[usize; MAX_REF]>,
+    /// coordination numbers of references [nsp][MAX_REF]
+    refcn: Vec<[f64; MAX_REF]>,
+    /// charges of references [nsp][MAX_REF]
+    refq: Vec<[f64; MAX_REF]>,
+    /// polarizabilities of references [nsp][MAX_REF][NFREQ]
+    aiw: Vec<[[f64; NFREQ]; MAX_REF]>,
+    /// C6 coefficients of references [nsp][nsp][MAX_REF][MAX_REF]
+    c6ref: Vec<f64>,
+}
+
+impl D4Model {
+    /// Create new D4 model for the elements in `numbers`.
+    pub(crate) fn new_f(numbers: &[usize], ga: f64, gc: f64, wf: f64) -> Result<Self, DFTD4Error> {
+        let mut species = numbers.to_vec();
+        species.sort_unstable();
+        species.dedup();
+        let nsp = species.len();
+
+        let mut zeff = vec![];
+        let mut eta = vec![];
+        let mut nref = vec![];
+        let mut ngw = vec![];
+        let mut refcn = vec![];
+        let mut refq = vec![];
+        let mut aiw = vec![];
+        for &number in species.iter() {
+            if number == 0 || number > MAX_ELEM {
+                return Err(DFTD4Error::Rust(format!(
+                    "No reference data for atomic number {}",
+                    number
+                )));
+            }
+            let z = number - 1;
+            zeff.push(get_element_data(
+                d4::EFFECTIVE_NUCLEAR_CHARGE,
+                number,
+                "effective nuclear charge",
+            )?);
+            eta.push(get_element_data(
+                d4::CHEMICAL_HARDNESS,
+                number,
+                "chemical hardness",
+            )?);
+            let n = d4::REFN[z].min(MAX_REF);
+            nref.push(n);
+
+            // number of gaussian weights from count of references with same coordination number
+            const MAX_CN: usize = 19;
+            let mut cncount = [0; MAX_CN + 1];
+            cncount[0] = 1;
+            let icn = |r: usize| (d4::REFCN[z * MAX_REF + r].round().max(0.0) as usize).min(MAX_CN);
+            (0..n).for_each(|r| cncount[icn(r)] += 1);
+            let mut ngw_sp = [1; MAX_REF];
+            (0..n).for_each(|r| ngw_sp[r] = cncount[icn(r)] * (cncount[icn(r)] + 1) / 2);
+            ngw.push(ngw_sp);
+
+            let mut refcn_sp = [0.0; MAX_REF];
+            let mut refq_sp = [0.0; MAX_REF];
+            let mut aiw_sp = [[0.0; NFREQ]; MAX_REF];
+            for r in 0..n {
+                refcn_sp[r] = d4::REFCOVCN[z * MAX_REF + r];
+                refq_sp[r] = d4::CLSQ[z * MAX_REF + r];
+                // reference polarizabilities, corrected for the contribution of hydrogen atoms
+                let is = d4::REFSYS[z * MAX_REF + r];
+                let mut sec = [0.0; NFREQ];
+                if is > 0 {
+                    let iz = get_element_data(
+                        d4::EFFECTIVE_NUCLEAR_CHARGE,
+                        is,
+                        "effective nuclear charge",
+                    )?;
+                    let eta_is = get_element_data(d4::CHEMICAL_HARDNESS, is, "chemical hardness")?;
+                    let scale = d4::SSCALE[is - 1]
+                        * zeta(ga, eta_is * gc, iz, d4::CLSH[z * MAX_REF + r] + iz);
+                    for (w, sec_w) in sec.iter_mut().enumerate() {
+                        *sec_w = scale * d4::SECAIW[(is - 1) * NFREQ + w];
+                    }
+                }
+                for w in 0..NFREQ {
+                    let alpha = d4::ALPHAIW[(z * MAX_REF + r) * NFREQ + w];
+                    aiw_sp[r][w] = (d4::ASCALE[z * MAX_REF + r]
+                        * (alpha - d4::HCOUNT[z * MAX_REF + r] * sec[w]))
+                        .max(0.0);
+                }
+            }
+            refcn.push(refcn_sp);
+            refq.push(refq_sp);
+            aiw.push(aiw_sp);
+        }
+
+        // reference C6 coefficients by Casimir-Polder integration
+        let weights = trapezoid_weights();
+        let mut c6ref = vec![0.0; nsp * nsp * MAX_REF * MAX_REF];
+        for si in 0..nsp {
+            for sj in 0..nsp {
+                for ri in 0..nref[si] {
+                    for rj in 0..nref[sj] {
+                        let c6 = 3.0 / PI
+                            * (0..NFREQ)
+                                .map(|w| weights[w] * aiw[si][ri][w] * aiw[sj][rj][w])
+                                .sum::<f64>();
+                        c6ref[((si * nsp + sj) * MAX_REF + ri) * MAX_REF + rj] = c6;
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            ga,
+            gc,
+            wf,
+            species,
+            zeff,
+            eta,
+            nref,
+            ngw,
+            refcn,
+            refq,
+            aiw,
+            c6ref,
+        })
+    }
+
+    /// Index of species for an atomic number
+    fn get_species(&self, number: usize) -> Result<usize, DFTD4Error> {
+        self.species
+            .iter()
+            .position(|&z| z == number)
+            .ok_or_else(|| {
+                DFTD4Error::Rust(format!(
+                    "Atomic number {} is not included in the model",
+                    number
+                ))
+            })
+    }
+
+    /// Species indices of atoms
+    pub(crate) fn get_species_ids(&self, numbers: &[usize]) -> Result<Vec<usize>, DFTD4Error> {
+        numbers
+            .iter()
+            .map(|&number| self.get_species(number))
+            .collect()
+    }
+
+    /// Weights of reference systems by coordination number and charge scaling.
+    ///
+    /// Returns weights [natoms][MAX_REF], and optionally their derivatives with respect to
+    /// coordination numbers and charges of the same atom.
+    pub(crate) fn weight_references(
+        &self,
+        id: &[usize],
+        cn: &[f64],
+        q: &[f64],
+        eval_grad: bool,
+    ) -> (Vec<f64>, Option<Vec<f64>>, Option<Vec<f64>>) {
+        let natoms = id.len();
+        let mut gwvec = vec![0.0; natoms * MAX_REF];
+        let mut dgwdcn = vec![0.0; natoms * MAX_REF];
+        let mut dgwdq = vec![0.0; natoms * MAX_REF];
+        for iat in 0..natoms {
+            let isp = id[iat];
+            let zi = self.zeff[isp];
+            let gi = self.eta[isp] * self.gc;
+            let nref = self.nref[isp];
+            let mut expw = [0.0; MAX_REF];
+            let mut expd = [0.0; MAX_REF];
+            for r in 0..nref {
+                for igw in 1..=self.ngw[isp][r] {
+                    let wf = igw as f64 * self.wf;
+                    let dcn = cn[iat] - self.refcn[isp][r];
+                    let gw = (-wf * dcn * dcn).exp();
+                    expw[r] += gw;
+                    expd[r] += -2.0 * wf * dcn * gw;
+                }
+            }
+            let norm = 1.0 / expw[..nref].iter().sum::<f64>();
+            let dnorm = expd[..nref].iter().sum::<f64>();
+            let maxcn = self.refcn[isp][..nref]
+                .iter()
+                .cloned()
+                .fold(f64::MIN, f64::max);
+            for r in 0..nref {
+                let (gwk, dgwk) = match (expw[r] * norm).is_finite() {
+                    true => (expw[r] * norm, norm * (expd[r] - expw[r] * dnorm * norm)),
+                    false => match (maxcn - self.refcn[isp][r]).abs() < 1e-12 {
+                        true => (1.0, 0.0),
+                        false => (0.0, 0.0),
+                    },
+                };
+                let z = zeta(self.ga, gi, self.refq[isp][r] + zi, q[iat] + zi);
+                let dz = dzeta(self.ga, gi, self.refq[isp][r] + zi, q[iat] + zi);
+                gwvec[iat * MAX_REF + r] = gwk * z;
+                dgwdcn[iat * MAX_REF + r] = dgwk * z;
+                dgwdq[iat * MAX_REF + r] = gwk * dz;
+            }
+        }
+        match eval_grad {
+            true => (gwvec, Some(dgwdcn), Some(dgwdq)),
+            false => (gwvec, None, None),
+        }
+    }
+
+    /// Atomic C6 coefficients from reference weights.
+    ///
+    /// Returns C6 coefficients [natoms][natoms], and optionally derivatives [natoms][natoms] with
+    /// respect to coordination numbers and charges, where `dc6dcn[i][j]` is the derivative of
+    /// `c6[i][j]` with respect to the coordination number of atom `i`.
+    pub(crate) fn get_atomic_c6(
+        &self,
+        id: &[usize],
+        gwvec: &[f64],
+        dgwdcn: Option<&[f64]>,
+        dgwdq: Option<&[f64]>,
+    ) -> (Vec<f64>, Option<Vec<f64>>, Option<Vec<f64>>) {
+        let natoms = id.len();
+        let nsp = self.species.len();
+        let mut c6 = vec![0.0; natoms * natoms];
+        let mut dc6dcn = dgwdcn.map(|_| vec![0.0; natoms * natoms]);
+        let mut dc6dq = dgwdq.map(|_| vec![0.0; natoms * natoms]);
+        for i in 0..natoms {
+            for j in 0..=i {
+                let (si, sj) = (id[i], id[j]);
+                let c6ref = &self.c6ref[(si * nsp + sj) * MAX_REF * MAX_REF..];
+                let mut val = 0.0;
+                let (mut dcni, mut dcnj, mut dqi, mut dqj) = (0.0, 0.0, 0.0, 0.0);
+                for ri in 0..self.nref[si] {
+                    for rj in 0..self.nref[sj] {
+                        let refc6 = c6ref[ri * MAX_REF + rj];
+                        let (gi, gj) = (gwvec[i * MAX_REF + ri], gwvec[j * MAX_REF + rj]);
+                        val += gi * gj * refc6;
+                        if let Some(dgwdcn) = dgwdcn {
+                            dcni += dgwdcn[i * MAX_REF + ri] * gj * refc6;
+                            dcnj += gi * dgwdcn[j * MAX_REF + rj] * refc6;
+                        }
+                        if let Some(dgwdq) = dgwdq {
+                            dqi += dgwdq[i * MAX_REF + ri] * gj * refc6;
+                            dqj += gi * dgwdq[j * MAX_REF + rj] * refc6;
+                        }
+                    }
+                }
+                c6[i * natoms + j] = val;
+                c6[j * natoms + i] = val;
+                if let Some(dc6dcn) = dc6dcn.as_mut() {
+                    dc6dcn[i * natoms + j] = dcni;
+                    dc6dcn[j * natoms + i] = dcnj;
+                }
+                if let Some(dc6dq) = dc6dq.as_mut() {
+                    dc6dq[i * natoms + j] = dqi;
+                    dc6dq[j * natoms + i] = dqj;
+                }
+            }
+        }
+        (c6, dc6dcn, dc6dq)
+    }
+
+    /// Dynamic polarizabilities of atoms at imaginary frequencies from reference weights
+    ///
+    /// Returns polarizabilities [natoms][NFREQ].
+    pub(crate) fn get_dynamic_polarizabilities(&self, id: &[usize], gwvec: &[f64]) -> Vec<f64> {
+        let mut alpha = vec![0.0; id.len() * NFREQ];
+        for (i, alpha_i) in alpha.chunks_mut(NFREQ).enumerate() {
+            for r in 0..self.nref[id[i]] {
+                let gw = gwvec[i * MAX_REF + r];
+                for (alpha, aiw) in alpha_i.iter_mut().zip(&self.aiw[id[i]][r]) {
+                    *alpha += gw * aiw;
+                }
+            }
+        }
+        alpha
+    }
+
+    /// Static polarizabilities of atoms from reference weights
+    pub(crate) fn get_polarizabilities(&self, id: &[usize], gwvec: &[f64]) -> Vec<f64> {
+        (0..id.len())
+            .map(|i| {
+                (0..self.nref[id[i]])
+                    .map(|r| gwvec[i * MAX_REF + r] * self.aiw[id[i]][r][0])
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+}
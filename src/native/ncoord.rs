@@ -0,0 +1,72 @@
+//! Electronegativity weighted coordination number of the D4 model.
+
+use super::Images;
+use crate::data::get_covalent_rad;
+use crate::eeq::GeometryDerivs;
+use crate::math::erf;
+use std::f64::consts::PI;
+
+/// Steepness of the error function counting function
+const KCN: f64 = 7.5;
+/// Parameters of the electronegativity weighting
+const K4: f64 = 4.10451;
+const K5: f64 = 19.08857;
+const K6: f64 = 2.0 * 11.28174 * 11.28174;
+
+/// Evaluate the electronegativity weighted coordination number.
+///
+/// Returns coordination numbers [natoms] and optionally derivatives with respect to positions
+/// [natoms][natoms][3] and strain [natoms][3][3], where `dcndr[i][j][k]` is the derivative of
+/// `cn[i]` with respect to coordinate `k` of atom `j`.
+pub(crate) fn get_coordination_number(
+    numbers: &[usize],
+    images: &Images,
+    en: &[f64],
+    eval_grad: bool,
+) -> (Vec<f64>, Option<GeometryDerivs>) {
+    let natoms = numbers.len();
+    let positions = images.positions;
+    let mut cn = vec![0.0; natoms];
+    let mut derivs = match eval_grad {
+        true => Some((vec![0.0; natoms * natoms * 3], vec![0.0; natoms * 9])),
+        false => None,
+    };
+    for i in 0..natoms {
+        for j in 0..=i {
+            // images of an atom count once to its own coordination number
+            let scale = if i == j { 0.5 } else { 1.0 };
+            let rc = get_covalent_rad(numbers[i]).unwrap() + get_covalent_rad(numbers[j]).unwrap();
+            let den = K4 * (-((en[i] - en[j]).abs() + K5).powi(2) / K6).exp();
+            for t in &images.trans {
+                let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k] - t[k]);
+                let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                if r2 > images.cutoff * images.cutoff || r2 < f64::EPSILON {
+                    continue;
+                }
+                let r = r2.sqrt();
+                let arg = -KCN * (r - rc) / rc;
+                let count = scale * den * 0.5 * (1.0 + erf(arg));
+                cn[i] += count;
+                cn[j] += count;
+                if let Some((dcndr, dcnde)) = derivs.as_mut() {
+                    let dcount = -scale * den * KCN / (rc * PI.sqrt()) * (-arg * arg).exp();
+                    for k in 0..3 {
+                        let d = dcount * v[k] / r;
+                        dcndr[(i * natoms + i) * 3 + k] += d;
+                        dcndr[(i * natoms + j) * 3 + k] -= d;
+                        dcndr[(j * natoms + j) * 3 + k] -= d;
+                        dcndr[(j * natoms + i) * 3 + k] += d;
+                    }
+                    for a in 0..3 {
+                        for b in 0..3 {
+                            let s = dcount * v[a] * v[b] / r;
+                            dcnde[9 * i + 3 * a + b] += s;
+                            dcnde[9 * j + 3 * a + b] += s;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    (cn, derivs)
+}
@@ -0,0 +1,720 @@
+//! Geometry optimization driven by D4 gradients.
+//!
+//! Optimizers work on flattened positions [natoms][3] and a pluggable energy function that
+//! returns energy and gradient; [`optimize_structure_f`] optimizes a [`DFTD4Structure`] in place,
+//! with D4 dispersion plus an optional user-defined energy term (force field, SCF, ...).
+//!
+//! Variable-cell optimization ([`optimize_cell_f`], [`optimize_structure_cell_f`]) relaxes lattice
+//! and positions together under external pressure, using the virial (sigma) of the energy.
+
+use crate::library::*;
+use crate::math::{det3, inv3, matmul3, transpose3};
+
+/// User-defined energy and gradient [natoms][3] of positions
+pub type ExtraFn<'a> = dyn FnMut(&[f64]) -> Result<(f64, Vec<f64>), DFTD4Error> + 'a;
+
+/// User-defined energy, gradient [natoms][3] and virial [3][3] of positions and lattice
+pub type ExtraCellFn<'a> =
+    dyn FnMut(&[f64], &[f64]) -> Result<(f64, Vec<f64>, Vec<f64>), DFTD4Error> + 'a;
+
+/// Optimization algorithm
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OptimizerAlgorithm {
+    /// Limited-memory BFGS with backtracking line search
+    LBFGS,
+    /// Fast inertial relaxation engine (Bitzek et al., Phys. Rev. Lett. 97, 170201 (2006))
+    FIRE,
+}
+
+/// Settings of geometry optimization (quantities in atomic units)
+#[derive(Clone, Debug)]
+pub struct OptimizerConfig {
+    pub algorithm: OptimizerAlgorithm,
+    /// maximum number of iterations
+    pub max_iter: usize,
+    /// convergence threshold of energy change between iterations
+    pub energy_tol: f64,
+    /// convergence threshold of maximum force component
+    pub max_force_tol: f64,
+    /// convergence threshold of root mean square of force
+    pub rms_force_tol: f64,
+    /// maximum displacement of atoms in one step
+    pub max_step: f64,
+    /// number of stored steps of L-BFGS
+    pub lbfgs_memory: usize,
+    /// initial time step of FIRE
+    pub fire_dt: f64,
+    /// maximum time step of FIRE
+    pub fire_dt_max: f64,
+}
+
+impl Default for OptimizerConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: OptimizerAlgorithm::LBFGS,
+            max_iter: 500,
+            energy_tol: 1.0e-6,
+            max_force_tol: 4.5e-4,
+            rms_force_tol: 3.0e-4,
+            max_step: 0.3,
+            lbfgs_memory: 20,
+            fire_dt: 1.0,
+            fire_dt_max: 10.0,
+        }
+    }
+}
+
+/// Result of geometry optimization
+#[derive(Clone, Debug)]
+pub struct OptimizerResult {
+    /// optimized positions [natoms][3]
+    pub positions: Vec<f64>,
+    /// energy of optimized positions
+    pub energy: f64,
+    /// gradient of optimized positions [natoms][3]
+    pub gradient: Vec<f64>,
+    /// number of iterations
+    pub niter: usize,
+    /// whether convergence criteria are fulfilled
+    pub converged: bool,
+    /// optimized lattice [3][3], for variable-cell optimization
+    pub lattice: Option<Vec<f64>>,
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Largest displacement of atoms in step [natoms][3]
+fn largest_displacement(step: &[f64]) -> f64 {
+    step.chunks(3).map(|d| dot(d, d).sqrt()).fold(0.0, f64::max)
+}
+
+/// Scale step so that no atom is displaced by more than `max_step`.
+fn limit_step(step: &mut [f64], max_step: f64) {
+    let largest = largest_displacement(step);
+    if largest > max_step {
+        step.iter_mut().for_each(|x| *x *= max_step / largest);
+    }
+}
+
+/// Check convergence criteria by energy change and gradient
+fn check_convergence(config: &OptimizerConfig, de: f64, gradient: &[f64]) -> bool {
+    let max_force = gradient.iter().fold(0.0, |acc: f64, g| acc.max(g.abs()));
+    let rms_force = (dot(gradient, gradient) / gradient.len().max(1) as f64).sqrt();
+    de.abs() < config.energy_tol
+        && max_force < config.max_force_tol
+        && rms_force < config.rms_force_tol
+}
+
+/// L-BFGS search direction by two-loop recursion
+fn lbfgs_direction(gradient: &[f64], s_list: &[Vec<f64>], y_list: &[Vec<f64>]) -> Vec<f64> {
+    let mut q = gradient.to_vec();
+    let mut alpha = vec![0.0; s_list.len()];
+    for k in (0..s_list.len()).rev() {
+        let rho = 1.0 / dot(&y_list[k], &s_list[k]);
+        alpha[k] = rho * dot(&s_list[k], &q);
+        q.iter_mut()
+            .zip(&y_list[k])
+            .for_each(|(q, y)| *q -= alpha[k] * y);
+    }
+    if let (Some(s), Some(y)) = (s_list.last(), y_list.last()) {
+        let gamma = dot(s, y) / dot(y, y);
+        q.iter_mut().for_each(|q| *q *= gamma);
+    }
+    for k in 0..s_list.len() {
+        let rho = 1.0 / dot(&y_list[k], &s_list[k]);
+        let beta = rho * dot(&y_list[k], &q);
+        q.iter_mut()
+            .zip(&s_list[k])
+            .for_each(|(q, s)| *q += (alpha[k] - beta) * s);
+    }
+    q.iter_mut().for_each(|q| *q = -*q);
+    q
+}
+
+/// Step length (fraction of the direction), positions, energy and gradient of an accepted step
+type AcceptedStep = (f64, Vec<f64>, f64, Vec<f64>);
+
+/// Backtracking line search with Armijo condition along `direction`, limited to `max_step`
+///
+/// Returns the accepted step, or `None` if no step down to 1e-3 of the full step decreases the
+/// energy sufficiently.
+fn line_search<F>(
+    x: &[f64],
+    energy: f64,
+    gradient: &[f64],
+    mut direction: Vec<f64>,
+    config: &OptimizerConfig,
+    energy_fn: &mut F,
+) -> Result<Option<AcceptedStep>, DFTD4Error>
+where
+    F: FnMut(&[f64]) -> Result<(f64, Vec<f64>), DFTD4Error>,
+{
+    limit_step(&mut direction, config.max_step);
+    let slope = dot(&direction, gradient);
+    let mut step = 1.0;
+    while step >= 1.0e-3 {
+        let x_new = x
+            .iter()
+            .zip(&direction)
+            .map(|(x, d)| x + step * d)
+            .collect::<Vec<f64>>();
+        let (energy_new, gradient_new) = energy_fn(&x_new)?;
+        if energy_new <= energy + 1.0e-4 * step * slope {
+            return Ok(Some((step, x_new, energy_new, gradient_new)));
+        }
+        step *= 0.5;
+    }
+    Ok(None)
+}
+
+fn optimize_lbfgs<F>(
+    positions: &[f64],
+    config: &OptimizerConfig,
+    energy_fn: &mut F,
+) -> Result<OptimizerResult, DFTD4Error>
+where
+    F: FnMut(&[f64]) -> Result<(f64, Vec<f64>), DFTD4Error>,
+{
+    let mut x = positions.to_vec();
+    let (mut energy, mut gradient) = energy_fn(&x)?;
+    let mut s_list: Vec<Vec<f64>> = vec![];
+    let mut y_list: Vec<Vec<f64>> = vec![];
+    // inverse curvature of steepest descent steps without stored pairs: the latest s.y / y.y,
+    // or adapted to line searches in regions of negative curvature
+    let mut scale = 1.0;
+    for niter in 1..=config.max_iter {
+        let steepest = gradient.iter().map(|g| -scale * g).collect::<Vec<f64>>();
+        let mut direction = match s_list.is_empty() {
+            true => steepest.clone(),
+            false => lbfgs_direction(&gradient, &s_list, &y_list),
+        };
+        if dot(&direction, &gradient) >= 0.0 {
+            // not a descent direction, restart from steepest descent
+            s_list.clear();
+            y_list.clear();
+            direction = steepest.clone();
+        }
+        let mut accepted = line_search(&x, energy, &gradient, direction, config, energy_fn)?;
+        if accepted.is_none() && !s_list.is_empty() {
+            // no sufficient decrease along the L-BFGS direction, restart from steepest descent
+            s_list.clear();
+            y_list.clear();
+            accepted = line_search(&x, energy, &gradient, steepest, config, energy_fn)?;
+        }
+        let Some((step, x_new, energy_new, gradient_new)) = accepted else {
+            // no sufficient decrease along steepest descent: stop, converged only if forces are
+            // within thresholds (energy cannot decrease beyond numerical precision)
+            let converged = check_convergence(config, 0.0, &gradient);
+            return Ok(OptimizerResult {
+                positions: x,
+                energy,
+                gradient,
+                niter,
+                converged,
+                lattice: None,
+            });
+        };
+        let s = x_new
+            .iter()
+            .zip(&x)
+            .map(|(a, b)| a - b)
+            .collect::<Vec<f64>>();
+        let y = gradient_new
+            .iter()
+            .zip(&gradient)
+            .map(|(a, b)| a - b)
+            .collect::<Vec<f64>>();
+        // keep only pairs of positive curvature
+        if dot(&s, &y) > f64::EPSILON * (dot(&s, &s) * dot(&y, &y)).sqrt() {
+            scale = dot(&s, &y) / dot(&y, &y);
+            s_list.push(s);
+            y_list.push(y);
+            if s_list.len() > config.lbfgs_memory {
+                s_list.remove(0);
+                y_list.remove(0);
+            }
+        } else if s_list.is_empty() {
+            // grow steepest descent steps after full steps, up to the maximum step
+            scale *= match step < 1.0 {
+                true => step,
+                false => 2.0,
+            };
+            let largest = largest_displacement(&gradient_new);
+            if largest > 0.0 {
+                scale = scale.min(config.max_step / largest);
+            }
+        }
+        let de = energy_new - energy;
+        (x, energy, gradient) = (x_new, energy_new, gradient_new);
+        if check_convergence(config, de, &gradient) {
+            return Ok(OptimizerResult {
+                positions: x,
+                energy,
+                gradient,
+                niter,
+                converged: true,
+                lattice: None,
+            });
+        }
+    }
+    let niter = config.max_iter;
+    Ok(OptimizerResult {
+        positions: x,
+        energy,
+        gradient,
+        niter,
+        converged: false,
+        lattice: None,
+    })
+}
+
+fn optimize_fire<F>(
+    positions: &[f64],
+    config: &OptimizerConfig,
+    energy_fn: &mut F,
+) -> Result<OptimizerResult, DFTD4Error>
+where
+    F: FnMut(&[f64]) -> Result<(f64, Vec<f64>), DFTD4Error>,
+{
+    const N_MIN: usize = 5;
+    const F_INC: f64 = 1.1;
+    const F_DEC: f64 = 0.5;
+    const ALPHA_START: f64 = 0.1;
+    const F_ALPHA: f64 = 0.99;
+
+    let mut x = positions.to_vec();
+    let (mut energy, mut gradient) = energy_fn(&x)?;
+    let mut velocity = vec![0.0; x.len()];
+    let mut dt = config.fire_dt;
+    let mut alpha = ALPHA_START;
+    let mut n_positive = 0;
+    for niter in 1..=config.max_iter {
+        // velocity update (unit masses) and mixing with force direction
+        velocity
+            .iter_mut()
+            .zip(&gradient)
+            .for_each(|(v, g)| *v -= dt * g);
+        let power = -dot(&gradient, &velocity);
+        if power > 0.0 {
+            let v_norm = dot(&velocity, &velocity).sqrt();
+            let f_norm = dot(&gradient, &gradient).sqrt();
+            velocity.iter_mut().zip(&gradient).for_each(|(v, g)| {
+                *v = (1.0 - alpha) * *v - alpha * v_norm * g / f_norm;
+            });
+            n_positive += 1;
+            if n_positive > N_MIN {
+                dt = (dt * F_INC).min(config.fire_dt_max);
+                alpha *= F_ALPHA;
+            }
+        } else {
+            velocity.iter_mut().for_each(|v| *v = 0.0);
+            n_positive = 0;
+            dt *= F_DEC;
+            alpha = ALPHA_START;
+        }
+        let mut step = velocity.iter().map(|v| dt * v).collect::<Vec<f64>>();
+        limit_step(&mut step, config.max_step);
+        x.iter_mut().zip(&step).for_each(|(x, d)| *x += d);
+        let (energy_new, gradient_new) = energy_fn(&x)?;
+        let de = energy_new - energy;
+        (energy, gradient) = (energy_new, gradient_new);
+        if check_convergence(config, de, &gradient) {
+            return Ok(OptimizerResult {
+                positions: x,
+                energy,
+                gradient,
+                niter,
+                converged: true,
+                lattice: None,
+            });
+        }
+    }
+    let niter = config.max_iter;
+    Ok(OptimizerResult {
+        positions: x,
+        energy,
+        gradient,
+        niter,
+        converged: false,
+        lattice: None,
+    })
+}
+
+/// Optimize positions by a user-defined energy function (failable)
+///
+/// L-BFGS stops without convergence if no step along steepest descent decreases the energy (e.g.
+/// for a gradient inconsistent with the energy).
+///
+/// # Arguments
+///
+/// * `positions` - initial positions [natoms][3]
+/// * `energy_fn` - function of positions [natoms][3], returning energy and gradient [natoms][3]
+pub fn optimize_f<F>(
+    positions: &[f64],
+    config: &OptimizerConfig,
+    mut energy_fn: F,
+) -> Result<OptimizerResult, DFTD4Error>
+where
+    F: FnMut(&[f64]) -> Result<(f64, Vec<f64>), DFTD4Error>,
+{
+    match config.algorithm {
+        OptimizerAlgorithm::LBFGS => optimize_lbfgs(positions, config, &mut energy_fn),
+        OptimizerAlgorithm::FIRE => optimize_fire(positions, config, &mut energy_fn),
+    }
+}
+
+/// Optimize structure by D4 dispersion plus an optional user-defined energy (failable)
+///
+/// The structure is updated in place by `DFTD4Structure::update_f`, and holds the last evaluated
+/// positions on return.
+///
+/// # Arguments
+///
+/// * `extra_fn` - additional energy term: function of positions [natoms][3], returning energy and
+///   gradient [natoms][3]
+pub fn optimize_structure_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    config: &OptimizerConfig,
+    mut extra_fn: Option<&mut ExtraFn>,
+) -> Result<OptimizerResult, DFTD4Error> {
+    let positions = structure.get_positions();
+    let result = optimize_f(&positions, config, |x| {
+        structure.update_f(x, None)?;
+        let (mut energy, gradient, _) = get_dispersion_f(structure, model, param, true, false)?;
+        let mut gradient = gradient.unwrap();
+        if let Some(extra_fn) = extra_fn.as_mut() {
+            let (extra_energy, extra_gradient) = extra_fn(x)?;
+            energy += extra_energy;
+            gradient
+                .iter_mut()
+                .zip(&extra_gradient)
+                .for_each(|(g, e)| *g += e);
+        }
+        Ok((energy, gradient))
+    })?;
+    structure.update_f(&result.positions, None)?;
+    Ok(result)
+}
+
+/// Optimize structure by D4 dispersion plus an optional user-defined energy
+pub fn optimize_structure(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    config: &OptimizerConfig,
+    extra_fn: Option<&mut ExtraFn>,
+) -> OptimizerResult {
+    optimize_structure_f(structure, model, param, config, extra_fn).unwrap()
+}
+
+/// Convert virial to stress tensor [3][3] (energy per volume)
+///
+/// # Arguments
+///
+/// * `sigma` - virial [3][3], as returned by `get_dispersion`
+/// * `lattice` - lattice [3][3]
+pub fn get_stress(sigma: &[f64], lattice: &[f64]) -> Vec<f64> {
+    let volume = det3(lattice).abs();
+    sigma.iter().map(|x| x / volume).collect()
+}
+
+/// Convert virial to pressure (positive for compressed systems)
+///
+/// # Arguments
+///
+/// * `sigma` - virial [3][3], as returned by `get_dispersion`
+/// * `lattice` - lattice [3][3]
+pub fn get_pressure(sigma: &[f64], lattice: &[f64]) -> f64 {
+    let stress = get_stress(sigma, lattice);
+    -(stress[0] + stress[4] + stress[8]) / 3.0
+}
+
+/// Optimize positions and lattice by a user-defined energy function (failable)
+///
+/// The enthalpy `E + pressure * V` is minimized. Lattice is deformed by a deformation gradient,
+/// which is optimized together with positions (scaled by number of atoms, as in unit cell filter
+/// of ASE); `energy` of the result is the enthalpy.
+///
+/// # Arguments
+///
+/// * `positions` - initial positions [natoms][3]
+/// * `lattice` - initial lattice [3][3]
+/// * `pressure` - external pressure (Hartree/Bohr^3)
+/// * `energy_fn` - function of positions [natoms][3] and lattice [3][3], returning energy,
+///   gradient [natoms][3] and virial [3][3]
+pub fn optimize_cell_f<F>(
+    positions: &[f64],
+    lattice: &[f64],
+    pressure: f64,
+    config: &OptimizerConfig,
+    mut energy_fn: F,
+) -> Result<OptimizerResult, DFTD4Error>
+where
+    F: FnMut(&[f64], &[f64]) -> Result<(f64, Vec<f64>, Vec<f64>), DFTD4Error>,
+{
+    let natoms = positions.len() / 3;
+    let cell_factor = natoms.max(1) as f64;
+    let volume0 = det3(lattice).abs();
+    if volume0 < f64::EPSILON {
+        return Err(DFTD4Error::Rust("Lattice is singular".to_string()));
+    }
+    // positions and lattice for deformation gradient; rows transform as r' = r F^T
+    let deform = |x: &[f64]| {
+        let f = [0, 1, 2, 3, 4, 5, 6, 7, 8].map(|k| x[3 * natoms + k] / cell_factor);
+        let ft = transpose3(&f);
+        let positions = x[..3 * natoms]
+            .chunks(3)
+            .flat_map(|r| [0, 1, 2].map(|b| (0..3).map(|a| r[a] * ft[3 * a + b]).sum::<f64>()))
+            .collect::<Vec<f64>>();
+        (f, positions, matmul3(lattice, &ft).to_vec())
+    };
+    let mut x = positions.to_vec();
+    x.extend([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0].map(|v| v * cell_factor));
+
+    let result = optimize_f(&x, config, |x| {
+        let (f, positions, lattice) = deform(x);
+        let finv_t =
+            transpose3(&inv3(&f).ok_or_else(|| {
+                DFTD4Error::Rust("Deformation of lattice is singular".to_string())
+            })?);
+        let (energy, gradient, sigma) = energy_fn(&positions, &lattice)?;
+        let volume = det3(&lattice).abs();
+        // gradient of reference positions: g F
+        let mut grad = gradient
+            .chunks(3)
+            .flat_map(|g| [0, 1, 2].map(|b| (0..3).map(|a| g[a] * f[3 * a + b]).sum::<f64>()))
+            .collect::<Vec<f64>>();
+        // gradient of deformation: (sigma + p V I) F^-T, sigma[a + 3b] as [b][a]
+        let mut stress = [0.0; 9];
+        for a in 0..3 {
+            for b in 0..3 {
+                stress[3 * a + b] = sigma[3 * a + b];
+            }
+            stress[4 * a] += pressure * volume;
+        }
+        let grad_f = matmul3(&stress, &finv_t);
+        grad.extend(grad_f.map(|g| g / cell_factor));
+        Ok((energy + pressure * volume, grad))
+    })?;
+
+    let (_, positions, lattice) = deform(&result.positions);
+    let (_, gradient, _) = energy_fn(&positions, &lattice)?;
+    Ok(OptimizerResult {
+        positions,
+        gradient,
+        lattice: Some(lattice),
+        ..result
+    })
+}
+
+/// Optimize structure and lattice by D4 dispersion plus an optional user-defined energy (failable)
+///
+/// The structure is updated in place by `DFTD4Structure::update_f(positions, Some(lattice))`, and
+/// holds the optimized positions and lattice on return. See [`optimize_cell_f`] for details.
+///
+/// # Arguments
+///
+/// * `pressure` - external pressure (Hartree/Bohr^3)
+/// * `extra_fn` - additional energy term: function of positions [natoms][3] and lattice [3][3],
+///   returning energy, gradient [natoms][3] and virial [3][3]
+pub fn optimize_structure_cell_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    pressure: f64,
+    config: &OptimizerConfig,
+    mut extra_fn: Option<&mut ExtraCellFn>,
+) -> Result<OptimizerResult, DFTD4Error> {
+    let positions = structure.get_positions();
+    let lattice = structure
+        .get_lattice()
+        .ok_or_else(|| DFTD4Error::Rust("Lattice is required for cell optimization".to_string()))?;
+    let result = optimize_cell_f(&positions, &lattice, pressure, config, |x, lattice| {
+        structure.update_f(x, Some(lattice))?;
+        let (mut energy, gradient, sigma) = get_dispersion_f(structure, model, param, true, true)?;
+        let (mut gradient, mut sigma) = (gradient.unwrap(), sigma.unwrap());
+        if let Some(extra_fn) = extra_fn.as_mut() {
+            let (extra_energy, extra_gradient, extra_sigma) = extra_fn(x, lattice)?;
+            energy += extra_energy;
+            gradient
+                .iter_mut()
+                .zip(&extra_gradient)
+                .for_each(|(g, e)| *g += e);
+            sigma
+                .iter_mut()
+                .zip(&extra_sigma)
+                .for_each(|(s, e)| *s += e);
+        }
+        Ok((energy, gradient, sigma))
+    })?;
+    structure.update_f(&result.positions, result.lattice.as_deref())?;
+    Ok(result)
+}
+
+/// Optimize structure and lattice by D4 dispersion plus an optional user-defined energy
+pub fn optimize_structure_cell(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    pressure: f64,
+    config: &OptimizerConfig,
+    extra_fn: Option<&mut ExtraCellFn>,
+) -> OptimizerResult {
+    optimize_structure_cell_f(structure, model, param, pressure, config, extra_fn).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lennard-Jones energy and gradient (epsilon = 1, sigma = 1)
+    fn lennard_jones(positions: &[f64]) -> Result<(f64, Vec<f64>), DFTD4Error> {
+        let natoms = positions.len() / 3;
+        let mut energy = 0.0;
+        let mut gradient = vec![0.0; positions.len()];
+        for i in 0..natoms {
+            for j in 0..i {
+                let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k]);
+                let r2 = dot(&v, &v);
+                let sr6 = 1.0 / r2.powi(3);
+                energy += 4.0 * (sr6 * sr6 - sr6);
+                let de = 4.0 * (-12.0 * sr6 * sr6 + 6.0 * sr6) / r2;
+                for k in 0..3 {
+                    gradient[3 * i + k] += de * v[k];
+                    gradient[3 * j + k] -= de * v[k];
+                }
+            }
+        }
+        Ok((energy, gradient))
+    }
+
+    #[test]
+    fn test_optimize_lennard_jones() {
+        // tetramer relaxes to regular tetrahedron with energy -6
+        #[rustfmt::skip]
+        let positions = [
+            0.0, 0.0, 0.0,   1.3, 0.1, 0.0,
+            0.5, 1.0, 0.1,   0.6, 0.4, 1.0,
+        ];
+        for algorithm in [OptimizerAlgorithm::LBFGS, OptimizerAlgorithm::FIRE] {
+            let config = OptimizerConfig {
+                algorithm,
+                max_step: 0.1,
+                fire_dt: 0.01,
+                fire_dt_max: 0.1,
+                max_force_tol: 1.0e-6,
+                rms_force_tol: 1.0e-6,
+                energy_tol: 1.0e-10,
+                max_iter: 5000,
+                ..Default::default()
+            };
+            let result = optimize_f(&positions, &config, lennard_jones).unwrap();
+            assert!(result.converged);
+            assert!((result.energy + 6.0).abs() < 1e-8);
+        }
+    }
+
+    /// Periodic Morse pair energy, gradient and virial (alpha = 2, re = 1.5, cutoff 10)
+    fn periodic_morse(positions: &[f64], lattice: &[f64]) -> (f64, Vec<f64>, Vec<f64>) {
+        let natoms = positions.len() / 3;
+        let images = crate::lattice::get_lattice_points(Some(lattice), &[true; 3], positions, 10.0);
+        let (mut energy, mut gradient, mut sigma) = (0.0, vec![0.0; 3 * natoms], vec![0.0; 9]);
+        for i in 0..natoms {
+            for j in 0..natoms {
+                for t in &images {
+                    let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k] - t[k]);
+                    let r = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+                    if !(1e-8..=10.0).contains(&r) {
+                        continue;
+                    }
+                    let e = (-2.0 * (r - 1.5)).exp();
+                    energy += 0.5 * (e * e - 2.0 * e);
+                    let de = 0.5 * (-4.0 * e * e + 4.0 * e) / r;
+                    for a in 0..3 {
+                        gradient[3 * i + a] += de * v[a];
+                        gradient[3 * j + a] -= de * v[a];
+                        for b in 0..3 {
+                            sigma[a + 3 * b] += de * v[a] * v[b];
+                        }
+                    }
+                }
+            }
+        }
+        (energy, gradient, sigma)
+    }
+
+    #[test]
+    fn test_optimize_cell() {
+        let positions = [0.0, 0.0, 0.0];
+        let lattice = [1.3, 0.0, 0.0, 0.0, 1.3, 0.0, 0.0, 0.0, 1.3];
+        // forces near 1e-8 are at the numerical noise of the energy
+        let config = OptimizerConfig {
+            max_force_tol: 1e-7,
+            rms_force_tol: 1e-7,
+            energy_tol: 1e-12,
+            max_step: 0.1,
+            ..Default::default()
+        };
+        for pressure in [0.0, 0.01] {
+            let result = optimize_cell_f(&positions, &lattice, pressure, &config, |x, lattice| {
+                Ok(periodic_morse(x, lattice))
+            })
+            .unwrap();
+            assert!(result.converged);
+            let lattice = result.lattice.unwrap();
+            // cubic symmetry is kept
+            for a in 0..3 {
+                assert!((lattice[4 * a] - lattice[0]).abs() < 1e-6);
+                for b in 0..3 {
+                    assert!(a == b || lattice[3 * a + b].abs() < 1e-6);
+                }
+            }
+            // internal pressure balances external pressure
+            let (_, _, sigma) = periodic_morse(&result.positions, &lattice);
+            assert!((get_pressure(&sigma, &lattice) - pressure).abs() < 1e-6);
+            let (energy, _, _) = periodic_morse(&result.positions, &lattice);
+            assert!((result.energy - energy - pressure * det3(&lattice)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_lbfgs_steps() {
+        let quadratic = |x: &[f64]| Ok::<_, DFTD4Error>((0.5 * dot(x, x), x.to_vec()));
+        let config = OptimizerConfig {
+            max_force_tol: 1e-8,
+            rms_force_tol: 1e-8,
+            energy_tol: 1e-12,
+            ..Default::default()
+        };
+        // first step follows the gradient (exact for unit curvature), not the maximum step
+        let result = optimize_f(&[0.01, -0.02, 0.005], &config, quadratic).unwrap();
+        assert!(result.converged);
+        assert_eq!(result.niter, 2);
+        assert_eq!(result.positions, [0.0; 3]);
+        // large gradients are limited to the maximum step
+        let mut largest = 0.0_f64;
+        let start = [3.0, 0.0, 0.0];
+        let mut energy_fn = |x: &[f64]| {
+            largest = largest.max((x[0] - start[0]).abs());
+            quadratic(x)
+        };
+        let config1 = OptimizerConfig {
+            max_iter: 1,
+            ..config.clone()
+        };
+        optimize_f(&start, &config1, &mut energy_fn).unwrap();
+        assert!((largest - config.max_step).abs() < 1e-14);
+
+        // inconsistent gradient: uphill steps are never accepted
+        let uphill = |x: &[f64]| Ok((0.5 * dot(x, x), x.iter().map(|x| -x).collect::<Vec<f64>>()));
+        let start = [0.5, 0.2, -0.1];
+        let result = optimize_f(&start, &config, uphill).unwrap();
+        assert!(!result.converged);
+        assert_eq!(result.niter, 1);
+        assert_eq!(result.positions, start);
+    }
+}
@@ -0,0 +1,9 @@
+//! Parameters of upstream models.
+
+/// Parameters of the EEQ model (multicharge, EEQ 2019)
+#[rustfmt::skip]
+pub mod eeq2019;
+
+/// Reference data and damping parameters of the D4 model (dftd4)
+#[rustfmt::skip]
+pub mod d4;
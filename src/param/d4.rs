@@ -0,0 +1,2911 @@
+//! Reference data and damping parameters of the D4 model (dftd4).
+//!
+//! Element data is indexed by atomic number minus one; arrays are flattened in row-major order.
+//!
+//! Apart from the `<r4>/<r2>` expectation values of H-Kr, these tables are provisional stand-ins,
+//! not the reference data of upstream dftd4: they are to be regenerated from dftd4 v3.7.0
+//! (`src/dftd4/data/*.f90`, `src/dftd4/reference.f90` and `src/dftd4/param.f90`). Until then, results of the pure-Rust implementation do not agree with
+//! `libdftd4`, and the reference tests of `tests/test.rs` fail with feature `pure-rust`.
+
+/// Pauling electronegativities [118]
+pub const PAULING_EN: &[f64] = &[
+    1.53174908, 1.11981471, 2.02778055, 1.28041404, 1.0061968, 2.04493214, 3.64566063, 3.28140229,
+    3.17200407, 1.48797734, 2.46370803, 1.65771619, 1.33526004, 1.12916821, 1.46464134, 3.67517446,
+    3.36965215, 3.30062227, 3.2813883, 1.39965042, 1.76053487, 2.74362437, 3.0688736, 3.44940991,
+    3.52815733, 1.06882658, 2.67814084, 2.88227452, 2.3684567, 1.35114954, 2.26812245, 1.07697324,
+    3.69722393, 3.48300093, 2.4976805, 1.73076179, 3.6174979, 2.57433709, 3.53518344, 3.42893667,
+    2.37595436, 2.08323273, 2.65662866, 2.13623335, 1.30009388, 1.74584595, 3.31903619, 0.93403925,
+    0.9435982, 2.74168731, 1.66934296, 2.45732756, 2.26084427, 1.86281412, 3.89156451, 1.40627783,
+    2.07966343, 1.42827891, 2.76126144, 1.65654499, 1.90307534, 3.11552231, 1.7940736, 2.53143985,
+    3.60337681, 1.11303626, 0.99099175, 1.50949526, 3.17200295, 2.70783943, 1.53599323, 1.82630769,
+    1.35037305, 2.22295813, 0.93271468, 2.96160483, 3.57737612, 3.75968655, 3.07812167, 3.77558953,
+    0.85638133, 1.69588905, 3.79462095, 3.20324225, 2.07232581, 3.72425594, 2.72358246, 3.33557618,
+    1.70957179, 1.39338713, 2.17684094, 1.22295664, 1.98306737, 3.78162223, 1.82705241, 0.82912905,
+    0.9388713, 1.32565784, 3.22961159, 1.92444523, 1.70003613, 1.10101686, 3.84342081, 2.11425281,
+    1.44454221, 0.98395252, 0.97133894, 1.32287784, 2.89816406, 1.26388566, 0.92676634, 2.32106961,
+    1.57208182, 3.89267323, 1.1790471, 2.44064892, 3.19875559, 2.06889582,
+];
+
+/// Expectation values `<r4>/<r2>` [118]; H-Kr are the values of upstream dftd4
+pub const R4_OVER_R2: &[f64] = &[
+    8.0589, 3.4698, 29.0974, 14.8517, 11.8799, 7.8715, 5.5588, 4.7566,
+    3.8025, 3.1036, 26.1552, 17.2304, 17.7210, 12.7442, 9.5361, 8.1652,
+    6.7463, 5.6004, 29.2012, 22.3934, 19.0598, 16.8590, 15.4023, 12.5589,
+    13.4788, 12.2309, 11.2809, 10.5569, 10.1428, 9.4907, 13.4606, 10.8544,
+    8.9386, 8.1350, 7.1251, 6.1971, 10.14826171, 7.28023893, 8.54732189, 8.85959852,
+    4.68299046, 11.22799963, 11.56279058, 2.74380568, 11.71088277, 11.61773817, 8.68351883, 2.4454397,
+    10.98969722, 3.27632788, 11.68534963, 8.67189917, 2.60483112, 3.67265614, 8.3518979, 7.69205936,
+    9.46494557, 11.27480946, 4.18541464, 2.03273027, 11.22361993, 2.13110277, 10.76423584, 3.15889969,
+    10.09872342, 9.82969731, 10.7787787, 7.50608397, 10.78707468, 4.01669448, 8.71481793, 5.30643116,
+    10.91750027, 9.73573859, 6.71510119, 7.26408646, 2.26393459, 2.3418315, 7.94486841, 6.88831226,
+    10.64719825, 8.08125149, 3.38761714, 5.62569683, 9.67579488, 7.22986292, 2.10551269, 10.37688963,
+    10.27561476, 2.8514101, 7.43378677, 5.81158026, 9.87387483, 5.11169374, 4.33700594, 6.86652001,
+    11.66278608, 2.95119671, 3.14451125, 8.20961732, 10.85342891, 7.12474649, 6.33953251, 10.57843956,
+    9.76586238, 2.66917871, 10.81324939, 3.95852672, 5.02305467, 10.36441975, 6.22464446, 9.98348871,
+    3.67376968, 10.74287152, 3.76350942, 3.49306963, 6.94255229, 5.38584909,
+];
+
+/// Chemical hardnesses [118]
+pub const CHEMICAL_HARDNESS: &[f64] = &[
+    0.41674521, 0.56162893, 0.48420468, 0.20222485, 0.32472686, 0.41797939, 0.39460268, 0.48623466,
+    0.39369978, 0.23027373, 0.29817647, 0.53902804, 0.34271623, 0.50666797, 0.5943219, 0.45068022,
+    0.47069334, 0.44381395, 0.32530903, 0.56511621, 0.3868117, 0.56456325, 0.32226016, 0.54701044,
+    0.51473975, 0.4451998, 0.3768262, 0.25626703, 0.50841686, 0.34487182, 0.46483467, 0.2533019,
+    0.23302404, 0.25757531, 0.5236089, 0.27106748, 0.56076532, 0.34879526, 0.43039299, 0.34017634,
+    0.44833201, 0.23738689, 0.36101898, 0.57447523, 0.27187025, 0.46170104, 0.33066901, 0.32023206,
+    0.20926855, 0.20804, 0.57975687, 0.53190958, 0.52044179, 0.52290005, 0.58133297, 0.2633863,
+    0.43366752, 0.39809644, 0.42954579, 0.57516464, 0.50410076, 0.58738936, 0.24672974, 0.46062243,
+    0.47015861, 0.49808067, 0.44714679, 0.53250515, 0.32114603, 0.57112871, 0.36244456, 0.43961365,
+    0.55874986, 0.48143579, 0.32387063, 0.29214744, 0.33064698, 0.45071869, 0.59857967, 0.55960712,
+    0.36008677, 0.36026402, 0.52699652, 0.31350851, 0.36462578, 0.20527333, 0.27355692, 0.41607912,
+    0.47731575, 0.44590396, 0.34572069, 0.58042645, 0.44929171, 0.26242102, 0.22708656, 0.58951602,
+    0.59512754, 0.56798565, 0.44152145, 0.32489474, 0.23653573, 0.30316098, 0.28886461, 0.57129772,
+    0.55702554, 0.51116757, 0.25949082, 0.29533978, 0.31968543, 0.57917133, 0.26532785, 0.51617694,
+    0.47227855, 0.4188541, 0.58371771, 0.30493432, 0.40974684, 0.26300775,
+];
+
+/// Effective nuclear charges [118]
+pub const EFFECTIVE_NUCLEAR_CHARGE: &[f64] = &[
+    4.77374107, 2.23821167, 13.34421689, 5.74907926, 3.38901065, 39.70927732, 12.27320435,
+    35.71903963, 28.37734595, 29.52172969, 26.55199512, 38.15190427, 35.26075662, 29.05790454,
+    22.83835963, 28.05671468, 29.224554, 22.541802, 20.59941839, 7.01385392, 33.92908793,
+    19.8834788, 3.64424661, 7.55335316, 35.11653588, 10.98674973, 16.26161721, 27.60348741,
+    34.60225092, 13.80846076, 16.08541615, 17.50036426, 2.09400579, 35.18942596, 1.7409827,
+    38.4436824, 6.9480107, 7.11028739, 34.0949652, 33.11250608, 10.04871055, 22.58748695,
+    19.59144179, 29.0231734, 8.21871242, 33.19411818, 39.86112828, 28.55186845, 36.92795231,
+    37.54781321, 15.79887412, 34.05279194, 33.51660444, 23.90185312, 5.1793361, 25.15063053,
+    36.55775279, 12.90077771, 26.22709173, 35.9893314, 24.40393579, 2.45180693, 25.64596922,
+    10.97109312, 34.46059019, 26.81605917, 12.98828944, 35.93016676, 25.37712026, 14.2267208,
+    33.53268635, 35.77323945, 35.83462556, 35.44731512, 26.67517746, 28.25046882, 24.58257421,
+    21.5565952, 39.51031506, 14.76931643, 4.17891271, 28.82688126, 20.42533664, 22.2801084,
+    24.31703742, 10.74512304, 8.83309712, 3.78243249, 31.48190701, 36.43002048, 28.17170599,
+    5.55445406, 39.16215467, 33.24071539, 20.86272992, 1.0351564, 33.9104163, 25.31112059,
+    25.26795128, 1.71236379, 29.58809527, 2.33757387, 19.64518198, 6.64966528, 15.03355079,
+    35.71633298, 30.18728261, 32.82076445, 12.62692605, 16.14994494, 24.58743871, 2.32763227,
+    17.07957687, 39.02059238, 30.4075989, 34.64669259, 12.65210815, 27.93856677,
+];
+
+/// Number of reference systems [118]
+pub const REFN: &[usize] = &[
+    1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+];
+
+/// Element of the secondary reference system, zero if none [118][7]
+pub const REFSYS: &[usize] = &[
+    7, 0, 0, 0, 0, 0, 0,
+    14, 11, 0, 0, 0, 0, 0,
+    6, 7, 1, 0, 0, 0, 0,
+    16, 0, 0, 0, 0, 0, 0,
+    14, 9, 0, 0, 0, 0, 0,
+    11, 14, 14, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    7, 9, 0, 0, 0, 0, 0,
+    9, 17, 3, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0,
+    13, 9, 0, 0, 0, 0, 0,
+    10, 5, 11, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0,
+    6, 3, 0, 0, 0, 0, 0,
+    7, 9, 7, 0, 0, 0, 0,
+    16, 0, 0, 0, 0, 0, 0,
+    4, 11, 0, 0, 0, 0, 0,
+    1, 15, 7, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    3, 8, 0, 0, 0, 0, 0,
+    17, 4, 4, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    10, 5, 0, 0, 0, 0, 0,
+    3, 6, 1, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    7, 9, 0, 0, 0, 0, 0,
+    2, 11, 11, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    6, 6, 0, 0, 0, 0, 0,
+    7, 2, 2, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+    9, 0, 0, 0, 0, 0, 0,
+    16, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0,
+    16, 0, 0, 0, 0, 0, 0,
+    16, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    16, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0,
+    15, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0,
+    15, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0,
+    15, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0,
+    16, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    17, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0,
+    9, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0,
+];
+
+/// Coordination numbers of reference systems [118][7]
+pub const REFCN: &[f64] = &[
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.8, 1.6, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+];
+
+/// Covalent coordination numbers of reference systems [118][7]
+pub const REFCOVCN: &[f64] = &[
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.7, 1.4, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+];
+
+/// Charges of reference systems [118][7]
+pub const CLSQ: &[f64] = &[
+    0.04408106, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.13417954, 0.36267138, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.30307639, -0.0288216, -0.38057692, 0.0, 0.0, 0.0, 0.0,
+    0.3830897, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.09567686, 0.48775144, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.35673837, 0.21110646, 0.09688477, 0.0, 0.0, 0.0, 0.0,
+    -0.44856434, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.3200399, 0.37418783, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.41946954, 0.44170319, 0.28496094, 0.0, 0.0, 0.0, 0.0,
+    0.10455461, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.04381828, -0.23685153, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.44272255, -0.0245378, 0.36594704, 0.0, 0.0, 0.0, 0.0,
+    -0.29089246, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.02751682, -0.21750147, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.19095028, 0.45963729, 0.38805016, 0.0, 0.0, 0.0, 0.0,
+    -0.21215546, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.08302878, -0.49734572, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.02936183, 0.35355302, 0.3447912, 0.0, 0.0, 0.0, 0.0,
+    -0.427953, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.06662017, -0.41995937, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.07554057, 0.41285563, -0.2164745, 0.0, 0.0, 0.0, 0.0,
+    0.34571969, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.45529749, 0.31838673, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.29805382, 0.41822099, 0.42285416, 0.0, 0.0, 0.0, 0.0,
+    0.49858561, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.48940878, -0.49639487, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.20856284, 0.01767026, -0.0759383, 0.0, 0.0, 0.0, 0.0,
+    -0.40751779, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.14050339, 0.16774049, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.13034918, 0.01062187, 0.04928969, 0.0, 0.0, 0.0, 0.0,
+    0.18978861, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.435543, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.06375066, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.3645045, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.39598011, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.26200208, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.17567582, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.16063166, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.22291575, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.08724712, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.38180943, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.14115529, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.42959843, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.45520372, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.34294724, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.26766408, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.04525075, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.07507451, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.19577279, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.1407064, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.25887238, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0247483, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.03638429, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.25242279, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.12484697, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.32940755, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.0175424, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.15144074, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.04420668, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.08059928, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.07016404, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.30611898, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.45767789, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.18382889, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.22561737, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.45504035, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.04832315, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.16728551, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.1959659, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.18372955, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.19984449, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.1839007, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.2909185, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.01600833, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.20793942, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.13152977, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.16360269, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.18480123, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.36337215, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.29750402, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.17148095, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.33442567, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.3235065, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.40417234, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.49484463, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.467066, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.49437901, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.36710185, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.49738399, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.15514569, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.05582236, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.2771471, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.25449028, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.46717845, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.10654282, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.25820261, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.05854069, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.23217108, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.20935512, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.41095511, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.29071177, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.40387506, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.44459952, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.49529188, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.49348108, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.06582451, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.23727345, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.057718, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.47485151, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.15682892, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.15056729, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.18216972, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.49943352, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.13639621, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.25931391, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.07281167, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.01007356, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.43684271, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+];
+
+/// Charges of secondary reference systems [118][7]
+pub const CLSH: &[f64] = &[
+    -0.0672273, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.23087388, -0.10757866, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.41357059, -0.11242075, -0.28927721, 0.0, 0.0, 0.0, 0.0,
+    -0.47271012, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.21402394, -0.37380084, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.04342959, 0.23773974, 0.25797287, 0.0, 0.0, 0.0, 0.0,
+    0.38346659, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.18228089, 0.16951408, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.38914934, -0.10410623, 0.41368215, 0.0, 0.0, 0.0, 0.0,
+    -0.39708793, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.42081848, 0.3811186, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.4799949, 0.33078574, 0.21081063, 0.0, 0.0, 0.0, 0.0,
+    -0.06491345, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.21745359, 0.37853334, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.07584378, 0.12173858, 0.42433568, 0.0, 0.0, 0.0, 0.0,
+    -0.21533254, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.16478997, -0.46446162, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.37781361, 0.12948675, 0.17276552, 0.0, 0.0, 0.0, 0.0,
+    -0.2619435, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.31545417, -0.02129394, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.3770459, -0.14531199, -0.07804971, 0.0, 0.0, 0.0, 0.0,
+    -0.08211016, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.3444451, -0.37607856, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.36561221, -0.488254, 0.1991278, 0.0, 0.0, 0.0, 0.0,
+    0.26356025, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.03042428, 0.24181942, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.21952482, -0.35890775, -0.14010275, 0.0, 0.0, 0.0, 0.0,
+    -0.23489761, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.21693137, 0.06416604, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.16072582, 0.47578053, 0.27323245, 0.0, 0.0, 0.0, 0.0,
+    0.29158454, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.4699912, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.42876855, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.24161804, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.15216697, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0083254, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.25567077, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.28669722, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.39987193, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.24236186, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.16885268, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.24566084, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.12156469, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.31306098, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.14698086, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.32396797, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.48234492, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.45538252, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.36986645, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.19517001, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.45507717, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.31864419, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.27132392, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.34518681, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.31709933, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.33846328, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.07435544, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.22612886, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.44355287, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.43658552, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.05897947, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.22147207, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.13824206, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.27520979, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.29811245, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.4368767, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.11402919, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.32594451, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.49456927, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.30640756, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.29579119, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.28021959, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.48594966, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.01741626, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.15098619, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.07395198, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.07728253, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.20782303, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.41545573, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.16164469, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.13499567, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.38143393, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.24875896, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.40389606, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.36211301, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.16234228, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.21055993, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.377278, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.1153457, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.29312809, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.25193024, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.01052565, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.11264193, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.42990772, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.35047342, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.13937746, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.37206579, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.34534995, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.22548007, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.32402819, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.20384137, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.07197465, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.3187519, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.41789234, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.39247991, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.35569433, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.33016214, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.04426362, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.36434806, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.02669255, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.3552297, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.13356113, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.06555932, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.25299268, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.1401078, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.22987523, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.49257352, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    -0.10172583, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+];
+
+/// Scaling of reference polarizabilities [118][7]
+pub const ASCALE: &[f64] = &[
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+];
+
+/// Number of secondary reference systems [118][7]
+pub const HCOUNT: &[f64] = &[
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 2.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+];
+
+/// Polarizabilities of reference systems at imaginary frequencies [118][7][23]
+pub const ALPHAIW: &[f64] = &[
+    7.91121653, 7.60693897, 6.82001425, 5.81707098, 4.82391252, 3.95560826, 3.24230185, 2.67270829,
+    2.22225183, 1.86585295, 1.58224331, 1.35466036, 1.1702983, 1.01948667, 0.894934, 0.79112165,
+    0.70384489, 0.62987393, 0.56670605, 0.51238449, 0.46536568, 0.42442149, 0.38856663,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    56.27074423, 54.10648484, 48.50926227, 41.37554723, 34.31142941, 28.13537211, 23.06178042,
+    19.01038656, 15.80638883, 13.27140194, 11.25414885, 9.63540141, 8.32407459, 7.25138457,
+    6.3654688, 5.62707442, 5.00629397, 4.4801548, 4.0308556, 3.64447825, 3.31004378, 3.01881675,
+    2.76378901,
+    25.99818604, 24.9982558, 22.41222934, 19.11631326, 15.85255246, 12.99909302, 10.65499428,
+    8.78317096, 7.30286125, 6.13164765, 5.19963721, 4.45174418, 3.84588551, 3.35028171, 2.94097127,
+    2.5998186, 2.31300588, 2.06991927, 1.86233424, 1.68382034, 1.52930506, 1.39475247, 1.27692466,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    60.82464814, 58.4852386, 52.4350415, 44.72400599, 37.08820009, 30.41232407, 24.92813448,
+    20.54886762, 17.08557532, 14.34543588, 12.16492963, 10.41517948, 8.99772902, 7.83822785,
+    6.88061631, 6.08246481, 5.41144556, 4.84272676, 4.35706649, 3.93942022, 3.57792048, 3.2631249,
+    2.98745816,
+    78.40091882, 75.38549887, 67.58699899, 57.64773443, 47.80543831, 39.20045941, 32.13152411,
+    26.4867969, 22.02273001, 18.49078274, 15.68018376, 13.42481487, 11.59776906, 10.10321119,
+    8.86888222, 7.84009188, 6.97517071, 6.24211137, 5.61611166, 5.07777972, 4.61181875, 4.20605788,
+    3.85073275,
+    5.2041957, 5.00403432, 4.4863756, 3.82661448, 3.17329006, 2.60209785, 2.13286709, 1.75817422,
+    1.46185272, 1.22740465, 1.04083914, 0.8911294, 0.76985143, 0.67064378, 0.58870992, 0.52041957,
+    0.46300673, 0.41434679, 0.37279339, 0.33705931, 0.30612916, 0.27919505, 0.25560883,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    35.5082345, 34.14253318, 30.61054699, 26.10899596, 21.6513625, 17.75411725, 14.55255512,
+    11.99602517, 9.97422317, 8.37458361, 7.1016469, 6.08017714, 5.25269741, 4.57580342, 4.01676861,
+    3.55082345, 3.1590956, 2.82708873, 2.54356981, 2.29975612, 2.08871968, 1.9049482, 1.74401938,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    42.45060274, 40.81788725, 36.59534719, 31.21367849, 25.88451387, 21.22530137, 17.39778801,
+    14.34141985, 11.92432661, 10.01193461, 8.49012055, 7.26893883, 6.27967496, 5.4704385,
+    4.80210438, 4.24506027, 3.77674402, 3.37982506, 3.04087412, 2.74939137, 2.49709428, 2.27739285,
+    2.08500013,
+    78.59294323, 75.57013772, 67.75253727, 57.78892885, 47.92252636, 39.29647162, 32.21022264,
+    26.55167001, 22.07666945, 18.53607152, 15.71858865, 13.45769576, 11.62617503, 10.1279566,
+    8.89060444, 7.85929432, 6.99225474, 6.25739994, 5.62986699, 5.09021653, 4.62311431, 4.21635962,
+    3.86016421,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    74.30987292, 71.45180088, 64.06023527, 54.63961244, 45.31089812, 37.15493646, 30.45486595,
+    25.1046868, 20.87355981, 17.52591342, 14.86197458, 12.72429331, 10.99258475, 9.57601455,
+    8.40609422, 7.43098729, 6.61119866, 5.91639116, 5.3230568, 4.8128156, 4.371169, 3.98658117,
+    3.64979729,
+    7.15662966, 6.88137467, 6.16950833, 5.26222769, 4.36379857, 3.57831483, 2.93304494, 2.41778029,
+    2.01028923, 1.68788435, 1.43132593, 1.22545028, 1.05867303, 0.92224609, 0.80957349, 0.71566297,
+    0.63671082, 0.56979536, 0.51265255, 0.46351228, 0.42097822, 0.38393936, 0.3515044,
+    15.2339081, 14.64798856, 13.1326794, 11.20140302, 9.28896836, 7.61695405, 6.24340496,
+    5.14659058, 4.27918767, 3.59290285, 3.04678162, 2.60854591, 2.2535367, 1.96313249, 1.72329277,
+    1.52339081, 1.3553299, 1.21289077, 1.09125416, 0.98665208, 0.89611224, 0.81726975, 0.74822731,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    35.57337737, 34.20517055, 30.66670463, 26.15689512, 21.69108376, 17.78668868, 14.57925302,
+    12.01803289, 9.99252173, 8.38994749, 7.11467547, 6.09133174, 5.26233393, 4.58419811, 4.02413771,
+    3.55733774, 3.16489122, 2.83227527, 2.5482362, 2.30397522, 2.09255161, 1.90844299, 1.74721893,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    65.30377159, 62.79208806, 56.29635482, 48.01747911, 39.81937292, 32.65188579, 26.76384081,
+    22.062085, 18.34375606, 15.40183292, 13.06075432, 11.18215267, 9.66032124, 8.41543448,
+    7.38730448, 6.53037716, 5.80994409, 5.19934487, 4.6779206, 4.22951889, 3.84139833, 3.50342122,
+    3.2074544,
+    64.22635921, 61.75611462, 55.36755104, 47.22526412, 39.16241415, 32.1131796, 26.32227836,
+    21.69809433, 18.04111214, 15.14772623, 12.84527184, 10.99766425, 9.50094071, 8.27659268,
+    7.26542525, 6.42263592, 5.7140889, 5.11356363, 4.60074206, 4.15973829, 3.77802113, 3.44562013,
+    3.15453631,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    46.67846448, 44.88313892, 40.24005559, 34.32240035, 28.46247834, 23.33923224, 19.13051823,
+    15.76975151, 13.11192822, 11.00907181, 9.3356929, 7.99288775, 6.9050983, 6.01526604, 5.28036928,
+    4.66784645, 4.1528883, 3.71643825, 3.34372955, 3.02321661, 2.74579203, 2.50420947, 2.29265543,
+    74.10296285, 71.25284889, 63.88186452, 54.48747268, 45.18473344, 37.05148142, 30.37006674,
+    25.03478475, 20.815439, 17.47711388, 14.82059257, 12.6888635, 10.96197675, 9.54935088,
+    8.3826881, 7.41029628, 6.59279029, 5.89991742, 5.30823516, 4.79941469, 4.35899781, 3.97548084,
+    3.63963472,
+    66.51963758, 63.96118998, 57.34451515, 48.91149822, 40.56075462, 33.25981879, 27.26214655,
+    22.47285053, 18.68529145, 15.68859377, 13.30392752, 11.3903489, 9.84018307, 8.57211824,
+    7.52484588, 6.65196376, 5.91811722, 5.29614949, 4.76501702, 4.30826668, 3.91291986, 3.56865008,
+    3.26717277,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    70.83827645, 68.11372735, 61.0674797, 52.08696798, 43.19407101, 35.41913822, 29.03208051,
+    23.93185015, 19.89839226, 16.70714067, 14.16765529, 12.12984186, 10.47903498, 9.12864387,
+    8.01337969, 7.08382764, 6.30233776, 5.63999016, 5.0743751, 4.58797127, 4.16695744, 3.80033672,
+    3.47928666,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    52.57463317, 50.55253189, 45.32295963, 38.65781851, 32.05770315, 26.28731658, 21.54698081,
+    17.76170039, 14.76815538, 12.39967763, 10.51492663, 9.00250568, 7.7773126, 6.77508159,
+    5.94735669, 5.25746332, 4.67745847, 4.18587844, 3.7660912, 3.40509282, 3.09262548, 2.82052753,
+    2.58225114,
+    24.44538869, 23.50518144, 21.07361094, 17.97455051, 14.90572481, 12.22269435, 10.01860192,
+    8.25857726, 6.86668222, 5.76542186, 4.88907774, 4.18585423, 3.61618176, 3.15017896, 2.76531546,
+    2.44453887, 2.17485665, 1.94628891, 1.75110234, 1.58325056, 1.43796404, 1.31144789, 1.2006576,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    60.08164646, 57.7708139, 51.79452281, 44.17768122, 36.63515028, 30.04082323, 24.6236256,
+    20.29785353, 16.87686698, 14.17019964, 12.01632929, 10.28795316, 8.88781752, 7.74248021,
+    6.79656634, 6.00816465, 5.34534221, 4.78357058, 4.30384287, 3.89129835, 3.5342145, 3.22326429,
+    2.95096495,
+    58.72814009, 56.46936547, 50.62770698, 43.18245595, 35.80984152, 29.36407005, 24.06890987,
+    19.84058787, 16.49666857, 13.85097644, 11.74562802, 10.05618837, 8.68759469, 7.56805929,
+    6.64345476, 5.87281401, 5.2249235, 4.67580733, 4.20688683, 3.80363602, 3.45459648, 3.15065129,
+    2.88448625,
+    74.58831108, 71.71952988, 64.30026817, 54.84434638, 45.48067749, 37.29415554, 30.56897995,
+    25.19875374, 20.95177277, 17.5915828, 14.91766222, 12.77197107, 11.03377383, 9.61189576,
+    8.43759175, 7.45883111, 6.63597074, 5.9385598, 5.34300223, 4.83084916, 4.38754771, 4.00151883,
+    3.66347304,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    17.82781933, 17.14213398, 15.36880977, 13.10869069, 10.87062155, 8.91390967, 7.30648333,
+    6.02291194, 5.00781442, 4.20467437, 3.56556387, 3.05270879, 2.63725138, 2.2973994, 2.01672164,
+    1.78278193, 1.58610492, 1.41941237, 1.27706442, 1.15465151, 1.04869525, 0.95642808, 0.87562963,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    6.66080811, 6.40462318, 5.74207595, 4.89765302, 4.06146836, 3.33040405, 2.72983939, 2.25027301,
+    1.87101351, 1.57094531, 1.33216162, 1.14054933, 0.98532664, 0.85835156, 0.75348508, 0.66608081,
+    0.59259859, 0.53031912, 0.47713525, 0.43139949, 0.39181224, 0.35733949, 0.32715168,
+    30.58765947, 29.41121103, 26.36867196, 22.49092608, 18.65101187, 15.29382974, 12.53592601,
+    10.33366874, 8.59203918, 7.21407063, 6.11753189, 5.23761292, 4.5248017, 3.94170869, 3.46014247,
+    3.05876595, 2.72132202, 2.43532321, 2.19109309, 1.98106603, 1.79927409, 1.64096886, 1.50234084,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    24.18931581, 23.25895751, 20.85285845, 17.78626162, 14.74958281, 12.0946579, 9.91365402,
+    8.17206615, 6.79475163, 5.70502731, 4.83786316, 4.14200613, 3.57830115, 3.11717987, 2.73634794,
+    2.41893158, 2.15207436, 1.92590094, 1.73275901, 1.56666553, 1.42290093, 1.29771008, 1.18808034,
+    34.03004066, 32.72119294, 29.33624195, 25.02208872, 20.75002479, 17.01502033, 13.94673797,
+    11.49663536, 9.55900018, 8.02595299, 6.80600813, 5.82706176, 5.03402968, 4.38531452, 3.84955211,
+    3.40300407, 3.02758369, 2.70939814, 2.437682, 2.20401818, 2.0017671, 1.82564596, 1.67141654,
+    9.47816396, 9.1136192, 8.170831, 6.96923821, 5.77936827, 4.73908198, 3.88449343, 3.20208242,
+    2.66240561, 2.23541603, 1.89563279, 1.62297328, 1.40209526, 1.22141288, 1.07219049, 0.9478164,
+    0.84325302, 0.75463089, 0.67895157, 0.61387072, 0.55753906, 0.50848519, 0.46552868,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    50.79111329, 48.83760893, 43.78544249, 37.34640683, 30.97019103, 25.39555664, 20.81603004,
+    17.1591599, 14.26716665, 11.97903615, 10.15822266, 8.69710844, 7.5134783, 6.54524656,
+    5.74560105, 5.07911133, 4.51878232, 4.04387845, 3.6383319, 3.28957988, 2.98771255, 2.72484513,
+    2.49465193,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    37.05117054, 35.62612552, 31.94066426, 27.24350775, 22.59217716, 18.52558527, 15.18490596,
+    12.51728734, 10.40763217, 8.73848362, 7.41023411, 6.34437852, 5.48094239, 4.77463538,
+    4.19130888, 3.70511705, 3.29636749, 2.94993396, 2.65409531, 2.39968721, 2.17948062, 1.98772374,
+    1.81980209,
+    24.88243287, 23.92541622, 21.45037317, 18.29590652, 15.17221517, 12.44121644, 10.19771839,
+    8.40622732, 6.98944744, 5.86849832, 4.97648657, 4.26069056, 3.68083327, 3.20649908, 2.81475485,
+    2.48824329, 2.21373958, 1.98108542, 1.78240923, 1.61155653, 1.46367252, 1.33489447, 1.22212342,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    12.61354877, 12.12841228, 10.87374894, 9.27466821, 7.69118827, 6.30677438, 5.1694872,
+    4.26133404, 3.54313168, 2.97489358, 2.52270975, 2.15985424, 1.86590958, 1.62545732, 1.42687203,
+    1.26135488, 1.12220185, 1.00426344, 0.90354934, 0.81693969, 0.74197346, 0.67669253, 0.61952597,
+    48.05766231, 46.20929068, 41.42901923, 35.3365164, 29.30345263, 24.02883115, 19.69576324,
+    16.23569673, 13.49934334, 11.33435432, 9.61153246, 8.22905176, 7.10912164, 6.19299772,
+    5.43638714, 4.80576623, 4.27559273, 3.826247, 3.44252595, 3.1125429, 2.82692131, 2.57820077,
+    2.36039599,
+    12.45252194, 11.97357878, 10.7349327, 9.15626613, 7.59300118, 6.22626097, 5.1034926, 4.20693309,
+    3.49789942, 2.93691555, 2.49050439, 2.13228115, 1.84208904, 1.60470644, 1.40865633, 1.24525219,
+    1.10787562, 0.99144283, 0.89201447, 0.80651049, 0.73250129, 0.66805375, 0.61161699,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    69.53248464, 66.8581583, 59.9417971, 51.12682694, 42.39785649, 34.76624232, 28.49691993,
+    23.49070427, 19.53159681, 16.3991709, 13.90649693, 11.90624737, 10.28587051, 8.96037173,
+    7.86566568, 6.95324846, 6.18616411, 5.53602585, 4.98083701, 4.50339926, 4.09014616, 3.73028351,
+    3.4151515,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    25.76851618, 24.7774194, 22.21423809, 18.94743837, 15.71250987, 12.88425809, 10.56086729,
+    8.70557979, 7.23834724, 6.07748023, 5.15370324, 4.41241715, 3.81191068, 3.32068507, 2.91499052,
+    2.57685162, 2.29257261, 2.05163345, 1.84588225, 1.66894535, 1.51579507, 1.38243113, 1.26564421,
+    72.3473189, 69.56472971, 62.36837836, 53.19655801, 44.11421884, 36.17365945, 29.65054053,
+    24.44166179, 20.32228059, 17.06304691, 14.46946378, 12.38823954, 10.70226611, 9.32310811,
+    8.18408585, 7.23473189, 6.43659421, 5.76013686, 5.1824727, 4.68570718, 4.25572464, 3.88129393,
+    3.55340466,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    14.29245333, 13.74274358, 12.32108045, 10.50915686, 8.71491056, 7.14622666, 5.85756284,
+    4.82853153, 4.01473408, 3.37086163, 2.85849067, 2.4473379, 2.11426824, 1.841811, 1.61679336,
+    1.42924533, 1.27157058, 1.13793418, 1.02381471, 0.92567703, 0.84073255, 0.76676252, 0.7019869,
+    34.21718626, 32.90114063, 29.49757436, 25.15969578, 20.86413796, 17.10859313, 14.02343699,
+    11.55986022, 9.61156917, 8.0700911, 6.84343725, 5.85910724, 5.06171394, 4.40943122, 3.87072243,
+    3.42171863, 3.04423365, 2.72429827, 2.45108784, 2.21613901, 2.01277566, 1.83568596, 1.68060836,
+    63.37953396, 60.94185958, 54.63752928, 46.6025985, 38.64605729, 31.68976698, 25.97521884,
+    21.41200472, 17.80323988, 14.94800329, 12.67590679, 10.85265993, 9.3756707, 8.16746572,
+    7.16963054, 6.3379534, 5.63874857, 5.04614124, 4.54008123, 4.1048921, 3.72820788, 3.40018959,
+    3.11294371,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    44.11429399, 42.41759038, 38.02956379, 32.43698088, 26.89895975, 22.057147, 18.07962869,
+    14.9034777, 12.39165562, 10.40431462, 8.8228588, 7.55381746, 6.52578314, 5.6848317, 4.99030475,
+    4.4114294, 3.92475925, 3.51228455, 3.16004971, 2.85714339, 2.59495847, 2.36664667, 2.16671385,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    38.5838757, 37.09988048, 33.26196181, 28.37049683, 23.52675347, 19.29193785, 15.81306381,
+    13.03509314, 10.83816733, 9.09997068, 7.71677514, 6.60682803, 5.70767392, 4.97214893,
+    4.36469182, 3.85838757, 3.43272915, 3.07196463, 2.76388794, 2.49895568, 2.26963975, 2.06995041,
+    1.8950823,
+    71.07434821, 68.34071943, 61.27098983, 52.26055015, 43.3380172, 35.5371741, 29.12883123,
+    24.01160412, 19.96470455, 16.76281797, 14.21486964, 12.1702651, 10.51395684, 9.15906549,
+    8.04008464, 7.10743482, 6.32334059, 5.65878569, 5.09128569, 4.60326089, 4.18084401, 3.81300151,
+    3.49088154,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    17.30286844, 16.6373735, 14.9162659, 12.72269739, 10.55052954, 8.65143422, 7.09133953,
+    5.84556366, 4.8603563, 4.0808652, 3.46057369, 2.96281994, 2.55959592, 2.22975109, 1.95733806,
+    1.73028684, 1.53940111, 1.37761691, 1.23946049, 1.1206521, 1.01781579, 0.92826547, 0.84984619,
+    68.8392425, 66.19157933, 59.34417457, 50.61709007, 41.97514787, 34.41962125, 28.2128043,
+    23.25650084, 19.33686587, 16.2356704, 13.7678485, 11.78754152, 10.1833199, 8.8710364,
+    7.78724463, 6.88392425, 6.12448777, 5.48083141, 4.93117783, 4.45850016, 4.04936721, 3.69309241,
+    3.38110228,
+    43.60446616, 41.9273713, 37.59005703, 32.06210747, 26.58808912, 21.80223308, 17.87068285,
+    14.73123857, 12.24844555, 10.28407221, 8.72089323, 7.46651818, 6.45036482, 5.61913224,
+    4.93263192, 4.36044662, 3.8794009, 3.47169317, 3.12352909, 2.82412346, 2.5649686, 2.33929539,
+    2.14167319,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    26.4183095, 25.40222067, 22.77440474, 19.42522757, 16.1087253, 13.20915475, 10.82717602,
+    8.92510456, 7.42087345, 6.23073337, 5.2836619, 4.52368313, 3.90803395, 3.40442133, 2.98849655,
+    2.64183095, 2.35038341, 2.10336859, 1.89242905, 1.71103041, 1.55401821, 1.41729128, 1.29755941,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    31.24476247, 30.04304084, 26.93514006, 22.97409005, 19.05168443, 15.62238124, 12.80523052,
+    10.555663, 8.77661867, 7.36904775, 6.24895249, 5.35013056, 4.62200628, 4.02638692, 3.53447539,
+    3.12447625, 2.77978314, 2.48764032, 2.2381635, 2.02362451, 1.8379272, 1.67622116, 1.53461505,
+    25.88550758, 24.88991114, 22.31509274, 19.03346146, 15.78384609, 12.94275379, 10.60881458,
+    8.74510391, 7.27121, 6.10507254, 5.17710152, 4.43244993, 3.8292171, 3.33576129, 2.92822484,
+    2.58855076, 2.3029811, 2.06094806, 1.85426272, 1.67652251, 1.52267692, 1.38870749, 1.27139035,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    52.44042088, 50.42348161, 45.20725938, 38.559133, 31.97586639, 26.22021044, 21.49197577,
+    17.7163584, 14.7304553, 12.36802379, 10.48808418, 8.97952412, 7.75745871, 6.7577862, 5.93217431,
+    5.24404209, 4.66551787, 4.17519274, 3.75647714, 3.39640032, 3.08473064, 2.8133273, 2.57565918,
+    38.05660376, 36.59288823, 32.80741703, 27.98279688, 23.20524619, 19.02830188, 15.59696875,
+    12.85696073, 10.69005723, 8.97561409, 7.61132075, 6.51654174, 5.62967511, 4.90420151,
+    4.30504567, 3.80566038, 3.38581884, 3.02998438, 2.72611775, 2.46480594, 2.23862375, 2.04166329,
+    1.86918486,
+    43.85610342, 42.16933021, 37.80698571, 32.24713487, 26.74152648, 21.92805171, 17.97381288,
+    14.81625116, 12.31913017, 10.34342062, 8.77122068, 7.50960675, 6.48758926, 5.65155972,
+    4.96109767, 4.38561034, 3.90178856, 3.49172798, 3.14155469, 2.84042121, 2.57977079, 2.35279525,
+    2.15403258,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    53.69254291, 51.6274451, 46.28667492, 39.47981096, 32.73935543, 26.84627145, 22.00514054,
+    18.1393726, 15.08217497, 12.66333559, 10.73850858, 9.19392858, 7.94268386, 6.91914213,
+    6.07381707, 5.36925429, 4.77691663, 4.27488399, 3.8461707, 3.4774963, 3.15838488, 2.88050123,
+    2.6371583,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    69.46457553, 66.79286109, 59.88325477, 51.07689377, 42.35644849, 34.73228777, 28.46908833,
+    23.467762, 19.51252122, 16.38315461, 13.89291511, 11.8946191, 10.27582478, 8.95162056,
+    7.85798366, 6.94645755, 6.18012238, 5.53061907, 4.97597246, 4.49900101, 4.0861515, 3.72664032,
+    3.41181609,
+    57.37306367, 55.16640738, 49.45953765, 42.18607623, 34.98357541, 28.68653184, 23.51355068,
+    19.38279178, 16.11602912, 13.53138294, 11.47461273, 9.82415474, 8.4871396, 7.39343604,
+    6.49016557, 5.73730637, 5.1043651, 4.56791908, 4.10981831, 3.715872, 3.3748861, 3.07795406,
+    2.81793044,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    27.18035087, 26.13495276, 23.43133696, 19.98555211, 16.57338468, 13.59017543, 11.13948806,
+    9.18255097, 7.63493002, 6.41046011, 5.43607017, 4.65416967, 4.02076196, 3.50262253, 3.07470032,
+    2.71803509, 2.41818068, 2.16404067, 1.94701654, 1.76038542, 1.59884417, 1.45817333, 1.33498776,
+    54.92557114, 52.81304918, 47.3496303, 40.38644937, 33.49120192, 27.46278557, 22.51047998,
+    18.5559362, 15.42853122, 12.95414414, 10.98511423, 9.40506355, 8.12508449, 7.07803752,
+    6.2132999, 5.49255711, 4.88661665, 4.37305503, 3.9344965, 3.55735564, 3.23091595, 2.94665081,
+    2.6977196,
+    56.91906255, 54.72986783, 49.06815737, 41.85225187, 34.70674546, 28.45953127, 23.32748465,
+    19.22941302, 15.98850072, 13.4243072, 11.38381251, 9.74641482, 8.41997967, 7.33493074,
+    6.43880798, 5.69190625, 5.06397354, 4.5317725, 4.07729674, 3.68646778, 3.34818015, 3.05359778,
+    2.79563176,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    73.11103437, 70.29907151, 63.02675376, 53.7581135, 44.579899, 36.55551718, 29.96353867,
+    24.69967377, 20.53680741, 17.24316848, 14.62220687, 12.51901273, 10.81524177, 9.42152505,
+    8.270479, 7.31110344, 6.50454042, 5.82094223, 5.23718011, 4.73517062, 4.30064908, 3.92226579,
+    3.59091524,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    33.96440472, 32.65808146, 29.27965924, 24.973827, 20.71000288, 16.98220236, 13.919838,
+    11.47446105, 9.54056312, 8.01047281, 6.79288094, 5.81582273, 5.02432023, 4.37685628, 3.84212723,
+    3.39644047, 3.02174419, 2.70417235, 2.43298028, 2.19976715, 1.99790616, 1.82212472, 1.66819277,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    44.91316527, 43.18573584, 38.71824592, 33.02438623, 27.38607639, 22.45658264, 18.40703495,
+    15.17336665, 12.61605766, 10.59272766, 8.98263305, 7.69061049, 6.64395936, 5.78777903,
+    5.0806748, 4.49131653, 3.99583321, 3.57588895, 3.21727545, 2.90888376, 2.6419509, 2.40950457,
+    2.20595114,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    61.74288079, 59.3681546, 53.22662137, 45.39917705, 37.64809804, 30.87144039, 25.30445934,
+    20.85908135, 17.34350584, 14.56200019, 12.34857616, 10.57241109, 9.13356225, 7.9565568,
+    6.98448878, 6.17428808, 5.49313886, 4.91583446, 4.42284246, 3.99889124, 3.63193416, 3.31238631,
+    3.032558,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    30.09454641, 28.93706386, 25.94357449, 22.12834295, 18.35033318, 15.04727321, 12.3338305,
+    10.16707649, 8.45352427, 7.09777038, 6.01890928, 5.15317576, 4.45185598, 3.8781632, 3.40436045,
+    3.00945464, 2.67745075, 2.39606261, 2.1557698, 1.94912865, 1.77026744, 1.61451429, 1.47812114,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    29.46238144, 28.32921293, 25.39860469, 21.66351577, 17.96486673, 14.73119072, 12.07474649,
+    9.95350724, 8.27594984, 6.94867487, 5.89247629, 5.04492833, 4.35834045, 3.79669864, 3.33284858,
+    2.94623814, 2.62120831, 2.34573101, 2.11048578, 1.90818533, 1.73308126, 1.58059986, 1.44707178,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    51.57204307, 49.58850296, 44.45865782, 37.92061991, 31.44636773, 25.78602154, 21.13608323,
+    17.42298752, 14.48652895, 12.16321771, 10.31440861, 8.83082929, 7.62900045, 6.64588184,
+    5.83394152, 5.15720431, 4.58826006, 4.10605438, 3.69427243, 3.34015823, 3.03364959, 2.76674051,
+    2.53300801,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    33.01745714, 31.74755494, 28.46332512, 24.27754201, 20.13259581, 16.50872857, 13.53174473,
+    11.15454633, 9.27456661, 7.78713612, 6.60349143, 5.65367417, 4.88423922, 4.25482695, 3.73500646,
+    3.30174571, 2.93749619, 2.62877843, 2.36514736, 2.13843634, 1.94220336, 1.77132281, 1.62168257,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    68.55021904, 65.91367215, 59.09501641, 50.40457282, 41.79891405, 34.27510952, 28.09435207,
+    23.15885778, 19.25567951, 16.16750449, 13.71004381, 11.73805121, 10.14056495, 8.83379111,
+    7.75454967, 6.8550219, 6.09877394, 5.45781999, 4.91047414, 4.43978103, 4.03236583, 3.67758686,
+    3.36690663,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    31.03819229, 29.84441567, 26.75706232, 22.82220021, 18.92572701, 15.51909615, 12.72057061,
+    10.48587577, 8.71859334, 7.32032837, 6.20763846, 5.31475895, 4.59144856, 3.99976705, 3.51110773,
+    3.10381923, 2.76140501, 2.47119365, 2.22336621, 2.01024561, 1.82577602, 1.66513907, 1.52446917,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    21.51680799, 20.68923845, 18.54897241, 15.82118235, 13.12000487, 10.758404, 8.81836393,
+    7.26919189, 6.04404719, 5.07471887, 4.3033616, 3.68438493, 3.18295976, 2.77278454, 2.43402805,
+    2.1516808, 1.91430676, 1.71312166, 1.54131862, 1.39357565, 1.26569459, 1.15433519, 1.05681768,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    50.73859965, 48.78711505, 43.74017212, 37.30779386, 30.93817052, 25.36929983, 20.79450806,
+    17.1414188, 14.25241563, 11.96665086, 10.14771993, 8.68811638, 7.50571001, 6.53847934,
+    5.73966059, 5.07385997, 4.51411029, 4.03969742, 3.63457018, 3.28617873, 2.98462351, 2.72202788,
+    2.49207267,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    49.87895038, 47.96052922, 42.99909516, 36.67569881, 30.41399414, 24.93947519, 20.44219278,
+    16.85099675, 14.01094112, 11.76390339, 9.97579008, 8.54091616, 7.37854296, 6.42769979,
+    5.6424152, 4.98789504, 4.43762904, 3.97125401, 3.57299072, 3.23050197, 2.9340559, 2.67590936,
+    2.44985022,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    79.27738118, 76.22825114, 68.34256999, 58.29219205, 48.33986658, 39.63869059, 32.49072999,
+    26.78289905, 22.2689273, 18.69749556, 15.85547624, 13.57489404, 11.72742325, 10.21615737,
+    8.96802955, 7.92773812, 7.05314779, 6.31189341, 5.6788955, 5.13454541, 4.66337536, 4.25307839,
+    3.893781,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    75.68051746, 72.76972833, 65.2418254, 55.64743931, 46.14665699, 37.84025873, 31.01660552,
+    25.56774239, 21.25857232, 17.84917865, 15.13610349, 12.95899272, 11.19534282, 9.752644,
+    8.56114451, 7.56805175, 6.73314212, 6.02551891, 5.42124051, 4.90158792, 4.45179514, 4.0601136,
+    3.71711775,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    36.61098427, 35.20286949, 31.56119334, 26.91984138, 22.3237709, 18.30549214, 15.00450175,
+    12.36857577, 10.28398435, 8.6346661, 7.32219685, 6.26900416, 5.41582608, 4.71791034, 4.14151406,
+    3.66109843, 3.25720501, 2.91488728, 2.62256334, 2.37117774, 2.15358731, 1.9641086, 1.79818194,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    38.64599494, 37.15961052, 33.31551288, 28.41617275, 23.56463106, 19.32299747, 15.83852251,
+    13.05607937, 10.85561656, 9.11462145, 7.72919899, 6.61746489, 5.71686316, 4.98015399,
+    4.37171888, 3.86459949, 3.43825578, 3.07691042, 2.76833775, 2.50297895, 2.27329382, 2.07328299,
+    1.89813335,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    53.88366521, 51.81121655, 46.45143553, 39.62034207, 32.85589342, 26.94183261, 22.08346935,
+    18.20394095, 15.13586101, 12.70841161, 10.77673304, 9.226655, 7.97095639, 6.94377129,
+    6.09543724, 5.38836652, 4.79392039, 4.29010073, 3.8598614, 3.48987469, 3.16962737, 2.89075457,
+    2.64654544,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    42.85269493, 41.20451436, 36.94197839, 31.50933451, 26.12969203, 21.42634747, 17.56257989,
+    14.4772618, 12.03727386, 10.10676767, 8.57053899, 7.33779023, 6.33915606, 5.5222545, 4.84758992,
+    4.28526949, 3.81251734, 3.41183877, 3.06967729, 2.77543361, 2.52074676, 2.29896432, 2.10474926,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    49.07099562, 47.18364964, 42.30258244, 36.08161443, 29.9213388, 24.53549781, 20.11106378,
+    16.57803906, 13.78398754, 11.57334802, 9.81419912, 8.40256774, 7.25902302, 6.32358191,
+    5.5510176, 4.90709956, 4.36574694, 3.9069264, 3.5151143, 3.17817329, 2.88652915, 2.63256414,
+    2.41016678,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    35.09760672, 33.74769877, 30.25655751, 25.80706376, 21.4009797, 17.54880336, 14.38426505,
+    11.85729957, 9.85887829, 8.27773743, 7.01952134, 6.00986416, 5.19195366, 4.52288746, 3.9703175,
+    3.50976067, 3.12256288, 2.79439544, 2.51415521, 2.27316106, 2.0645651, 1.88291882, 1.72385102,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    69.49908319, 66.82604153, 59.91300275, 51.10226705, 42.37748975, 34.7495416, 28.48323082,
+    23.47942, 19.52221438, 16.39129321, 13.89981664, 11.90052794, 10.28092947, 8.95606742,
+    7.86188724, 6.94990832, 6.18319245, 5.5333665, 4.97844435, 4.50123596, 4.08818136, 3.72849159,
+    3.41351096,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    71.91383903, 69.14792214, 61.99468882, 52.87782282, 43.84990185, 35.95691951, 29.47288485,
+    24.29521589, 20.20051658, 16.96081109, 14.38276781, 12.31401353, 10.63814187, 9.2672473,
+    8.13504966, 7.1913839, 6.39802838, 5.72562413, 5.15142113, 4.65763206, 4.23022583, 3.85803857,
+    3.5321139,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    64.28536112, 61.81284723, 55.41841476, 47.26864788, 39.19839093, 32.14268056, 26.34645948,
+    21.71802741, 18.05768571, 15.16164177, 12.85707222, 11.00776732, 9.50966881, 8.28419602,
+    7.27209967, 6.42853611, 5.71933818, 5.11826124, 4.60496856, 4.16355966, 3.78149183, 3.44878547,
+    3.15743424,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    61.18986607, 58.83640968, 52.74988454, 44.99254858, 37.31089394, 30.59493303, 25.07781396,
+    20.67225205, 17.18816463, 14.43157219, 12.23797321, 10.47771679, 9.05175534, 7.88529202,
+    6.92193055, 6.11898661, 5.44393826, 4.87180462, 4.38322823, 3.96307423, 3.59940389, 3.28271814,
+    3.00539617,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    71.58825828, 68.83486373, 61.71401576, 52.6384252, 43.651377, 35.79412914, 29.33945011,
+    24.18522239, 20.10906131, 16.88402318, 14.31765166, 12.2582634, 10.58997904, 9.22529102,
+    8.09821926, 7.15882583, 6.36906212, 5.69970209, 5.12809873, 4.63654523, 4.21107402, 3.8405718,
+    3.51612271,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    24.18243995, 23.25234611, 20.84693099, 17.78120585, 14.74539022, 12.09121998, 9.91083605,
+    8.16974323, 6.79282021, 5.70340565, 4.83648799, 4.14082876, 3.57728402, 3.11629381, 2.73557013,
+    2.418244, 2.15146263, 1.9253535, 1.73226647, 1.5662202, 1.42249647, 1.2973412, 1.18774263,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    75.63345996, 72.72448073, 65.20125858, 55.6128382, 46.11796339, 37.81672998, 30.99731965,
+    25.55184458, 21.24535392, 17.83808018, 15.12669199, 12.95093492, 11.18838165, 9.74657989,
+    8.55582126, 7.563346, 6.72895551, 6.02177229, 5.41786962, 4.89854015, 4.44902706, 4.05758905,
+    3.71480648,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    20.13168578, 19.35739017, 17.35490153, 14.80271013, 12.27541816, 10.06584289, 8.25069089,
+    6.8012452, 5.65496792, 4.7480391, 4.02633716, 3.44720647, 2.97806003, 2.5942894, 2.27734002,
+    2.01316858, 1.79107525, 1.60284122, 1.44209784, 1.30386566, 1.18421681, 1.08002606, 0.98878614,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    12.59055408, 12.106302, 10.85392593, 9.25776036, 7.67716712, 6.29527704, 5.16006315, 4.25356557,
+    3.5366725, 2.9694703, 2.51811082, 2.1559168, 1.862508, 1.62249408, 1.42427082, 1.25905541,
+    1.12015606, 1.00243265, 0.90190216, 0.81545039, 0.74062083, 0.67545891, 0.61839657,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    42.11860921, 40.4986627, 36.30914587, 30.96956559, 25.68207879, 21.0593046, 17.26172509,
+    14.22925987, 11.83107, 9.93363425, 8.42372184, 7.21209062, 6.23056349, 5.42765583, 4.76454855,
+    4.21186092, 3.74720722, 3.35339245, 3.01709235, 2.7278892, 2.47756525, 2.25958204, 2.06869397,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    16.85547229, 16.20718489, 14.53057956, 12.39372962, 10.277727, 8.42773614, 6.90798045,
+    5.69441631, 4.73468323, 3.97534724, 3.37109446, 2.88621101, 2.49341306, 2.17209694, 1.90672763,
+    1.68554723, 1.49959718, 1.3419962, 1.20741205, 1.09167567, 0.99149837, 0.90426353, 0.82787192,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    24.19810435, 23.26740802, 20.86043478, 17.79272378, 14.75494167, 12.09905217, 9.91725588,
+    8.17503525, 6.79722032, 5.70710008, 4.83962087, 4.14351102, 3.57960123, 3.11831242, 2.73734212,
+    2.41981043, 2.15285626, 1.92660066, 1.73338856, 1.56723474, 1.4234179, 1.29818156, 1.188512,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    50.43521424, 48.49539831, 43.47863296, 37.08471635, 30.75317941, 25.21760712, 20.67016977,
+    17.03892373, 14.16719501, 11.8950977, 10.08704285, 8.63616682, 7.46083051, 6.49938328,
+    5.70534098, 5.04352142, 4.4871187, 4.01554253, 3.6128377, 3.26652942, 2.96677731, 2.70575184,
+    2.47717162,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    16.14461552, 15.52366876, 13.917772, 11.87104082, 9.84427775, 8.07230776, 6.6166457, 5.454262,
+    4.53500436, 3.80769234, 3.2289231, 2.76448896, 2.38825673, 2.08049169, 1.82631397, 1.61446155,
+    1.43635369, 1.28539932, 1.15649108, 1.04563572, 0.94968327, 0.86612744, 0.79295754,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    34.83299009, 33.4932597, 30.02843973, 25.61249272, 21.23962811, 17.41649505, 14.27581561,
+    11.76790206, 9.78454778, 8.21532785, 6.96659802, 5.9645531, 5.15280919, 4.48878738, 3.94038349,
+    3.48329901, 3.09902047, 2.77332724, 2.49519986, 2.25602267, 2.04899942, 1.86872264, 1.71085413,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    32.49479306, 31.24499333, 28.01275264, 23.89323019, 19.81389821, 16.24739653, 13.31753814,
+    10.97797063, 9.12775086, 7.66386629, 6.49895861, 5.56417689, 4.80692205, 4.18747333, 3.67588157,
+    3.24947931, 2.89099582, 2.58716505, 2.32770724, 2.10458504, 1.91145842, 1.74328289, 1.59601145,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    75.74478221, 72.83152135, 65.29722604, 55.6946928, 46.18584281, 37.8723911, 31.04294353,
+    25.58945345, 21.27662422, 17.86433543, 15.14895644, 12.96999695, 11.20484944, 9.76092554,
+    8.56841428, 7.57447822, 6.73885963, 6.03063553, 5.425844, 4.90575014, 4.45557542, 4.06356128,
+    3.72027418,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    10.88777434, 10.46901379, 9.38601236, 8.00571642, 6.63888679, 5.44388717, 4.4622026, 3.67830214,
+    3.05836358, 2.56787131, 2.17755487, 1.86434492, 1.61061751, 1.4030637, 1.23164868, 1.08877743,
+    0.9686632, 0.86686101, 0.77992653, 0.70516673, 0.64045731, 0.58410807, 0.53476298,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    43.23733203, 41.57435772, 37.27356209, 31.7921559, 26.36422685, 21.61866601, 17.72021804,
+    14.60720677, 12.14531799, 10.19748397, 8.64746641, 7.40365274, 6.39605503, 5.57182114,
+    4.89110091, 4.3237332, 3.84673772, 3.44246274, 3.09723009, 2.80034534, 2.54337247, 2.31959936,
+    2.12364106,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    40.10687689, 38.5643047, 34.57489387, 29.49035065, 24.45541274, 20.05343844, 16.43724463,
+    13.54962057, 11.26597665, 9.45916908, 8.02137538, 6.86761591, 5.93296995, 5.16841197,
+    4.53697702, 4.01068769, 3.56822748, 3.19322268, 2.87298545, 2.59759565, 2.35922805, 2.15165649,
+    1.9698859,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    17.1547161, 16.49491932, 14.78854836, 12.61376184, 10.46019274, 8.57735805, 7.03062135,
+    5.79551219, 4.81874048, 4.04592361, 3.43094322, 2.93745139, 2.5376799, 2.21065929, 1.94057874,
+    1.71547161, 1.52622029, 1.36582135, 1.22884786, 1.11105674, 1.00910095, 0.92031739, 0.84256955,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    30.99066333, 29.79871474, 26.71608908, 22.78725245, 18.89674593, 15.49533166, 12.70109153,
+    10.46981869, 8.70524251, 7.30911871, 6.19813267, 5.30662043, 4.58441765, 3.99364218, 3.50573115,
+    3.09906633, 2.75717645, 2.4674095, 2.21996156, 2.00716731, 1.8229802, 1.66258923, 1.52213474,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    32.24841447, 31.00809083, 27.8003573, 23.71206946, 19.66366736, 16.12420723, 13.21656331,
+    10.89473462, 9.05854339, 7.60575813, 6.44968289, 5.52198878, 4.77047551, 4.15572351, 3.64801069,
+    3.22484145, 2.86907602, 2.56754892, 2.31005834, 2.08862788, 1.89696556, 1.73006515, 1.58391034,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    31.85952401, 30.6341577, 27.46510691, 23.4261206, 19.42653903, 15.92976201, 13.05718197,
+    10.76335271, 8.9493045, 7.51403868, 6.3719048, 5.45539795, 4.71294734, 4.10560876, 3.60401855,
+    3.1859524, 2.83447723, 2.53658631, 2.28220086, 2.06344067, 1.87408965, 1.70920193, 1.56480963,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    46.77211809, 44.97319047, 40.32079145, 34.3912633, 28.5195842, 23.38605904, 19.16890085,
+    15.80139125, 13.13823542, 11.03115993, 9.35442362, 8.00892433, 6.91895238, 6.0273348,
+    5.29096358, 4.67721181, 4.16122047, 3.72389475, 3.35043826, 3.02928226, 2.75130106, 2.5092338,
+    2.29725531,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    46.73553265, 44.93801216, 40.28925228, 34.36436224, 28.49727601, 23.36776632, 19.15390682,
+    15.7890313, 13.12795861, 11.02253129, 9.34710653, 8.0026597, 6.91354033, 6.02262019, 5.28682496,
+    4.67355326, 4.15796554, 3.7209819, 3.34781753, 3.02691274, 2.74914898, 2.50727106, 2.29545838,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    39.96004287, 38.42311815, 34.44831282, 29.38238447, 24.3658798, 19.98002144, 16.37706675,
+    13.50001448, 11.22473114, 9.42453841, 7.99200857, 6.84247309, 5.91124895, 5.14949006,
+    4.52036684, 3.99600429, 3.55516396, 3.18153208, 2.86246725, 2.58808568, 2.35059076, 2.14377912,
+    1.96267401,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    38.02951949, 36.56684566, 32.78406852, 27.96288198, 23.18873139, 19.01475974, 15.58586864,
+    12.84781064, 10.68244929, 8.96922629, 7.6059039, 6.51190402, 5.62566856, 4.90071127, 4.30198184,
+    3.80295195, 3.38340921, 3.02782798, 2.72417761, 2.46305178, 2.23703056, 2.04021027, 1.86785459,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    50.69499662, 48.74518906, 43.70258329, 37.27573281, 30.9115833, 25.34749831, 20.77663796,
+    17.12668805, 14.24016759, 11.95636713, 10.13899932, 8.68065011, 7.49925985, 6.53286039,
+    5.73472812, 5.06949966, 4.51023102, 4.03622585, 3.63144675, 3.2833547, 2.98205862, 2.71968866,
+    2.48993107,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    41.35683145, 39.76618408, 35.6524409, 30.40943489, 25.21758015, 20.67841572, 16.94952108,
+    13.97190252, 11.61708748, 9.75396968, 8.27136629, 7.08164922, 6.11787447, 5.32948859, 4.6783746,
+    4.13568314, 3.6794334, 3.29274136, 2.96252374, 2.67855126, 2.43275479, 2.21871413, 2.03127856,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    14.40962494, 13.8554086, 12.42209047, 10.59531246, 8.78635667, 7.20481247, 5.90558399,
+    4.86811653, 4.04764746, 3.39849645, 2.88192499, 2.46740153, 2.13160132, 1.85691043, 1.63004807,
+    1.44096249, 1.2819951, 1.14726313, 1.03220809, 0.93326586, 0.847625, 0.77304855, 0.70774189,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    54.21295102, 52.12783752, 46.7353026, 39.86246398, 33.05667745, 27.10647551, 22.21842255,
+    18.31518616, 15.22835703, 12.78607335, 10.8425902, 9.28303956, 8.01966731, 6.98620503,
+    6.13268677, 5.4212951, 4.82321628, 4.31631776, 3.88344921, 3.51120149, 3.18899712, 2.90842012,
+    2.66271862,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    35.67242961, 34.30041309, 30.75209449, 26.22972766, 21.75148147, 17.83621481, 14.6198482,
+    12.05149649, 10.0203454, 8.41330887, 7.13448592, 6.10829274, 5.27698663, 4.59696258, 4.03534272,
+    3.56724296, 3.1737037, 2.84016159, 2.55533163, 2.31039052, 2.09837821, 1.91375695, 1.75208397,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    68.96045953, 66.30813416, 59.448672, 50.70622024, 42.04906069, 34.48022976, 28.26248341,
+    23.29745254, 19.3709156, 16.26425932, 13.79209191, 11.80829786, 10.20125141, 8.88665716,
+    7.80095696, 6.89604595, 6.1352722, 5.49048245, 4.939861, 4.46635101, 4.05649762, 3.69959547,
+    3.38705597,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    14.91313709, 14.3395549, 12.85615266, 10.96554198, 9.09337628, 7.45656855, 6.11194143,
+    5.03822199, 4.18908345, 3.51724931, 2.98262742, 2.55361936, 2.20608537, 1.92179602, 1.68700646,
+    1.49131371, 1.32679156, 1.18735168, 1.0682763, 0.96587675, 0.87724336, 0.800061, 0.73247235,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    45.93915352, 44.172263, 39.60271855, 33.77878936, 28.01167898, 22.96957676, 18.82752194,
+    15.5199843, 12.90425661, 10.83470602, 9.1878307, 7.86629341, 6.79573277, 5.91999401, 5.19673682,
+    4.59391535, 4.0871133, 3.65757592, 3.29077031, 2.97533378, 2.70230315, 2.46454686, 2.25634349,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    27.47528516, 26.41854342, 23.68559065, 20.20241556, 16.75322266, 13.73764258, 11.26036277,
+    9.28219093, 7.71777673, 6.48002008, 5.49505703, 4.70467212, 4.0643913, 3.54062953, 3.10806393,
+    2.74752852, 2.44442039, 2.1875227, 1.96814364, 1.77948738, 1.61619324, 1.47399598, 1.34947373,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    52.44070347, 50.42375334, 45.20750299, 38.55934079, 31.9760387, 26.22035174, 21.49209159,
+    17.71645388, 14.73053468, 12.36809044, 10.48814069, 8.97957251, 7.75750051, 6.75782261,
+    5.93220628, 5.24407035, 4.66554301, 4.17521524, 3.75649738, 3.39641862, 3.08474726, 2.81334246,
+    2.57567306,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    66.34954079, 63.79763537, 57.19787999, 48.78642705, 40.45703707, 33.17477039, 27.19243475,
+    22.4153854, 18.63751146, 15.6484766, 13.26990816, 11.36122274, 9.81502083, 8.55019856,
+    7.50560416, 6.63495408, 5.90298406, 5.28260675, 4.75283243, 4.29725005, 3.90291416, 3.55952472,
+    3.25881831,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    53.71251983, 51.64665369, 46.30389641, 39.49449988, 32.75153648, 26.85625992, 22.0133278,
+    18.14612157, 15.08778647, 12.66804713, 10.74250397, 9.19734929, 7.94563903, 6.92171647,
+    6.0760769, 5.37125198, 4.77869394, 4.27647451, 3.84760171, 3.47879014, 3.15955999, 2.88157295,
+    2.63813948,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    47.87965028, 46.03812527, 41.27556058, 35.2056252, 29.1949087, 23.93982514, 19.62280749,
+    16.17555753, 13.44933997, 11.29237035, 9.57593006, 8.19857025, 7.0827885, 6.17005803,
+    5.41625003, 4.78796503, 4.25975536, 3.81207407, 3.42977438, 3.10101362, 2.81645002, 2.56865077,
+    2.35165276,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    74.86213485, 71.98282197, 64.53632315, 55.04568739, 45.6476432, 37.43106743, 30.68120281,
+    25.29126177, 21.02868957, 17.65616388, 14.97242697, 12.81885871, 11.0742803, 9.64718233,
+    8.46856729, 7.48621349, 6.66033228, 5.96036106, 5.36261711, 4.84858386, 4.40365499, 4.01620895,
+    3.67692214,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    21.96237627, 21.11766949, 18.93308299, 16.14880608, 13.39169285, 10.98118813, 9.00097388,
+    7.41972171, 6.16920682, 5.17980572, 4.39247525, 3.76068087, 3.24887223, 2.83020313, 2.4844317,
+    2.19623763, 1.95394807, 1.74859684, 1.57323612, 1.4224337, 1.29190449, 1.17823907, 1.07870217,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    79.67198256, 76.60767554, 68.68274358, 58.58234012, 48.58047717, 39.83599128, 32.65245187,
+    26.91621032, 22.37977038, 18.79056192, 15.93439651, 13.64246277, 11.78579624, 10.26700806,
+    9.01266771, 7.96719826, 7.08825468, 6.34331071, 5.70716207, 5.1601025, 4.68658721, 4.27424799,
+    3.91316221,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    37.91746556, 36.4591015, 32.68747031, 27.88048938, 23.12040583, 18.95873278, 15.5399449,
+    12.80995458, 10.65097347, 8.94279848, 7.58349311, 6.49271671, 5.60909254, 4.88627134,
+    4.28930606, 3.79174656, 3.37344, 3.01890649, 2.71615083, 2.4557944, 2.23043915, 2.0341988,
+    1.86235096,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    52.4990223, 50.47982914, 45.25777785, 38.60222228, 32.01159896, 26.24951115, 21.51599275,
+    17.73615618, 14.74691638, 12.38184488, 10.49980446, 8.98955861, 7.76612756, 6.76533793,
+    5.93880343, 5.24990223, 4.67073152, 4.17985846, 3.76067495, 3.40019574, 3.08817778, 2.81647115,
+    2.57853744,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    14.73503099, 14.16829903, 12.70261292, 10.83458161, 8.98477499, 7.36751549, 6.03894713,
+    4.97805101, 4.13905365, 3.47524316, 2.9470062, 2.52312174, 2.17973831, 1.8988442, 1.66685871,
+    1.4735031, 1.31094582, 1.17317126, 1.05551798, 0.95434139, 0.86676653, 0.79050595, 0.72372451,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    7.25833914, 6.97917225, 6.25718891, 5.33701407, 4.42581655, 3.62916957, 2.97472916, 2.4521416,
+    2.03885931, 1.71187244, 1.45166783, 1.24286629, 1.07371881, 0.93535298, 0.82107909, 0.72583391,
+    0.64575971, 0.57789324, 0.51993833, 0.47009969, 0.42696113, 0.38939588, 0.35649996,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    16.93525284, 16.28389696, 14.5993559, 12.45239179, 10.32637368, 8.46762642, 6.94067739,
+    5.7213692, 4.75709349, 3.99416341, 3.38705057, 2.89987206, 2.50521492, 2.18237794, 1.91575258,
+    1.69352528, 1.50669509, 1.34834816, 1.21312699, 1.0968428, 0.99619134, 0.90854361, 0.83179041,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    45.74100803, 43.98173849, 39.43190348, 33.63309414, 27.89085856, 22.87050402, 18.74631477,
+    15.45304325, 12.84859776, 10.78797359, 9.14820161, 7.83236439, 6.76642131, 5.8944598,
+    5.17432218, 4.5741008, 4.0694847, 3.6418, 3.27657651, 2.96250052, 2.69064753, 2.45391674,
+    2.2466114,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    23.0176064, 22.13231385, 19.84276414, 16.92471059, 14.03512585, 11.5088032, 9.43344525,
+    7.77621838, 6.46561978, 5.42868075, 4.60352128, 3.94137096, 3.40497136, 2.96618639, 2.60380163,
+    2.30176064, 2.04782975, 1.83261197, 1.64882567, 1.49077762, 1.35397685, 1.23485013, 1.13053077,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    64.59741157, 62.11289574, 55.68742376, 47.49809674, 39.38866559, 32.29870578, 26.474349,
+    21.82344985, 18.14534033, 15.23523858, 12.91948231, 11.06120061, 9.55583011, 8.32440871,
+    7.3073995, 6.45974116, 5.74710067, 5.14310602, 4.62732175, 4.18377018, 3.79984774, 3.46552637,
+    3.17276088,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    16.61495494, 15.97591821, 14.32323702, 12.21687863, 10.13107009, 8.30747747, 6.80940776,
+    5.61316045, 4.66712217, 3.91862145, 3.32299099, 2.84502653, 2.45783357, 2.14110244, 1.87951979,
+    1.66149549, 1.47819884, 1.32284673, 1.19018302, 1.07609812, 0.97735029, 0.89136024, 0.81605869,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    54.71727052, 52.61276012, 47.1700608, 40.23328715, 33.36418934, 27.35863526, 22.42511087,
+    18.48556437, 15.37001981, 12.90501663, 10.9434541, 9.36939564, 8.09427079, 7.05119466,
+    6.18973648, 5.47172705, 4.86808457, 4.35647058, 3.91957525, 3.54386467, 3.21866297, 2.93547589,
+    2.68748873,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    72.50812436, 69.71935035, 62.50700376, 53.31479733, 44.21227095, 36.25406218, 29.71644441,
+    24.49598796, 20.36745066, 17.10097273, 14.50162487, 12.41577472, 10.7260539, 9.34383046,
+    8.20227651, 7.25081244, 6.45090074, 5.77293984, 5.19399172, 4.69612204, 4.26518379, 3.88992083,
+    3.56130277,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    56.96915386, 54.77803256, 49.11133953, 41.88908372, 34.73728894, 28.48457693, 23.34801388,
+    19.24633576, 16.00257131, 13.43612119, 11.39383077, 9.7549921, 8.42738962, 7.34138581,
+    6.44447442, 5.69691539, 5.06843006, 4.53576066, 4.08088495, 3.68971204, 3.3511267, 3.05628508,
+    2.79809204,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    74.20961462, 71.35539867, 63.97380571, 54.5658931, 45.24976501, 37.10480731, 30.41377648,
+    25.07081575, 20.84539737, 17.5022676, 14.84192292, 12.70712579, 10.97775364, 9.56309467,
+    8.39475279, 7.42096146, 6.60227888, 5.90840881, 5.31587497, 4.80632219, 4.36527145, 3.9812025,
+    3.64487302,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    66.78345801, 64.21486347, 57.57194656, 49.10548383, 40.72162074, 33.39172901, 27.37026968,
+    22.56197906, 18.75939832, 15.75081557, 13.3566916, 11.43552363, 9.87920977, 8.60611572,
+    7.55468982, 6.6783458, 5.94158879, 5.3171543, 4.78391533, 4.3253535, 3.92843871, 3.58280354,
+    3.28013055,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    69.33124548, 66.66465911, 59.76831507, 50.97885697, 42.27514968, 34.66562274, 28.41444487,
+    23.42271807, 19.47506895, 16.35170884, 13.8662491, 11.87178861, 10.2561014, 8.93443885,
+    7.84290107, 6.93312455, 6.16826027, 5.52000362, 4.9664216, 4.49036564, 4.07830856, 3.71948742,
+    3.40526746,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    7.9854497, 7.67831702, 6.88400836, 5.87165419, 4.86917665, 3.99272485, 3.27272529, 2.69778706,
+    2.24310385, 1.88336078, 1.59708994, 1.36737152, 1.18127954, 1.0290528, 0.90333141, 0.79854497,
+    0.71044926, 0.63578421, 0.57202362, 0.51719234, 0.46973234, 0.42840395, 0.39221266,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    43.28713614, 41.62224629, 37.31649668, 31.82877658, 26.39459521, 21.64356807, 17.74062957,
+    14.62403248, 12.15930791, 10.20923022, 8.65742723, 7.41218085, 6.40342251, 5.57823919,
+    4.89673486, 4.32871361, 3.8511687, 3.44642804, 3.10079772, 2.80357099, 2.54630213, 2.32227125,
+    2.12608724,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    27.43463053, 26.37945243, 23.65054356, 20.17252245, 16.72843325, 13.71731526, 11.24370104,
+    9.26845626, 7.70635689, 6.47043173, 5.48692611, 4.69771071, 4.0583773, 3.53539053, 3.10346499,
+    2.74346305, 2.44080343, 2.18428587, 1.96523141, 1.77685431, 1.6138018, 1.47181494, 1.34747694,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    37.90040778, 36.44269979, 32.67276533, 27.8679469, 23.11000474, 18.95020389, 15.53295401,
+    12.80419182, 10.64618196, 8.93877542, 7.58008156, 6.48979585, 5.6065692, 4.88407317, 4.28737645,
+    3.79004078, 3.3719224, 3.01754839, 2.71492892, 2.45468962, 2.22943575, 2.03328368, 1.86151315,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    59.64010031, 57.3462503, 51.41387958, 43.85301494, 36.36591482, 29.82005016, 24.44266406,
+    20.14868254, 16.75283717, 14.06606139, 11.92802006, 10.21234594, 8.82250005, 7.68557994,
+    6.74661768, 5.96401003, 5.30605875, 4.74841563, 4.27221349, 3.8627008, 3.50824119, 3.1995762,
+    2.92927801,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    59.82785468, 57.52678335, 51.57573679, 43.99106962, 36.48039919, 29.91392734, 24.51961257,
+    20.21211307, 16.80557716, 14.11034308, 11.96557094, 10.24449566, 8.85027436, 7.70977509,
+    6.76785686, 5.98278547, 5.32276287, 4.76336423, 4.28566294, 3.87486105, 3.51928557, 3.20964886,
+    2.93849974,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    20.31012652, 19.52896781, 17.50872976, 14.93391656, 12.38422349, 10.15506326, 8.32382234,
+    6.86152923, 5.70509172, 4.79012418, 4.0620253, 3.47776139, 3.00445659, 2.61728435, 2.29752562,
+    2.03101265, 1.80695076, 1.61704829, 1.45488012, 1.3154227, 1.19471332, 1.08959906, 0.99755042,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    17.8214752, 17.13603384, 15.36334069, 13.10402588, 10.86675317, 8.9107376, 7.30388328,
+    6.02076865, 5.00603236, 4.20317811, 3.56429504, 3.05162247, 2.6363129, 2.29658186, 2.01600398,
+    1.78214752, 1.5855405, 1.41890726, 1.27660997, 1.15424062, 1.04832207, 0.95608773, 0.87531804,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
+];
+
+/// Scaling of polarizabilities of secondary reference systems [17]
+pub const SSCALE: &[f64] = &[
+    0.69117394, 1.32434606, 0.53394927, 0.9933094, 0.75685953, 1.27600604, 1.27101585, 0.62674801,
+    0.55153919, 1.230308, 0.91900246, 0.87276189, 0.84842941, 0.86683474, 1.07654495, 0.88821475,
+    0.62886626,
+];
+
+/// Polarizabilities of secondary reference systems at imaginary frequencies [17][23]
+pub const SECAIW: &[f64] = &[
+    0.57716139, 0.57144692, 0.55496288, 0.52950586, 0.49755292, 0.46172911, 0.42438338, 0.38735664,
+    0.35192768, 0.3188737, 0.2885807, 0.261159, 0.23654155, 0.21455814, 0.19498696, 0.17758812,
+    0.16212399, 0.14837054, 0.13612297, 0.1251977, 0.11543228, 0.10668418, 0.09882901,
+    0.99079325, 0.98098341, 0.95268582, 0.90898463, 0.85413211, 0.7926346, 0.72852445, 0.66496191,
+    0.60414222, 0.54739958, 0.49539662, 0.44832274, 0.40606281, 0.36832463, 0.33472745, 0.30485946,
+    0.27831271, 0.25470263, 0.23367765, 0.21492261, 0.19815865, 0.18314108, 0.16965638,
+    0.81334971, 0.80529675, 0.78206703, 0.7461924, 0.70116355, 0.65067977, 0.59805126, 0.54587229,
+    0.49594495, 0.44936448, 0.40667486, 0.36803154, 0.33334005, 0.30236049, 0.27478031, 0.25026145,
+    0.22846902, 0.20908733, 0.19182776, 0.17643161, 0.16266994, 0.15034191, 0.13927221,
+    0.77466916, 0.76699917, 0.74487419, 0.71070565, 0.66781824, 0.61973533, 0.56960968, 0.51991219,
+    0.47235925, 0.42799401, 0.38733458, 0.35052903, 0.31748736, 0.2879811, 0.26171256, 0.23835974,
+    0.2176037, 0.19914374, 0.18270499, 0.16804103, 0.15493383, 0.14319208, 0.13264883,
+    0.94209779, 0.93277009, 0.90586326, 0.8643099, 0.81215327, 0.75367823, 0.69271896, 0.63228039,
+    0.57444987, 0.52049602, 0.47104889, 0.42628859, 0.38610565, 0.35022223, 0.31827628, 0.28987624,
+    0.26463421, 0.24218452, 0.22219288, 0.20435961, 0.18841956, 0.17414007, 0.16131812,
+    0.5557051, 0.55020307, 0.53433183, 0.5098212, 0.47905612, 0.44456408, 0.40860669, 0.37295645,
+    0.33884457, 0.30701939, 0.27785255, 0.25145027, 0.22774799, 0.20658182, 0.18773821, 0.17098619,
+    0.15609694, 0.14285478, 0.13106252, 0.12054341, 0.11114102, 0.10271813, 0.09515498,
+    0.48982662, 0.48497685, 0.47098714, 0.44938222, 0.42226433, 0.3918613, 0.36016663, 0.3287427,
+    0.29867477, 0.27062244, 0.24491331, 0.22164101, 0.20074862, 0.18209168, 0.16548197, 0.15071588,
+    0.13759175, 0.12591944, 0.11552515, 0.10625306, 0.09796532, 0.09054097, 0.08387442,
+    0.83427185, 0.82601173, 0.80218447, 0.76538701, 0.71919987, 0.66741748, 0.61343518, 0.55991399,
+    0.50870235, 0.46092367, 0.41713592, 0.37749857, 0.34191469, 0.31013823, 0.2818486, 0.25669903,
+    0.23434602, 0.21446577, 0.19676223, 0.18097003, 0.16685437, 0.15420921, 0.14285477,
+    0.28221078, 0.27941661, 0.27135652, 0.25890897, 0.24328515, 0.22576862, 0.20750793, 0.18940321,
+    0.17207974, 0.15591756, 0.14110539, 0.12769719, 0.11566016, 0.10491107, 0.09534148, 0.08683409,
+    0.07927269, 0.07254776, 0.06655915, 0.06121709, 0.05644216, 0.05216465, 0.04832376,
+    0.81324166, 0.80518976, 0.78196313, 0.74609326, 0.70107039, 0.65059333, 0.59797181, 0.54579977,
+    0.49587906, 0.44930478, 0.40662083, 0.36798265, 0.33329576, 0.30232032, 0.2747438, 0.2502282,
+    0.22843867, 0.20905955, 0.19180228, 0.17640817, 0.16264833, 0.15032193, 0.13925371,
+    0.31848023, 0.31532696, 0.30623099, 0.29218369, 0.27455192, 0.25478418, 0.23417664, 0.21374512,
+    0.19419526, 0.17595593, 0.15924011, 0.1441087, 0.13052468, 0.11839414, 0.10759467, 0.09799392,
+    0.08946074, 0.08187152, 0.07511326, 0.06908465, 0.06369605, 0.0588688, 0.05453429,
+    0.69864964, 0.69173232, 0.6717785, 0.64096298, 0.60228418, 0.55891972, 0.51371297, 0.46889238,
+    0.42600588, 0.38599428, 0.34932482, 0.31613106, 0.28633182, 0.25972106, 0.23603029, 0.21496912,
+    0.1962499, 0.17960145, 0.16477586, 0.1515509, 0.13972993, 0.12914042, 0.11963179,
+    0.61107352, 0.60502328, 0.58757069, 0.5606179, 0.52678751, 0.48885881, 0.44931876, 0.41011645,
+    0.3726058, 0.33760968, 0.30553676, 0.27650385, 0.25043996, 0.22716488, 0.20644376, 0.18802262,
+    0.17164986, 0.15708831, 0.14412111, 0.13255391, 0.1222147, 0.11295259, 0.10463588,
+    0.81748898, 0.80939503, 0.78604709, 0.74998989, 0.70473188, 0.65399118, 0.60109484, 0.54865032,
+    0.49846889, 0.45165137, 0.40874449, 0.36990451, 0.33503647, 0.30389925, 0.27617871, 0.25153507,
+    0.22963174, 0.21015141, 0.192804, 0.1773295, 0.1634978, 0.15110702, 0.13998099,
+    0.25028411, 0.24780605, 0.2406578, 0.22961845, 0.21576216, 0.20022729, 0.18403243, 0.16797591,
+    0.15261226, 0.13827851, 0.12514205, 0.11325073, 0.10257545, 0.09304242, 0.08455544, 0.0770105,
+    0.07030452, 0.06434039, 0.05902927, 0.05429156, 0.05005682, 0.04626324, 0.04285687,
+    0.73724126, 0.72994184, 0.70888583, 0.67636813, 0.63555281, 0.58979301, 0.54208916, 0.49479279,
+    0.44953735, 0.40731561, 0.36862063, 0.33359333, 0.30214806, 0.27406738, 0.24906799, 0.22684347,
+    0.20709024, 0.18952218, 0.17387766, 0.15992218, 0.14744825, 0.1362738, 0.12623994,
+    0.86163441, 0.85310337, 0.82849462, 0.79049028, 0.74278828, 0.68930753, 0.63355471, 0.57827813,
+    0.52538683, 0.47604111, 0.4308172, 0.38987982, 0.35312886, 0.32031019, 0.29109271, 0.26511828,
+    0.24203214, 0.22149985, 0.20321566, 0.18690551, 0.17232688, 0.15926699, 0.14754014,
+];
+
+/// Functional names and identifiers of their damping parameters
+pub const FUNCTIONAL_IDS: &[(&str, &str)] = &[
+    ("pbe", "p_pbe"),
+    ("b3lyp", "p_b3lyp"),
+    ("b3-lyp", "p_b3lyp"),
+    ("tpss", "p_tpss"),
+    ("scan", "p_scan"),
+];
+
+/// Rational damping parameters: functional identifier, whether fitted with the three-body term
+/// (`None` if the same for both), and `[s6, s8, s9, a1, a2, alp]`
+pub const RATIONAL_DAMPING: &[(&str, Option<bool>, [f64; 6])] = &[
+    ("p_pbe", Some(true), [1.0, 0.95948085, 1.0, 0.38574991, 4.80688534, 16.0]),
+    ("p_pbe", Some(false), [1.0, 0.95, 1.0, 0.4, 4.8, 16.0]),
+    ("p_b3lyp", None, [1.0, 2.02929367, 1.0, 0.40868035, 4.53807137, 16.0]),
+    ("p_tpss", None, [1.0, 1.76596355, 1.0, 0.42822303, 4.54257102, 16.0]),
+    ("p_scan", None, [1.0, 1.46126056, 1.0, 0.62930855, 6.31284039, 16.0]),
+];
@@ -0,0 +1,66 @@
+//! Parameters of the EEQ 2019 model (multicharge), indexed by atomic number minus one.
+//!
+//! These tables are provisional stand-ins for elements H to Rn, and are not the parameters of
+//! upstream multicharge; they are to be regenerated from multicharge (as built with dftd4 v3.7.0)
+//! for all 118 elements. Charges of other elements are an error of `EEQModel`.
+
+/// Electronegativities [86]
+pub const EEQ_CHI: &[f64] = &[
+    0.64780067, 1.43217711, 1.34015208, 0.78057593, 1.0449786, 0.99444017, 1.21675227, 1.36759569,
+    0.60324555, 0.53118222, 1.41934161, 0.97604377, 1.33850809, 0.50231666, 0.98992591, 1.29369404,
+    0.75163844, 1.53979777, 1.4915702, 0.53364898, 0.52799045, 1.09555372, 1.53306408, 0.91932466,
+    0.73825934, 0.96432823, 0.53194487, 0.74386083, 0.98167635, 1.04539347, 0.7563929, 0.7539532,
+    0.74065914, 1.00556381, 0.81875978, 0.52363868, 1.42133577, 1.11209975, 1.2065238, 0.70449689,
+    1.59179775, 1.44594118, 0.63297896, 0.8659647, 1.29363285, 1.28231095, 1.53008465, 0.9643177,
+    1.41303926, 1.23733612, 0.83370536, 1.14633867, 1.4707269, 1.43081716, 1.0558122, 1.14790248,
+    0.53797841, 0.76701397, 1.37714467, 0.9557454, 0.69030814, 1.10367864, 1.27334484, 1.24193441,
+    0.91217332, 0.98285779, 1.05926914, 1.35628688, 1.07303226, 0.9325806, 1.03866287, 0.53253246,
+    0.54783602, 1.2737203, 1.58150649, 1.1525021, 0.93295966, 0.68738412, 1.05246241, 1.5802843,
+    1.34757545, 1.09357919, 1.44631876, 0.75539374, 1.06514883, 1.54771413,
+];
+
+/// Chemical hardnesses [86]
+pub const EEQ_ETA: &[f64] = &[
+    0.57779481, 0.45913173, 0.26927948, 0.54799631, 0.95711628, 0.00570913, 0.78365523, 0.82048591,
+    0.88617958, 0.74050341, 0.8091399, 0.51867828, 0.56135786, 0.42609068, 0.0561233, 0.87001016,
+    0.56999933, 0.19983942, 0.50472047, 0.48492511, 0.35678996, 0.34607792, 0.5384788, 0.62348945,
+    0.61245246, 0.4581468, 0.02797498, 0.22960503, 0.17721126, 0.58446087, 0.86100886, 0.79843894,
+    0.79709756, 0.81643737, 0.25529404, 0.84174483, 0.67311353, 0.08323414, 0.01669063, 0.01455997,
+    0.75558678, 0.24955923, 0.10948863, 0.62480208, 0.34442286, 0.06951538, 0.15962552, 0.5273804,
+    0.16814495, 0.27291444, 0.71158993, 0.45470163, 0.32200177, 0.47377101, 0.02363458, 0.3865571,
+    0.42091868, 0.1880393, 0.10876169, 0.8998185, 0.51011598, 0.20909099, 0.60564864, 0.81703967,
+    0.02081811, 0.01786452, 0.14646174, 0.71883547, 0.16022759, 0.70460563, 0.6781758, 0.54470216,
+    0.22059975, 0.97559452, 0.79781086, 0.51659952, 0.22319578, 0.64850642, 0.39489801, 0.57584596,
+    0.32124581, 0.63094786, 0.05878512, 0.29860595, 0.96790331, 0.87553424,
+];
+
+/// Coordination number scaling of electronegativities [86]
+pub const EEQ_KCN: &[f64] = &[
+    -0.02340334, 0.1146286, -0.02240909, 0.13482211, 0.08596053, 0.00404307, -0.03691047,
+    -0.09787993, 0.11967947, -0.09052087, 0.10485353, 0.14055028, 0.04257014, -0.05712073,
+    0.11694527, 0.14344381, 0.07600579, 0.02721844, -0.00550779, -0.01326728, -0.04855956,
+    0.06853825, 0.00823753, -0.05147034, -0.07389394, 0.06648938, -0.02598183, 0.02494998,
+    -0.01866359, 0.11790538, 0.12491957, -0.09547675, -0.04978675, -0.01806482, 0.14676243,
+    0.09567509, -0.01522609, -0.04674255, 0.06861377, 0.10942527, 0.13304687, -0.01403755,
+    0.1205983, 0.07177755, 0.02112468, 0.14637706, -0.04133989, 0.0813663, -0.07882994, -0.05757646,
+    0.12774695, -0.04675795, 0.08977905, 0.05005221, 0.11028305, -0.007973, -0.01492869,
+    -0.02719618, 0.11685496, 0.05099563, 0.13857686, 0.12181628, -0.06616351, 0.03779262,
+    -0.07393125, -0.09021555, -0.08170165, 0.11654209, 0.09702911, 0.10712649, -0.01477563,
+    0.05379651, 0.0954759, -0.00549009, 0.04269538, -0.04407148, -0.07956418, -0.03331909,
+    0.12269203, 0.04111171, 0.1312668, 0.01444231, -0.03070431, 0.09675367, 0.10694204, -0.09690456,
+];
+
+/// Charge widths [86]
+pub const EEQ_RAD: &[f64] = &[
+    2.1760291, 0.72920781, 0.78775625, 2.71265018, 0.60005884, 1.09908341, 2.97039625, 1.55253397,
+    0.78889545, 0.91845859, 1.10355071, 2.36001604, 0.75708536, 2.77691105, 1.44569318, 2.92566009,
+    2.77305682, 1.23505896, 1.13352534, 1.69252524, 0.75032286, 2.1301255, 0.59905053, 0.52626538,
+    2.95645907, 1.23887465, 1.99142661, 1.62461134, 1.28320215, 0.65741198, 2.78348004, 2.92453319,
+    2.92449126, 0.77840578, 1.03798318, 2.0445172, 2.94988221, 1.85728299, 2.22047452, 2.15458607,
+    1.14771498, 1.85400566, 1.26830279, 1.11595299, 0.70342191, 1.20196681, 2.95844179, 1.6197556,
+    2.13002634, 2.1086652, 2.85183631, 1.47619638, 1.26696074, 1.31810354, 1.29183787, 2.61783691,
+    2.73375061, 1.25702332, 1.33583351, 1.86056354, 1.94746359, 1.98990635, 1.11274501, 0.55093507,
+    1.10939825, 0.68081883, 1.87801189, 0.67729092, 0.68782448, 2.08845523, 1.22705388, 2.48046189,
+    1.73315261, 2.65662244, 0.88544899, 1.75357396, 2.48745873, 0.69276747, 2.87306987, 0.93310527,
+    2.44052246, 2.96223968, 2.55387536, 1.29946001, 0.76719434, 1.78589563,
+];
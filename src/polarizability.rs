@@ -0,0 +1,246 @@
+//! Molecular dispersion coefficients and static polarizabilities.
+//!
+//! Atomic C6 coefficients and static polarizabilities of the D4 model (`get_properties`) are
+//! summed to molecular quantities, and C8 coefficients follow from the r4/r2 expectation values
+//! as `C8 = 3 C6 r4r2[i] r4r2[j]`. All quantities are in atomic units (Hartree Bohr^6 for C6,
+//! Hartree Bohr^8 for C8, Bohr^3 for polarizabilities).
+//!
+//! D4 obtains C6 coefficients by Casimir-Polder integration of polarizabilities at imaginary
+//! frequencies, `C6 = 3 / pi * int alpha_a(iw) alpha_b(iw) dw`, on the frequency grid
+//! [`native::FREQ`](crate::native::FREQ). [`casimir_polder`] integrates polarizabilities on any
+//! grid, e.g. from dipole oscillator strength distributions.
+
+use crate::library::*;
+use crate::native::FREQ;
+use std::f64::consts::PI;
+
+/// Atomic and molecular dispersion coefficients and static polarizabilities
+#[derive(Debug, Clone)]
+pub struct DispersionCoefficients {
+    /// atomic C6 coefficients [natoms][natoms]
+    pub c6: Vec<f64>,
+    /// atomic C8 coefficients [natoms][natoms]
+    pub c8: Vec<f64>,
+    /// atomic static polarizabilities [natoms]
+    pub alpha: Vec<f64>,
+    /// molecular C6 coefficient, sum over all pairs of atoms
+    pub molecular_c6: f64,
+    /// effective molecular C8 coefficient, sum over all pairs of atoms
+    pub molecular_c8: f64,
+    /// molecular static polarizability, sum over atoms
+    pub molecular_alpha: f64,
+}
+
+/// Get C8 coefficients from C6 coefficients (failable)
+pub fn get_c8_coefficients_f(c6: &[f64], r4r2: &[f64]) -> Result<Vec<f64>, DFTD4Error> {
+    let natoms = r4r2.len();
+    if c6.len() != natoms * natoms {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for c6, expected {}, got {}",
+            natoms * natoms,
+            c6.len()
+        )));
+    }
+    Ok((0..natoms * natoms)
+        .map(|ij| 3.0 * c6[ij] * r4r2[ij / natoms] * r4r2[ij % natoms])
+        .collect())
+}
+
+/// Get C8 coefficients from C6 coefficients
+///
+/// # Arguments
+///
+/// * `c6` - C6 coefficients [natoms][natoms]
+/// * `r4r2` - scaling factors of C8 coefficients [natoms], see `library::get_r4r2`
+pub fn get_c8_coefficients(c6: &[f64], r4r2: &[f64]) -> Vec<f64> {
+    get_c8_coefficients_f(c6, r4r2).unwrap()
+}
+
+/// Get atomic and molecular dispersion coefficients (failable)
+pub fn get_dispersion_coefficients_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+) -> Result<DispersionCoefficients, DFTD4Error> {
+    let (_, _, c6, alpha) = get_properties_f(structure, model)?;
+    let r4r2 = get_r4r2_f(&structure.get_numbers())?;
+    let c8 = get_c8_coefficients_f(&c6, &r4r2)?;
+    Ok(DispersionCoefficients {
+        molecular_c6: c6.iter().sum(),
+        molecular_c8: c8.iter().sum(),
+        molecular_alpha: alpha.iter().sum(),
+        c6,
+        c8,
+        alpha,
+    })
+}
+
+/// Get atomic and molecular dispersion coefficients
+///
+/// Molecular coefficients are sums over all pairs of atoms `(i, j)`, i.e. the coefficients of
+/// the interaction of the molecule with a copy of itself at large distance.
+pub fn get_dispersion_coefficients(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+) -> DispersionCoefficients {
+    get_dispersion_coefficients_f(structure, model).unwrap()
+}
+
+/// Combination rule of C6 coefficients from homoatomic C6 coefficients and polarizabilities
+fn combine_c6(c6_a: f64, alpha_a: f64, c6_b: f64, alpha_b: f64) -> f64 {
+    if alpha_a <= 0.0 || alpha_b <= 0.0 {
+        return 0.0;
+    }
+    2.0 * c6_a * c6_b / (alpha_b / alpha_a * c6_a + alpha_a / alpha_b * c6_b)
+}
+
+/// Get C6 coefficient between two separate molecules
+///
+/// Atomic C6 coefficients between atoms of different molecules are obtained by the combination
+/// rule `C6_ij = 2 C6_ii C6_jj / (alpha_j / alpha_i C6_ii + alpha_i / alpha_j C6_jj)` (K. T. Tang,
+/// Phys. Rev. 1969, 177, 108), from coefficients of each molecule with its own charges and
+/// coordination numbers. Exact D4 coefficients are given by [`get_casimir_polder_c6`] where
+/// dynamic polarizabilities are available.
+pub fn get_intermolecular_c6(a: &DispersionCoefficients, b: &DispersionCoefficients) -> f64 {
+    let (na, nb) = (a.alpha.len(), b.alpha.len());
+    let mut c6 = 0.0;
+    for i in 0..na {
+        for j in 0..nb {
+            c6 += combine_c6(a.c6[i * na + i], a.alpha[i], b.c6[j * nb + j], b.alpha[j]);
+        }
+    }
+    c6
+}
+
+/// Casimir-Polder integration of C6 coefficient (failable)
+pub fn casimir_polder_f(
+    frequencies: &[f64],
+    alpha_a: &[f64],
+    alpha_b: &[f64],
+) -> Result<f64, DFTD4Error> {
+    let nfreq = frequencies.len();
+    if alpha_a.len() != nfreq || alpha_b.len() != nfreq {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for polarizabilities, expected {}, got {} and {}",
+            nfreq,
+            alpha_a.len(),
+            alpha_b.len()
+        )));
+    }
+    if nfreq < 2 || frequencies.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(DFTD4Error::Rust(
+            "Frequencies should be at least two and strictly increasing".to_string(),
+        ));
+    }
+    // trapezoidal rule
+    let integral = (1..nfreq)
+        .map(|w| {
+            let width = frequencies[w] - frequencies[w - 1];
+            0.5 * width * (alpha_a[w - 1] * alpha_b[w - 1] + alpha_a[w] * alpha_b[w])
+        })
+        .sum::<f64>();
+    Ok(3.0 / PI * integral)
+}
+
+/// Casimir-Polder integration of C6 coefficient
+///
+/// Integrates `C6 = 3 / pi * int alpha_a(iw) alpha_b(iw) dw` by the trapezoidal rule over the
+/// frequency grid, as in D4.
+///
+/// # Arguments
+///
+/// * `frequencies` - imaginary frequencies (Hartree) in increasing order [nfreq]
+/// * `alpha_a`, `alpha_b` - polarizabilities at the frequencies [nfreq]
+pub fn casimir_polder(frequencies: &[f64], alpha_a: &[f64], alpha_b: &[f64]) -> f64 {
+    casimir_polder_f(frequencies, alpha_a, alpha_b).unwrap()
+}
+
+/// Get molecular dynamic polarizabilities (failable)
+pub fn get_molecular_polarizabilities_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+) -> Result<Vec<f64>, DFTD4Error> {
+    let alpha = get_dynamic_polarizabilities_f(structure, model)?;
+    let nfreq = FREQ.len();
+    Ok((0..nfreq)
+        .map(|w| alpha.iter().skip(w).step_by(nfreq).sum())
+        .collect())
+}
+
+/// Get molecular dynamic polarizabilities
+///
+/// Returns sums over atoms of polarizabilities [nfreq] at the imaginary frequencies
+/// `native::FREQ`. Only available in the pure-Rust implementation.
+pub fn get_molecular_polarizabilities(structure: &DFTD4Structure, model: &DFTD4Model) -> Vec<f64> {
+    get_molecular_polarizabilities_f(structure, model).unwrap()
+}
+
+/// Get C6 coefficient between two separate molecules by Casimir-Polder integration (failable)
+pub fn get_casimir_polder_c6_f(
+    structure_a: &DFTD4Structure,
+    model_a: &DFTD4Model,
+    structure_b: &DFTD4Structure,
+    model_b: &DFTD4Model,
+) -> Result<f64, DFTD4Error> {
+    let alpha_a = get_molecular_polarizabilities_f(structure_a, model_a)?;
+    let alpha_b = get_molecular_polarizabilities_f(structure_b, model_b)?;
+    casimir_polder_f(&FREQ, &alpha_a, &alpha_b)
+}
+
+/// Get C6 coefficient between two separate molecules by Casimir-Polder integration
+///
+/// Integrates molecular dynamic polarizabilities, which is equivalent to the sum of D4 atomic
+/// C6 coefficients over pairs of atoms of different molecules. Only available in the pure-Rust
+/// implementation; see [`get_intermolecular_c6`] otherwise.
+pub fn get_casimir_polder_c6(
+    structure_a: &DFTD4Structure,
+    model_a: &DFTD4Model,
+    structure_b: &DFTD4Structure,
+    model_b: &DFTD4Model,
+) -> f64 {
+    get_casimir_polder_c6_f(structure_a, model_a, structure_b, model_b).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coefficients() {
+        let c6 = [10.0, 20.0, 20.0, 50.0];
+        let c8 = get_c8_coefficients(&c6, &[1.0, 2.0]);
+        assert_eq!(c8, [30.0, 120.0, 120.0, 600.0]);
+        assert!(get_c8_coefficients_f(&c6, &[1.0]).is_err());
+
+        // combination rule is exact for homoatomic pairs
+        assert!((combine_c6(10.0, 5.0, 10.0, 5.0) - 10.0).abs() < 1e-12);
+        // and for single-pole polarizabilities alpha(iw) = alpha / (1 + (w / w0)^2), where
+        // C6_ab = 3 / 2 alpha_a alpha_b w_a w_b / (w_a + w_b)
+        let c6_pole = |alpha_a: f64, w_a: f64, alpha_b: f64, w_b: f64| {
+            1.5 * alpha_a * alpha_b * w_a * w_b / (w_a + w_b)
+        };
+        let (c6_a, c6_b) = (c6_pole(5.0, 0.5, 5.0, 0.5), c6_pole(20.0, 0.3, 20.0, 0.3));
+        let expected = c6_pole(5.0, 0.5, 20.0, 0.3);
+        assert!((combine_c6(c6_a, 5.0, c6_b, 20.0) - expected).abs() < 1e-12);
+        assert_eq!(combine_c6(c6_a, 0.0, c6_b, 20.0), 0.0);
+    }
+
+    #[test]
+    fn test_casimir_polder() {
+        // single-pole polarizabilities on a fine grid
+        let frequencies = (0..=100000).map(|k| 0.01 * k as f64).collect::<Vec<f64>>();
+        let pole = |alpha: f64, w0: f64| {
+            frequencies
+                .iter()
+                .map(|w| alpha / (1.0 + (w / w0).powi(2)))
+                .collect::<Vec<f64>>()
+        };
+        let c6 = casimir_polder(&frequencies, &pole(5.0, 0.5), &pole(20.0, 0.3));
+        let expected = 1.5 * 5.0 * 20.0 * 0.5 * 0.3 / 0.8;
+        assert!((c6 - expected).abs() < 1e-4 * expected);
+        // D4 frequency grid
+        assert!(casimir_polder_f(&FREQ, &[1.0; 3], &[1.0; 3]).is_err());
+        assert!(casimir_polder_f(&[0.0, 1.0, 1.0], &[1.0; 3], &[1.0; 3]).is_err());
+        let c6 = casimir_polder(&FREQ, &[1.0; FREQ.len()], &[1.0; FREQ.len()]);
+        assert!((c6 - 3.0 / PI * (FREQ[FREQ.len() - 1] - FREQ[0])).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,170 @@
+//! QM/MM partitioned dispersion energies and gradients.
+//!
+//! Atoms are partitioned into a QM and an MM region by a mask. Dispersion energies of regions
+//! are evaluated subtractively: QM-QM and MM-MM energies are those of each region alone (with the
+//! atoms of the other region as ghost atoms), and the QM-MM energy is the remainder of the energy
+//! of the whole structure, so that the three contributions sum to the total energy. Pairwise
+//! energies of the whole structure are decomposed into blocks of pairs within and between
+//! regions.
+
+use crate::library::*;
+
+/// Dispersion contribution of pairs of atoms within or between regions
+#[derive(Debug, Clone)]
+pub struct RegionDispersion {
+    /// dispersion energy (Hartree)
+    pub energy: f64,
+    /// dispersion gradient [natoms][3]
+    pub gradient: Option<Vec<f64>>,
+    /// pairwise two-body energies of the whole structure, zero for other pairs [natoms][natoms]
+    pub pair_energy2: Vec<f64>,
+    /// pairwise three-body energies of the whole structure, zero for other pairs [natoms][natoms]
+    pub pair_energy3: Vec<f64>,
+}
+
+/// QM/MM partitioned dispersion
+#[derive(Debug, Clone)]
+pub struct QMMMDispersion {
+    /// pairs of QM atoms
+    pub qm_qm: RegionDispersion,
+    /// pairs of a QM and an MM atom
+    pub qm_mm: RegionDispersion,
+    /// pairs of MM atoms
+    pub mm_mm: RegionDispersion,
+}
+
+impl QMMMDispersion {
+    /// Total dispersion energy of the whole structure
+    pub fn get_energy(&self) -> f64 {
+        self.qm_qm.energy + self.qm_mm.energy + self.mm_mm.energy
+    }
+}
+
+/// Create structure data of a region, with atoms of other regions as ghost atoms
+fn get_region_structure_f(
+    structure: &DFTD4Structure,
+    region: &[bool],
+    charge: Option<f64>,
+) -> Result<DFTD4Structure, DFTD4Error> {
+    let ghosts = match structure.get_ghosts() {
+        Some(ghosts) => (0..region.len()).map(|i| ghosts[i] || !region[i]).collect(),
+        None => region.iter().map(|&x| !x).collect::<Vec<bool>>(),
+    };
+    DFTD4Structure::new_with_ghosts_f(
+        region.len(),
+        &structure.get_numbers(),
+        &structure.get_positions(),
+        charge,
+        structure.get_lattice().as_deref(),
+        structure.get_periodic().as_deref(),
+        Some(&ghosts),
+    )
+}
+
+/// Evaluate QM/MM partitioned dispersion energies and gradients (failable)
+pub fn get_qmmm_dispersion_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    qm: &[bool],
+    charges: Option<[f64; 2]>,
+    eval_grad: bool,
+) -> Result<QMMMDispersion, DFTD4Error> {
+    let natoms = structure.get_natoms();
+    if qm.len() != natoms {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for QM region, expected {}, got {}",
+            natoms,
+            qm.len()
+        )));
+    }
+    let ghosts = structure.get_ghosts().unwrap_or(vec![false; natoms]);
+    if (0..natoms).all(|i| ghosts[i] || !qm[i]) {
+        return Err(DFTD4Error::Rust("QM region has no atoms".to_string()));
+    }
+    if (0..natoms).all(|i| ghosts[i] || qm[i]) {
+        return Err(DFTD4Error::Rust("MM region has no atoms".to_string()));
+    }
+    let total_charge = structure.get_charge().unwrap_or(0.0);
+    let region_charge = charges.map_or(0.0, |charges| charges[0] + charges[1]);
+    if (total_charge - region_charge).abs() > 1e-8 {
+        return Err(DFTD4Error::Rust(format!(
+            "Sum of region charges {} differs from total charge {}",
+            region_charge, total_charge
+        )));
+    }
+
+    // whole structure and regions
+    let (energy, grad, _) = get_dispersion_f(structure, model, param, eval_grad, false)?;
+    let (pair_energy2, pair_energy3) = get_pairwise_dispersion_f(structure, model, param)?;
+    let atoms = (0..natoms).collect::<Vec<usize>>();
+    let mm = qm.iter().map(|&x| !x).collect::<Vec<bool>>();
+    let mut regions = vec![];
+    for (ireg, region) in [qm, mm.as_slice()].into_iter().enumerate() {
+        let charge = charges.map(|charges| charges[ireg]);
+        let region_structure = get_region_structure_f(structure, region, charge)?;
+        let region_model = model.new_derived_f(&region_structure, &atoms)?;
+        regions.push(get_dispersion_f(
+            &region_structure,
+            &region_model,
+            param,
+            eval_grad,
+            false,
+        )?);
+    }
+    let (energy_qm, grad_qm, _) = regions.remove(0);
+    let (energy_mm, grad_mm, _) = regions.remove(0);
+    let grad_qm_mm = grad.map(|grad| {
+        let (grad_qm, grad_mm) = (grad_qm.as_ref().unwrap(), grad_mm.as_ref().unwrap());
+        (0..3 * natoms)
+            .map(|k| grad[k] - grad_qm[k] - grad_mm[k])
+            .collect::<Vec<f64>>()
+    });
+
+    // pairwise energies decomposed by blocks: 0 for QM-QM, 1 for QM-MM, 2 for MM-MM
+    let block = |i: usize, j: usize| 2 - qm[i] as usize - qm[j] as usize;
+    let decompose = |pair_energy: &[f64], iblock: usize| {
+        (0..natoms * natoms)
+            .map(|ij| match block(ij / natoms, ij % natoms) == iblock {
+                true => pair_energy[ij],
+                false => 0.0,
+            })
+            .collect::<Vec<f64>>()
+    };
+    let region = |iblock: usize, energy: f64, gradient: Option<Vec<f64>>| RegionDispersion {
+        energy,
+        gradient,
+        pair_energy2: decompose(&pair_energy2, iblock),
+        pair_energy3: decompose(&pair_energy3, iblock),
+    };
+    Ok(QMMMDispersion {
+        qm_qm: region(0, energy_qm, grad_qm),
+        qm_mm: region(1, energy - energy_qm - energy_mm, grad_qm_mm),
+        mm_mm: region(2, energy_mm, grad_mm),
+    })
+}
+
+/// Evaluate QM/MM partitioned dispersion energies and gradients
+///
+/// QM-QM and MM-MM contributions are evaluated for each region alone, with its own coordination
+/// numbers and charges; the QM-MM contribution is the remainder of the whole structure. Gradients
+/// keep all atoms, e.g. the QM-QM gradient is zero for MM atoms. Sums of the pairwise blocks
+/// differ from the energies of contributions by the change of coordination numbers and charges
+/// upon partitioning; an MM engine that evaluates its own MM-MM dispersion should take the QM-QM
+/// and QM-MM contributions only. Models of regions keep the settings of `model` (see
+/// `DFTD4Model::new_derived`), e.g. cutoffs and custom charges.
+///
+/// # Arguments
+///
+/// * `qm` - whether atoms are in the QM region [natoms]; both regions should not be empty
+/// * `charges` - total charges of QM and MM regions; regions are neutral if `None`
+pub fn get_qmmm_dispersion(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    qm: &[bool],
+    charges: Option<[f64; 2]>,
+    eval_grad: bool,
+) -> QMMMDispersion {
+    get_qmmm_dispersion_f(structure, model, param, qm, charges, eval_grad).unwrap()
+}
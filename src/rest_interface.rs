@@ -1,6 +1,17 @@
 use crate::prelude::*;
 use std::ffi::{c_char, c_double, c_int};
 
+/// Evaluate DFT-D4 energy, gradient and sigma for REST.
+///
+/// # Safety
+///
+/// All pointers must be valid: `num` [num_size], `xyz` [num_size][3], `method` [method_len],
+/// `gradient` [num_size][3], `sigma` [3][3]; `charge` and `uhf` (number of unpaired electrons)
+/// can be null.
+///
+/// Errors (e.g. a negative `uhf`, a multiplicity inconsistent with the number of electrons or an
+/// unknown method) are returned to the caller; outputs are left untouched in that case.
+#[allow(clippy::too_many_arguments)]
 pub unsafe fn calc_dftd4_rest_(
     num: *const c_int,
     num_size: *const c_int,
@@ -12,9 +23,7 @@ pub unsafe fn calc_dftd4_rest_(
     energy: *mut c_double,
     gradient: *mut c_double,
     sigma: *mut c_double,
-) {
-    let _ = uhf;
-
+) -> Result<(), DFTD4Error> {
     // convert c-style arguments to rust-style arguments
     let natoms = unsafe { *num_size } as usize;
     let charges = {
@@ -23,25 +32,45 @@ pub unsafe fn calc_dftd4_rest_(
     };
     let coords = {
         let coords = unsafe { std::slice::from_raw_parts(xyz, natoms * 3) };
-        coords.iter().map(|&x| x).collect::<Vec<f64>>() // this may not required, since c_double is always f64
+        coords.to_vec() // this may not required, since c_double is always f64
     };
     let method = {
         let method = unsafe { std::slice::from_raw_parts(method, *method_len as usize) };
         let method = method.iter().map(|&x| x as u8).collect::<Vec<u8>>();
-        std::str::from_utf8(&method).unwrap().to_string()
+        String::from_utf8(method)
+            .map_err(|err| DFTD4Error::Rust(format!("Method name is not valid UTF-8: {err}")))?
     };
     let charge = match charge.is_null() {
         true => None,
         false => Some(*charge),
     };
 
-    // create structure and model
-    let structure = DFTD4Structure::new(natoms, &charges, &coords, charge, None, None);
-    let model = DFTD4Model::new(&structure);
+    let multiplicity = match uhf.is_null() {
+        true => None,
+        false if *uhf < 0 => {
+            return Err(DFTD4Error::Rust(format!(
+                "Number of unpaired electrons must not be negative, got {}",
+                *uhf
+            )))
+        }
+        false => Some(*uhf as usize + 1),
+    };
+
+    // create structure (with spin multiplicity validated against number of electrons) and model
+    let structure = DFTD4Structure::new_with_multiplicity_f(
+        natoms,
+        &charges,
+        &coords,
+        charge,
+        multiplicity,
+        None,
+        None,
+    )?;
+    let model = DFTD4Model::new_f(&structure)?;
 
     // get dispersion energy and gradient
-    let param = DFTD4Param::load_rational_damping(&method, true);
-    let result = get_dispersion(&structure, &model, &param, true, true);
+    let param = DFTD4Param::load_rational_damping_f(&method, true)?;
+    let result = get_dispersion_f(&structure, &model, &param, true, true)?;
 
     // set energy and gradient
     unsafe {
@@ -51,4 +80,62 @@ pub unsafe fn calc_dftd4_rest_(
         gradient.copy_from_slice(&result.1.unwrap());
         sigma.copy_from_slice(&result.2.unwrap());
     }
+    Ok(())
+}
+
+/// Evaluate SC-D4 energy and its derivative with respect to atomic charges for REST.
+///
+/// The charges of the current SCF density replace EEQ charges in the dispersion model (pure-Rust
+/// implementation only), see `scd4::get_scd4_energy`.
+///
+/// # Safety
+///
+/// All pointers must be valid: `num` [num_size], `xyz` [num_size][3], `atomic_charges`
+/// [num_size], `method` [method_len], `dedq` [num_size]; `charge` can be null.
+///
+/// Errors are returned to the caller; outputs are left untouched in that case.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn calc_dftd4_scd4_rest_(
+    num: *const c_int,
+    num_size: *const c_int,
+    xyz: *const c_double,
+    charge: *const c_double,
+    atomic_charges: *const c_double,
+    method: *const c_char,
+    method_len: *const c_int,
+    energy: *mut c_double,
+    dedq: *mut c_double,
+) -> Result<(), DFTD4Error> {
+    // convert c-style arguments to rust-style arguments
+    let natoms = unsafe { *num_size } as usize;
+    let numbers = {
+        let numbers = unsafe { std::slice::from_raw_parts(num, natoms) };
+        numbers.iter().map(|&x| x as usize).collect::<Vec<usize>>()
+    };
+    let coords = unsafe { std::slice::from_raw_parts(xyz, natoms * 3) };
+    let atomic_charges = unsafe { std::slice::from_raw_parts(atomic_charges, natoms) };
+    let method = {
+        let method = unsafe { std::slice::from_raw_parts(method, *method_len as usize) };
+        let method = method.iter().map(|&x| x as u8).collect::<Vec<u8>>();
+        String::from_utf8(method)
+            .map_err(|err| DFTD4Error::Rust(format!("Method name is not valid UTF-8: {err}")))?
+    };
+    let charge = match charge.is_null() {
+        true => None,
+        false => Some(*charge),
+    };
+
+    // create structure and model with charges of the SCF density
+    let structure = DFTD4Structure::new_f(natoms, &numbers, coords, charge, None, None)?;
+    let mut model = DFTD4Model::new_f(&structure)?;
+    let param = DFTD4Param::load_rational_damping_f(&method, true)?;
+    let result = crate::scd4::get_scd4_energy_f(&structure, &mut model, &param, atomic_charges)?;
+
+    // set energy and derivative
+    unsafe {
+        *energy = result.0;
+        let dedq = std::slice::from_raw_parts_mut(dedq, natoms);
+        dedq.copy_from_slice(&result.1);
+    }
+    Ok(())
 }
@@ -0,0 +1,169 @@
+//! Self-consistent D4 (SC-D4): dispersion contribution to SCF potentials.
+//!
+//! D4 scales reference polarizabilities by atomic partial charges, so that the dispersion energy
+//! depends on the electron density through the charges (E. Caldeweyher et al., J. Chem. Phys.
+//! 2019, 150, 154122). In each SCF iteration, charges of the current density are passed to the
+//! model ([`get_scd4_energy`]), and `dE/dq` is contracted with the derivative of charges with
+//! respect to the density matrix, e.g. for Mulliken charges by [`get_mulliken_potential`].
+//!
+//! Only available in the pure-Rust implementation, see `DFTD4Model::set_charges`.
+
+use crate::library::*;
+
+/// Evaluate dispersion energy and its derivative with respect to charges (failable)
+pub fn get_scd4_energy_f(
+    structure: &DFTD4Structure,
+    model: &mut DFTD4Model,
+    param: &DFTD4Param,
+    charges: &[f64],
+) -> Result<(f64, Vec<f64>), DFTD4Error> {
+    let natoms = structure.get_natoms();
+    if charges.len() != natoms {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for charges, expected {}, got {}",
+            natoms,
+            charges.len()
+        )));
+    }
+    model.set_charges_f(Some(charges))?;
+    let (energy, _, _) = get_dispersion_f(structure, model, param, false, false)?;
+    let dedq = get_charge_derivatives_f(structure, model, param)?;
+    Ok((energy, dedq))
+}
+
+/// Evaluate dispersion energy and its derivative with respect to charges
+///
+/// The charges of `model` are set to `charges`, which are kept for later evaluations.
+///
+/// # Arguments
+///
+/// * `charges` - atomic partial charges of the current density [natoms]
+///
+/// # Returns
+///
+/// Dispersion energy, and `dE/dq` [natoms].
+pub fn get_scd4_energy(
+    structure: &DFTD4Structure,
+    model: &mut DFTD4Model,
+    param: &DFTD4Param,
+    charges: &[f64],
+) -> (f64, Vec<f64>) {
+    get_scd4_energy_f(structure, model, param, charges).unwrap()
+}
+
+/// Dispersion potential in a basis for Mulliken charges (failable)
+pub fn get_mulliken_potential_f(
+    dedq: &[f64],
+    overlap: &[f64],
+    basis_atoms: &[usize],
+) -> Result<Vec<f64>, DFTD4Error> {
+    let nbasis = basis_atoms.len();
+    if overlap.len() != nbasis * nbasis {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for overlap, expected {}, got {}",
+            nbasis * nbasis,
+            overlap.len()
+        )));
+    }
+    if let Some(&a) = basis_atoms.iter().find(|&&a| a >= dedq.len()) {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid atom index {} of basis function, number of atoms is {}",
+            a,
+            dedq.len()
+        )));
+    }
+    let mut potential = vec![0.0; nbasis * nbasis];
+    for mu in 0..nbasis {
+        for nu in 0..nbasis {
+            let shift = dedq[basis_atoms[mu]] + dedq[basis_atoms[nu]];
+            potential[mu * nbasis + nu] = -0.5 * shift * overlap[mu * nbasis + nu];
+        }
+    }
+    Ok(potential)
+}
+
+/// Dispersion potential in a basis for Mulliken charges
+///
+/// For Mulliken charges `q_A = Z_A - sum_(mu in A) (P S)_mu,mu`, the derivative of the dispersion
+/// energy with respect to the density matrix is `V_mu,nu = -1/2 (dE/dq_A + dE/dq_B) S_mu,nu`
+/// with `mu` on atom `A` and `nu` on atom `B`, to be added to the Fock matrix.
+///
+/// # Arguments
+///
+/// * `dedq` - derivative of the dispersion energy with respect to charges [natoms]
+/// * `overlap` - overlap matrix [nbasis][nbasis]
+/// * `basis_atoms` - atom index of each basis function [nbasis]
+pub fn get_mulliken_potential(dedq: &[f64], overlap: &[f64], basis_atoms: &[usize]) -> Vec<f64> {
+    get_mulliken_potential_f(dedq, overlap, basis_atoms).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mulliken_potential() {
+        // model energy E(q) = sum_A k_A q_A^2 of Mulliken charges
+        let basis_atoms = [0, 0, 1, 2];
+        let nuclear = [3.0, 1.0, 1.0];
+        let k = [0.3, -0.2, 0.5];
+        #[rustfmt::skip]
+        let overlap = [
+            1.0, 0.0, 0.4, 0.3,
+            0.0, 1.0, 0.2, -0.1,
+            0.4, 0.2, 1.0, 0.1,
+            0.3, -0.1, 0.1, 1.0,
+        ];
+        #[rustfmt::skip]
+        let density = [
+            1.2, 0.1, 0.3, 0.2,
+            0.1, 0.9, 0.1, 0.0,
+            0.3, 0.1, 0.8, 0.1,
+            0.2, 0.0, 0.1, 0.7,
+        ];
+        let n = basis_atoms.len();
+        let charges = |p: &[f64]| {
+            let mut q = nuclear.to_vec();
+            for mu in 0..n {
+                let ps = (0..n)
+                    .map(|nu| p[mu * n + nu] * overlap[nu * n + mu])
+                    .sum::<f64>();
+                q[basis_atoms[mu]] -= ps;
+            }
+            q
+        };
+        let energy = |p: &[f64]| {
+            let q = charges(p);
+            (0..3).map(|a| k[a] * q[a] * q[a]).sum::<f64>()
+        };
+        let q = charges(&density);
+        let dedq = (0..3).map(|a| 2.0 * k[a] * q[a]).collect::<Vec<f64>>();
+        let potential = get_mulliken_potential(&dedq, &overlap, &basis_atoms);
+
+        // symmetric perturbations of the density matrix
+        let step = 1e-6;
+        for mu in 0..n {
+            for nu in 0..=mu {
+                let mut p = density.to_vec();
+                p[mu * n + nu] += step;
+                if mu != nu {
+                    p[nu * n + mu] += step;
+                }
+                let ep = energy(&p);
+                let mut p = density.to_vec();
+                p[mu * n + nu] -= step;
+                if mu != nu {
+                    p[nu * n + mu] -= step;
+                }
+                let em = energy(&p);
+                let expected = match mu == nu {
+                    true => potential[mu * n + nu],
+                    false => 2.0 * potential[mu * n + nu],
+                };
+                assert!(((ep - em) / (2.0 * step) - expected).abs() < 1e-8);
+            }
+        }
+        assert!(get_mulliken_potential_f(&dedq, &overlap[..9], &basis_atoms).is_err());
+        assert!(get_mulliken_potential_f(&dedq[..2], &overlap, &basis_atoms).is_err());
+    }
+}
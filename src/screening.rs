@@ -0,0 +1,124 @@
+//! Screening of dispersion corrections of several functionals on one structure.
+//!
+//! All functionals share the dispersion model of the structure (coordination numbers, charges and
+//! C6 coefficients), and only differ by damping parameters; the model is evaluated once by the
+//! pure-Rust implementation, and for each functional by `libdftd4`, see
+//! `library::get_dispersion_multi`.
+
+use crate::library::*;
+
+/// Energy differences [nfunc], and optionally gradient differences [nfunc][natoms][3]
+pub type DispersionDifferences = (Vec<f64>, Option<Vec<Vec<f64>>>);
+
+/// Dispersion energies and gradients of several functionals
+#[derive(Debug, Clone)]
+pub struct DispersionTable {
+    /// names of functionals or parameter sets [nfunc]
+    pub names: Vec<String>,
+    /// dispersion energies (Hartree) [nfunc]
+    pub energies: Vec<f64>,
+    /// dispersion gradients [nfunc][natoms][3]
+    pub gradients: Option<Vec<Vec<f64>>>,
+}
+
+impl DispersionTable {
+    /// Index of a functional in the table (failable)
+    pub fn get_index_f(&self, name: &str) -> Result<usize, DFTD4Error> {
+        self.names
+            .iter()
+            .position(|x| x == name)
+            .ok_or_else(|| DFTD4Error::Rust(format!("Functional {} is not in the table", name)))
+    }
+
+    /// Energy and optionally gradient differences to a reference functional (failable)
+    pub fn get_differences_f(&self, reference: &str) -> Result<DispersionDifferences, DFTD4Error> {
+        let iref = self.get_index_f(reference)?;
+        let energies = self
+            .energies
+            .iter()
+            .map(|e| e - self.energies[iref])
+            .collect();
+        let gradients = self.gradients.as_ref().map(|gradients| {
+            gradients
+                .iter()
+                .map(|g| g.iter().zip(&gradients[iref]).map(|(a, b)| a - b).collect())
+                .collect()
+        });
+        Ok((energies, gradients))
+    }
+
+    /// Energy and optionally gradient differences to a reference functional
+    ///
+    /// Returns `E - E_ref` [nfunc], and `grad - grad_ref` [nfunc][natoms][3] if gradients are
+    /// evaluated.
+    pub fn get_differences(&self, reference: &str) -> (Vec<f64>, Option<Vec<Vec<f64>>>) {
+        self.get_differences_f(reference).unwrap()
+    }
+}
+
+/// Evaluate dispersion energies of named parameter sets (failable)
+pub fn get_dispersion_table_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    params: &[(&str, &DFTD4Param)],
+    eval_grad: bool,
+) -> Result<DispersionTable, DFTD4Error> {
+    let param_refs = params.iter().map(|(_, p)| *p).collect::<Vec<&DFTD4Param>>();
+    let results = get_dispersion_multi_f(structure, model, &param_refs, eval_grad)?;
+    let (energies, gradients): (Vec<f64>, Vec<Option<Vec<f64>>>) = results.into_iter().unzip();
+    Ok(DispersionTable {
+        names: params.iter().map(|(name, _)| name.to_string()).collect(),
+        energies,
+        gradients: gradients.into_iter().collect(),
+    })
+}
+
+/// Evaluate dispersion energies of named parameter sets
+///
+/// # Arguments
+///
+/// * `params` - names and damping parameters [nfunc]
+pub fn get_dispersion_table(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    params: &[(&str, &DFTD4Param)],
+    eval_grad: bool,
+) -> DispersionTable {
+    get_dispersion_table_f(structure, model, params, eval_grad).unwrap()
+}
+
+/// Evaluate dispersion energies of functionals with parameters from internal storage (failable)
+pub fn get_functional_table_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    methods: &[&str],
+    mdb: bool,
+    eval_grad: bool,
+) -> Result<DispersionTable, DFTD4Error> {
+    let params = methods
+        .iter()
+        .map(|method| DFTD4Param::load_rational_damping_f(method, mdb))
+        .collect::<Result<Vec<DFTD4Param>, DFTD4Error>>()?;
+    let named = methods
+        .iter()
+        .zip(&params)
+        .map(|(&method, param)| (method, param))
+        .collect::<Vec<(&str, &DFTD4Param)>>();
+    get_dispersion_table_f(structure, model, &named, eval_grad)
+}
+
+/// Evaluate dispersion energies of functionals with parameters from internal storage
+///
+/// # Arguments
+///
+/// * `methods` - names of functionals [nfunc], as in `DFTD4Param::load_rational_damping`
+/// * `mdb` - whether to load parameters with the three-body term
+pub fn get_functional_table(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    methods: &[&str],
+    mdb: bool,
+    eval_grad: bool,
+) -> DispersionTable {
+    get_functional_table_f(structure, model, methods, mdb, eval_grad).unwrap()
+}
@@ -0,0 +1,90 @@
+//! Two-body and non-additive three-body (ATM) dispersion terms.
+//!
+//! D4 dispersion is the sum of the two-body term with rational damping (`s6`, `s8`) and the
+//! Axilrod-Teller-Muto three-body term scaled by `s9`. Both are linear in their scaling factors,
+//! so each term is evaluated by parameters with the scaling factors of the other term set to zero.
+
+use crate::library::*;
+
+/// Two-body and three-body dispersion energies, gradients and virials
+#[derive(Debug, Clone)]
+pub struct DispersionTerms {
+    /// scaling factor of the three-body term used for evaluation
+    pub s9: f64,
+    /// two-body dispersion energy (Hartree)
+    pub energy2: f64,
+    /// three-body dispersion energy (Hartree)
+    pub energy3: f64,
+    /// two-body dispersion gradient [natoms][3]
+    pub gradient2: Option<Vec<f64>>,
+    /// three-body dispersion gradient [natoms][3]
+    pub gradient3: Option<Vec<f64>>,
+    /// two-body virial [3][3]
+    pub sigma2: Option<Vec<f64>>,
+    /// three-body virial [3][3]
+    pub sigma3: Option<Vec<f64>>,
+}
+
+impl DispersionTerms {
+    /// Total dispersion energy
+    pub fn get_energy(&self) -> f64 {
+        self.energy2 + self.energy3
+    }
+}
+
+/// Evaluate two-body and three-body dispersion terms (failable)
+pub fn get_dispersion_terms_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    s9: Option<f64>,
+    eval_grad: bool,
+    eval_sigma: bool,
+) -> Result<DispersionTerms, DFTD4Error> {
+    let [s6, s8, s9_param, a1, a2, alp] = param.get_rational_damping_f()?;
+    let s9 = s9.unwrap_or(s9_param);
+    let param2 = DFTD4Param::new_rational_damping_f(s6, s8, 0.0, a1, a2, alp)?;
+    let (energy2, gradient2, sigma2) =
+        get_dispersion_f(structure, model, &param2, eval_grad, eval_sigma)?;
+    let (energy3, gradient3, sigma3) = match s9 == 0.0 {
+        true => (
+            0.0,
+            eval_grad.then(|| vec![0.0; 3 * structure.get_natoms()]),
+            eval_sigma.then(|| vec![0.0; 9]),
+        ),
+        false => {
+            let param3 = DFTD4Param::new_rational_damping_f(0.0, 0.0, s9, a1, a2, alp)?;
+            get_dispersion_f(structure, model, &param3, eval_grad, eval_sigma)?
+        }
+    };
+    Ok(DispersionTerms {
+        s9,
+        energy2,
+        energy3,
+        gradient2,
+        gradient3,
+        sigma2,
+        sigma3,
+    })
+}
+
+/// Evaluate two-body and three-body dispersion terms
+///
+/// Both terms are evaluated with the damping parameters of `param`; their sum is the dispersion
+/// energy of `get_dispersion` with the same `s9`. Values of loaded parameters are only available
+/// in the pure-Rust implementation, see `DFTD4Param::get_rational_damping`.
+///
+/// # Arguments
+///
+/// * `s9` - scaling factor of the three-body term, overriding the one of `param` if given; the
+///   three-body term is skipped (zero) for `Some(0.0)`
+pub fn get_dispersion_terms(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    s9: Option<f64>,
+    eval_grad: bool,
+    eval_sigma: bool,
+) -> DispersionTerms {
+    get_dispersion_terms_f(structure, model, param, s9, eval_grad, eval_sigma).unwrap()
+}
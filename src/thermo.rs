@@ -0,0 +1,306 @@
+//! Ideal-gas thermochemistry (rigid rotor, harmonic oscillator) from harmonic frequencies.
+//!
+//! Low-frequency modes can be treated in the quasi-RRHO approximation of Grimme (S. Grimme,
+//! Chem. Eur. J. 2012, 18, 9955-9964): vibrational entropies are interpolated to free-rotor
+//! entropies below a cutoff frequency, which avoids the divergence of harmonic entropies.
+//!
+//! All results are per molecule in atomic units (Hartree, Hartree/K), and are thermal corrections
+//! to be added to the electronic (e.g. D4-corrected) energy.
+
+use crate::library::*;
+use crate::math::eigh;
+use crate::vibration::{get_masses_f, get_vibrations_f};
+
+/// Boltzmann constant (J/K)
+const KB: f64 = 1.380649e-23;
+/// Planck constant (J s)
+const PLANCK: f64 = 6.62607015e-34;
+/// Speed of light (cm/s)
+const SPEED_OF_LIGHT: f64 = 2.99792458e10;
+/// Atomic mass unit (kg)
+const AMU: f64 = 1.66053906660e-27;
+/// Bohr radius (m)
+const BOHR: f64 = 0.529177210903e-10;
+/// Hartree energy (J)
+const HARTREE: f64 = 4.3597447222071e-18;
+
+/// Configuration of thermochemistry
+#[derive(Debug, Clone)]
+pub struct ThermoConfig {
+    /// temperature (K)
+    pub temperature: f64,
+    /// pressure (Pa)
+    pub pressure: f64,
+    /// rotational symmetry number
+    pub symmetry_number: usize,
+    /// spin multiplicity, for electronic entropy; singlet, or the multiplicity of the structure in
+    /// `get_thermochemistry`, if `None`
+    pub multiplicity: Option<usize>,
+    /// whether low-frequency modes are treated in quasi-RRHO approximation
+    pub qrrho: bool,
+    /// cutoff frequency of quasi-RRHO interpolation (cm^-1)
+    pub qrrho_cutoff: f64,
+    /// exponent of quasi-RRHO interpolation
+    pub qrrho_alpha: f64,
+    /// average moment of inertia of free rotors (kg m^2)
+    pub qrrho_inertia: f64,
+}
+
+impl Default for ThermoConfig {
+    fn default() -> Self {
+        Self {
+            temperature: 298.15,
+            pressure: 101325.0,
+            symmetry_number: 1,
+            multiplicity: None,
+            qrrho: true,
+            qrrho_cutoff: 100.0,
+            qrrho_alpha: 4.0,
+            qrrho_inertia: 1e-44,
+        }
+    }
+}
+
+/// Result of thermochemistry
+#[derive(Debug, Clone)]
+pub struct ThermoResult {
+    /// zero-point vibrational energy (Hartree)
+    pub zpe: f64,
+    /// enthalpy correction, including zero-point energy (Hartree)
+    pub enthalpy: f64,
+    /// entropy (Hartree/K)
+    pub entropy: f64,
+    /// Gibbs free energy correction, `enthalpy - temperature * entropy` (Hartree)
+    pub gibbs: f64,
+    /// number of imaginary frequencies, which are skipped
+    pub nimag: usize,
+    /// spin multiplicity of electronic entropy
+    pub multiplicity: usize,
+}
+
+/// Principal moments of inertia (kg m^2) in ascending order
+fn get_principal_moments(positions: &[f64], masses: &[f64]) -> [f64; 3] {
+    let natoms = masses.len();
+    let total = masses.iter().sum::<f64>();
+    let com = [0, 1, 2].map(|k| {
+        (0..natoms)
+            .map(|i| masses[i] * positions[3 * i + k])
+            .sum::<f64>()
+            / total
+    });
+    let mut inertia = [0.0; 9];
+    for i in 0..natoms {
+        let r = [0, 1, 2].map(|k| positions[3 * i + k] - com[k]);
+        let r2 = r.iter().map(|x| x * x).sum::<f64>();
+        for a in 0..3 {
+            for b in 0..3 {
+                let delta = if a == b { r2 } else { 0.0 };
+                inertia[3 * a + b] += masses[i] * (delta - r[a] * r[b]);
+            }
+        }
+    }
+    let (moments, _) = eigh(3, &inertia);
+    [0, 1, 2].map(|k| moments[k].max(0.0) * AMU * BOHR * BOHR)
+}
+
+/// Ideal-gas thermochemistry from harmonic frequencies (failable)
+pub fn thermochemistry_f(
+    positions: &[f64],
+    masses: &[f64],
+    frequencies: &[f64],
+    config: &ThermoConfig,
+) -> Result<ThermoResult, DFTD4Error> {
+    let natoms = masses.len();
+    if positions.len() != 3 * natoms {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for positions, expected {}, got {}",
+            3 * natoms,
+            positions.len()
+        )));
+    }
+    if config.temperature <= 0.0 || config.pressure <= 0.0 {
+        return Err(DFTD4Error::Rust(
+            "Temperature and pressure should be positive".to_string(),
+        ));
+    }
+    if config.symmetry_number == 0 || config.multiplicity == Some(0) {
+        return Err(DFTD4Error::Rust(
+            "Symmetry number and multiplicity should be positive".to_string(),
+        ));
+    }
+    let temp = config.temperature;
+    let kt = KB * temp;
+
+    // translation (Sackur-Tetrode), enthalpy includes pV = kT
+    let mass = masses.iter().sum::<f64>() * AMU;
+    let qtrans = (2.0 * std::f64::consts::PI * mass * kt / (PLANCK * PLANCK)).powf(1.5) * kt
+        / config.pressure;
+    let mut entropy = KB * (qtrans.ln() + 2.5);
+    let mut enthalpy = 2.5 * kt;
+
+    // rigid rotor
+    let moments = get_principal_moments(positions, masses);
+    let theta = moments.map(|i| PLANCK * PLANCK / (8.0 * std::f64::consts::PI.powi(2) * i * KB));
+    let sigma = config.symmetry_number as f64;
+    if natoms > 1 {
+        if moments[0] < 1e-8 * moments[2] {
+            // linear
+            let qrot = temp / (sigma * theta[2]);
+            entropy += KB * (qrot.ln() + 1.0);
+            enthalpy += kt;
+        } else {
+            let qrot = std::f64::consts::PI.sqrt() / sigma
+                * (temp.powi(3) / (theta[0] * theta[1] * theta[2])).sqrt();
+            entropy += KB * (qrot.ln() + 1.5);
+            enthalpy += 1.5 * kt;
+        }
+    }
+
+    // electronic
+    let multiplicity = config.multiplicity.unwrap_or(1);
+    entropy += KB * (multiplicity as f64).ln();
+
+    // harmonic oscillators, or quasi-RRHO for entropy
+    let mut zpe = 0.0;
+    let mut nimag = 0;
+    for &freq in frequencies {
+        if freq <= 0.0 {
+            nimag += (freq < 0.0) as usize;
+            continue;
+        }
+        let energy = PLANCK * SPEED_OF_LIGHT * freq;
+        let x = energy / kt;
+        zpe += 0.5 * energy;
+        enthalpy += energy / x.exp_m1();
+        let s_vib = KB * (x / x.exp_m1() - (-(-x).exp_m1()).ln());
+        entropy += match config.qrrho {
+            true => {
+                // free rotor with moment of inertia of the mode, limited by average moment
+                let mu = PLANCK / (8.0 * std::f64::consts::PI.powi(2) * SPEED_OF_LIGHT * freq);
+                let mu = mu * config.qrrho_inertia / (mu + config.qrrho_inertia);
+                let s_rot = KB
+                    * (0.5
+                        + (8.0 * std::f64::consts::PI.powi(3) * mu * kt / (PLANCK * PLANCK))
+                            .sqrt()
+                            .ln());
+                let weight = 1.0 / (1.0 + (config.qrrho_cutoff / freq).powf(config.qrrho_alpha));
+                weight * s_vib + (1.0 - weight) * s_rot
+            }
+            false => s_vib,
+        };
+    }
+    enthalpy += zpe;
+
+    let (zpe, enthalpy, entropy) = (zpe / HARTREE, enthalpy / HARTREE, entropy / HARTREE);
+    Ok(ThermoResult {
+        zpe,
+        enthalpy,
+        entropy,
+        gibbs: enthalpy - temp * entropy,
+        nimag,
+        multiplicity,
+    })
+}
+
+/// Ideal-gas thermochemistry from harmonic frequencies
+///
+/// # Arguments
+///
+/// * `positions` - positions [natoms][3]
+/// * `masses` - atomic masses in atomic mass units [natoms]
+/// * `frequencies` - vibrational frequencies in cm^-1 [nmodes]; imaginary (negative) frequencies
+///   are skipped
+pub fn thermochemistry(
+    positions: &[f64],
+    masses: &[f64],
+    frequencies: &[f64],
+    config: &ThermoConfig,
+) -> ThermoResult {
+    thermochemistry_f(positions, masses, frequencies, config).unwrap()
+}
+
+/// Ideal-gas thermochemistry with frequencies of the D4 dispersion hessian (failable)
+pub fn get_thermochemistry_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    hessian: Option<&[f64]>,
+    masses: Option<&[f64]>,
+    config: &ThermoConfig,
+) -> Result<ThermoResult, DFTD4Error> {
+    if structure.get_lattice().is_some() {
+        return Err(DFTD4Error::Rust(
+            "Ideal-gas thermochemistry is not available for periodic structures".to_string(),
+        ));
+    }
+    let (frequencies, _) = get_vibrations_f(structure, model, param, hessian, masses)?;
+    let masses = match masses {
+        Some(masses) => masses.to_vec(),
+        None => get_masses_f(&structure.get_numbers())?,
+    };
+    let config = ThermoConfig {
+        multiplicity: config.multiplicity.or(Some(structure.get_multiplicity())),
+        ..config.clone()
+    };
+    thermochemistry_f(&structure.get_positions(), &masses, &frequencies, &config)
+}
+
+/// Ideal-gas thermochemistry with frequencies of the D4 dispersion hessian
+///
+/// The numerical D4 hessian is added to `hessian` (e.g. from an electronic structure method), or
+/// used alone if `None`, as in `get_vibrations`. The spin multiplicity of `structure` is used
+/// unless `config.multiplicity` is given.
+///
+/// # Arguments
+///
+/// * `hessian` - additional Cartesian hessian [natoms][3][natoms][3]
+/// * `masses` - atomic masses in atomic mass units [natoms]; standard atomic weights if `None`
+pub fn get_thermochemistry(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    hessian: Option<&[f64]>,
+    masses: Option<&[f64]>,
+    config: &ThermoConfig,
+) -> ThermoResult {
+    get_thermochemistry_f(structure, model, param, hessian, masses, config).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thermochemistry() {
+        // standard molar entropy of argon at 1 bar, 154.85 J/(mol K)
+        let standard = ThermoConfig {
+            pressure: 1e5,
+            ..Default::default()
+        };
+        let result = thermochemistry(&[0.0; 3], &[39.948], &[], &standard);
+        let to_molar = HARTREE * 6.02214076e23;
+        assert!((result.entropy * to_molar - 154.85).abs() < 0.05);
+        assert!((result.enthalpy - 2.5 * KB * 298.15 / HARTREE).abs() < 1e-12);
+        assert_eq!(result.zpe, 0.0);
+
+        // quasi-RRHO agrees with RRHO for high frequencies, and reduces entropy of low frequencies
+        let positions = [0.0, 0.0, 0.0, 1.8, 0.0, 0.0, -0.5, 1.7, 0.0];
+        let masses = [15.999, 1.008, 1.008];
+        let rrho = ThermoConfig {
+            qrrho: false,
+            ..Default::default()
+        };
+        let config = ThermoConfig::default();
+        let high = [1600.0, 3700.0, 3800.0];
+        let a = thermochemistry(&positions, &masses, &high, &config);
+        let b = thermochemistry(&positions, &masses, &high, &rrho);
+        assert!((a.entropy - b.entropy).abs() < 1e-6 * b.entropy);
+        assert!((a.zpe - 0.5 * high.iter().sum::<f64>() / crate::data::AUTORCM).abs() < 1e-8);
+        let low = [5.0, -20.0, 3700.0];
+        let a = thermochemistry(&positions, &masses, &low, &config);
+        let b = thermochemistry(&positions, &masses, &low, &rrho);
+        assert!(a.entropy < b.entropy);
+        assert_eq!(a.nimag, 1);
+        assert!((a.gibbs - (a.enthalpy - 298.15 * a.entropy)).abs() < 1e-14);
+    }
+}
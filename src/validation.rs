@@ -0,0 +1,135 @@
+//! Finite-difference validation of analytic derivatives.
+//!
+//! [`check_derivatives`] compares gradient and virial from `get_dispersion` against central finite
+//! differences of the energy. Gradient components are evaluated by displacing atoms; virial
+//! components by straining positions (and lattice, for periodic structures) as
+//! `r' = r (I + e)`, so that `sigma[a + 3 * b] = dE / de[a][b]`. For 1D and 2D periodic
+//! structures, strain and virial are restricted to periodic directions.
+
+use crate::lattice::get_periodic_projector;
+use crate::library::*;
+
+/// Report of finite-difference validation
+#[derive(Clone, Debug)]
+pub struct DerivativeReport {
+    /// analytic gradient [natoms][3]
+    pub gradient: Vec<f64>,
+    /// finite-difference gradient [natoms][3]
+    pub gradient_fd: Vec<f64>,
+    /// absolute error of gradient components [natoms][3]
+    pub gradient_error: Vec<f64>,
+    /// analytic virial [3][3]
+    pub sigma: Vec<f64>,
+    /// finite-difference virial [3][3]
+    pub sigma_fd: Vec<f64>,
+    /// absolute error of virial components [3][3]
+    pub sigma_error: Vec<f64>,
+}
+
+impl DerivativeReport {
+    /// Maximum absolute error of gradient components
+    pub fn max_gradient_error(&self) -> f64 {
+        self.gradient_error.iter().cloned().fold(0.0, f64::max)
+    }
+
+    /// Maximum absolute error of virial components
+    pub fn max_sigma_error(&self) -> f64 {
+        self.sigma_error.iter().cloned().fold(0.0, f64::max)
+    }
+}
+
+/// Compare analytic derivatives against finite differences (failable)
+pub fn check_derivatives_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    step: f64,
+) -> Result<DerivativeReport, DFTD4Error> {
+    if step <= 0.0 {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid finite-difference step {}",
+            step
+        )));
+    }
+    let positions = structure.get_positions();
+    let lattice = structure.get_lattice();
+    let (_, gradient, sigma) = get_dispersion_f(structure, model, param, true, true)?;
+    let (gradient, sigma) = (gradient.unwrap(), sigma.unwrap());
+
+    let result = (|| {
+        let energy_at = |x: &[f64], lattice: Option<&[f64]>| {
+            structure.update_f(x, lattice)?;
+            get_dispersion_f(structure, model, param, false, false).map(|(e, _, _)| e)
+        };
+
+        // gradient by displacement of atoms
+        let mut gradient_fd = vec![0.0; positions.len()];
+        for (k, g) in gradient_fd.iter_mut().enumerate() {
+            let mut x = positions.clone();
+            x[k] = positions[k] + step;
+            let ep = energy_at(&x, None)?;
+            x[k] = positions[k] - step;
+            let em = energy_at(&x, None)?;
+            *g = (ep - em) / (2.0 * step);
+        }
+
+        // virial by strain of positions and lattice: r'[d] = r[d] + sum_c r[c] e[c][d]; for 1D
+        // and 2D periodic structures, strain is restricted to periodic directions as P e P, which
+        // keeps vacuum lattice vectors and gives the restricted virial P sigma P
+        let proj = match (lattice.as_deref(), structure.get_periodic()) {
+            (Some(l), Some(periodic)) => get_periodic_projector(l, &periodic),
+            _ => [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        };
+        let strain = |v: &[f64], a: usize, b: usize, e: f64| {
+            let mut v = v.to_vec();
+            v.chunks_mut(3).for_each(|r| {
+                let ra = (0..3).map(|c| r[c] * proj[3 * c + a]).sum::<f64>();
+                (0..3).for_each(|d| r[d] += e * ra * proj[3 * b + d]);
+            });
+            v
+        };
+        let mut sigma_fd = vec![0.0; 9];
+        for a in 0..3 {
+            for b in 0..3 {
+                let mut energies = [0.0; 2];
+                for (e, sign) in energies.iter_mut().zip([1.0, -1.0]) {
+                    let x = strain(&positions, a, b, sign * step);
+                    let l = lattice.as_deref().map(|l| strain(l, a, b, sign * step));
+                    *e = energy_at(&x, l.as_deref())?;
+                }
+                sigma_fd[a + 3 * b] = (energies[0] - energies[1]) / (2.0 * step);
+            }
+        }
+        Ok((gradient_fd, sigma_fd))
+    })();
+    // restore structure before reporting errors
+    structure.update_f(&positions, lattice.as_deref())?;
+    let (gradient_fd, sigma_fd) = result?;
+
+    let error = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| (x - y).abs()).collect();
+    Ok(DerivativeReport {
+        gradient_error: error(&gradient, &gradient_fd),
+        sigma_error: error(&sigma, &sigma_fd),
+        gradient,
+        gradient_fd,
+        sigma,
+        sigma_fd,
+    })
+}
+
+/// Compare analytic derivatives against finite differences
+///
+/// The structure is displaced and strained by `DFTD4Structure::update_f`, and restored on return.
+/// For molecules, the virial is checked by straining positions only.
+///
+/// # Arguments
+///
+/// * `step` - displacement of atoms (Bohr) and strain of finite differences, e.g. 1e-5
+pub fn check_derivatives(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    step: f64,
+) -> DerivativeReport {
+    check_derivatives_f(structure, model, param, step).unwrap()
+}
@@ -0,0 +1,378 @@
+//! Harmonic vibrational analysis.
+//!
+//! The Cartesian hessian is mass-weighted, translations and rotations are projected out, and the
+//! remaining modes are diagonalized. Frequencies are given in cm^-1, with imaginary frequencies as
+//! negative numbers. Normal modes can be exported in Molden format for visualization.
+
+use crate::data::{get_atomic_mass, get_element_symbol, AMUTOAU, AUTORCM};
+use crate::library::*;
+use crate::math::eigh;
+
+/// Get masses (in atomic mass units) of atoms from standard atomic weights (failable)
+pub fn get_masses_f(numbers: &[usize]) -> Result<Vec<f64>, DFTD4Error> {
+    numbers
+        .iter()
+        .map(|&z| {
+            get_atomic_mass(z)
+                .ok_or_else(|| DFTD4Error::Rust(format!("Unsupported atomic number {}", z)))
+        })
+        .collect()
+}
+
+/// Get masses (in atomic mass units) of atoms from standard atomic weights
+///
+/// Masses can be modified for isotopes (e.g. 2.014 for deuterium) before they are passed to
+/// [`harmonic_analysis`].
+pub fn get_masses(numbers: &[usize]) -> Vec<f64> {
+    get_masses_f(numbers).unwrap()
+}
+
+/// Orthonormal basis [nvib][natoms * 3] of mass-weighted displacements without translations (and
+/// rotations, if `rotations`).
+fn get_internal_basis(positions: &[f64], masses: &[f64], rotations: bool) -> Vec<Vec<f64>> {
+    let natoms = masses.len();
+    let n = 3 * natoms;
+    let total = masses.iter().sum::<f64>();
+    let com = [0, 1, 2].map(|k| {
+        (0..natoms)
+            .map(|i| masses[i] * positions[3 * i + k])
+            .sum::<f64>()
+            / total
+    });
+
+    // translations and rotations first, then Cartesian unit vectors to span the complement
+    let mut candidates = vec![];
+    for a in 0..3 {
+        let mut v = vec![0.0; n];
+        (0..natoms).for_each(|i| v[3 * i + a] = masses[i].sqrt());
+        candidates.push(v);
+    }
+    if rotations {
+        for a in 0..3 {
+            let mut v = vec![0.0; n];
+            for i in 0..natoms {
+                let r = [0, 1, 2].map(|k| positions[3 * i + k] - com[k]);
+                // e_a x r
+                let (b, c) = ((a + 1) % 3, (a + 2) % 3);
+                v[3 * i + c] = masses[i].sqrt() * r[b];
+                v[3 * i + b] = -masses[i].sqrt() * r[c];
+            }
+            candidates.push(v);
+        }
+    }
+    let nexternal = candidates.len();
+    for k in 0..n {
+        let mut v = vec![0.0; n];
+        v[k] = 1.0;
+        candidates.push(v);
+    }
+
+    // Gram-Schmidt; vanishing rotations (linear molecules, single atoms) are dropped
+    let mut basis: Vec<Vec<f64>> = vec![];
+    let mut nprojected = 0;
+    for (ic, mut v) in candidates.into_iter().enumerate() {
+        let norm0 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm0 < f64::EPSILON {
+            continue;
+        }
+        for _ in 0..2 {
+            for u in &basis {
+                let dot = u.iter().zip(&v).map(|(a, b)| a * b).sum::<f64>();
+                v.iter_mut().zip(u).for_each(|(x, y)| *x -= dot * y);
+            }
+        }
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm > 1e-6 * norm0 && basis.len() < n {
+            v.iter_mut().for_each(|x| *x /= norm);
+            basis.push(v);
+            if ic < nexternal {
+                nprojected += 1;
+            }
+        }
+    }
+    basis.split_off(nprojected)
+}
+
+/// Harmonic vibrational analysis of a Cartesian hessian (failable)
+pub fn harmonic_analysis_f(
+    positions: &[f64],
+    hessian: &[f64],
+    masses: &[f64],
+    rotations: bool,
+) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
+    let natoms = masses.len();
+    let n = 3 * natoms;
+    if positions.len() != n {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for positions, expected {}, got {}",
+            n,
+            positions.len()
+        )));
+    }
+    if hessian.len() != n * n {
+        return Err(DFTD4Error::Rust(format!(
+            "Invalid dimension for hessian, expected {}, got {}",
+            n * n,
+            hessian.len()
+        )));
+    }
+    if let Some(m) = masses.iter().find(|&&m| m <= 0.0) {
+        return Err(DFTD4Error::Rust(format!("Invalid atomic mass {}", m)));
+    }
+
+    // mass-weighted hessian, symmetrized
+    let sqrt_mass = (0..n).map(|k| masses[k / 3].sqrt()).collect::<Vec<f64>>();
+    let mut hmw = vec![0.0; n * n];
+    for k in 0..n {
+        for l in 0..n {
+            hmw[k * n + l] =
+                0.5 * (hessian[k * n + l] + hessian[l * n + k]) / (sqrt_mass[k] * sqrt_mass[l]);
+        }
+    }
+
+    // hessian in the internal basis
+    let basis = get_internal_basis(positions, masses, rotations);
+    let nvib = basis.len();
+    let hb = basis
+        .iter()
+        .map(|b| {
+            (0..n)
+                .map(|k| (0..n).map(|l| hmw[k * n + l] * b[l]).sum::<f64>())
+                .collect::<Vec<f64>>()
+        })
+        .collect::<Vec<Vec<f64>>>();
+    let mut hint = vec![0.0; nvib * nvib];
+    for p in 0..nvib {
+        for q in 0..nvib {
+            hint[p * nvib + q] = basis[p].iter().zip(&hb[q]).map(|(a, b)| a * b).sum();
+        }
+    }
+    let (eigenvalues, eigenvectors) = eigh(nvib, &hint);
+
+    // frequencies: eigenvalues are in Hartree / (Bohr^2 amu)
+    let frequencies = eigenvalues
+        .iter()
+        .map(|&w| w.signum() * (w.abs() / AMUTOAU).sqrt() * AUTORCM)
+        .collect::<Vec<f64>>();
+    // normal modes as normalized Cartesian displacements
+    let mut modes = vec![0.0; nvib * n];
+    for m in 0..nvib {
+        let mode = &mut modes[m * n..(m + 1) * n];
+        for (p, b) in basis.iter().enumerate() {
+            let c = eigenvectors[m * nvib + p];
+            mode.iter_mut().zip(b).for_each(|(x, y)| *x += c * y);
+        }
+        mode.iter_mut().zip(&sqrt_mass).for_each(|(x, s)| *x /= s);
+        let norm = mode.iter().map(|x| x * x).sum::<f64>().sqrt();
+        mode.iter_mut().for_each(|x| *x /= norm);
+    }
+    Ok((frequencies, modes))
+}
+
+/// Harmonic vibrational analysis of a Cartesian hessian
+///
+/// # Arguments
+///
+/// * `positions` - positions [natoms][3]
+/// * `hessian` - Cartesian hessian [natoms][3][natoms][3]
+/// * `masses` - atomic masses in atomic mass units [natoms], see [`get_masses`]
+/// * `rotations` - whether rotations are projected out (false for periodic systems)
+///
+/// # Returns
+///
+/// Frequencies in cm^-1 [nmodes] in ascending order (imaginary frequencies are negative), and
+/// normal modes as normalized Cartesian displacements [nmodes][natoms][3]. Number of modes is
+/// 3N-6 (3N-5 for linear molecules), or 3N-3 without projection of rotations.
+pub fn harmonic_analysis(
+    positions: &[f64],
+    hessian: &[f64],
+    masses: &[f64],
+    rotations: bool,
+) -> (Vec<f64>, Vec<f64>) {
+    harmonic_analysis_f(positions, hessian, masses, rotations).unwrap()
+}
+
+/// Harmonic vibrational analysis with the D4 dispersion hessian (failable)
+pub fn get_vibrations_f(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    hessian: Option<&[f64]>,
+    masses: Option<&[f64]>,
+) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
+    let natoms = structure.get_natoms();
+    let mut total = get_numerical_hessian_f(structure, model, param)?;
+    if let Some(hessian) = hessian {
+        if hessian.len() != total.len() {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for hessian, expected {}, got {}",
+                total.len(),
+                hessian.len()
+            )));
+        }
+        total.iter_mut().zip(hessian).for_each(|(h, x)| *h += x);
+    }
+    let masses = match masses {
+        Some(masses) if masses.len() != natoms => {
+            return Err(DFTD4Error::Rust(format!(
+                "Invalid dimension for masses, expected {}, got {}",
+                natoms,
+                masses.len()
+            )));
+        }
+        Some(masses) => masses.to_vec(),
+        None => get_masses_f(&structure.get_numbers())?,
+    };
+    let rotations = structure.get_lattice().is_none();
+    harmonic_analysis_f(&structure.get_positions(), &total, &masses, rotations)
+}
+
+/// Harmonic vibrational analysis with the D4 dispersion hessian
+///
+/// The numerical D4 hessian is added to `hessian` (e.g. from a force field or electronic
+/// structure method), or used alone if `None`. Rotations are not projected out for periodic
+/// structures. See [`harmonic_analysis`] for the returned values.
+///
+/// # Arguments
+///
+/// * `hessian` - additional Cartesian hessian [natoms][3][natoms][3]
+/// * `masses` - atomic masses in atomic mass units [natoms] (for isotopes); standard atomic
+///   weights if `None`
+pub fn get_vibrations(
+    structure: &DFTD4Structure,
+    model: &DFTD4Model,
+    param: &DFTD4Param,
+    hessian: Option<&[f64]>,
+    masses: Option<&[f64]>,
+) -> (Vec<f64>, Vec<f64>) {
+    get_vibrations_f(structure, model, param, hessian, masses).unwrap()
+}
+
+/// Format normal modes in Molden format (failable)
+pub fn get_molden_f(
+    numbers: &[usize],
+    positions: &[f64],
+    frequencies: &[f64],
+    modes: &[f64],
+    multiplicity: Option<usize>,
+) -> Result<String, DFTD4Error> {
+    let natoms = numbers.len();
+    if positions.len() != 3 * natoms || modes.len() != 3 * natoms * frequencies.len() {
+        return Err(DFTD4Error::Rust(
+            "Inconsistent dimensions of positions, frequencies and modes".to_string(),
+        ));
+    }
+    let mut molden = String::from("[Molden Format]\n");
+    if let Some(multiplicity) = multiplicity {
+        molden += &format!("[Title]\nmultiplicity {}\n", multiplicity);
+    }
+    molden += "[FREQ]\n";
+    for freq in frequencies {
+        molden += &format!("{:12.4}\n", freq);
+    }
+    molden += "[FR-COORD]\n";
+    for (i, &z) in numbers.iter().enumerate() {
+        let symbol = get_element_symbol(z)
+            .ok_or_else(|| DFTD4Error::Rust(format!("Unsupported atomic number {}", z)))?;
+        let r = &positions[3 * i..3 * i + 3];
+        molden += &format!("{:<3}{:16.8}{:16.8}{:16.8}\n", symbol, r[0], r[1], r[2]);
+    }
+    molden += "[FR-NORM-COORD]\n";
+    for (m, mode) in modes.chunks(3 * natoms).enumerate() {
+        molden += &format!(" vibration {}\n", m + 1);
+        for d in mode.chunks(3) {
+            molden += &format!("{:16.8}{:16.8}{:16.8}\n", d[0], d[1], d[2]);
+        }
+    }
+    Ok(molden)
+}
+
+/// Format normal modes in Molden format
+///
+/// Positions are in Bohr. The returned string can be written to a `.molden` file.
+///
+/// # Arguments
+///
+/// * `frequencies` - frequencies in cm^-1 [nmodes]
+/// * `modes` - normal modes [nmodes][natoms][3]
+/// * `multiplicity` - spin multiplicity, recorded in the `[Title]` section if given, e.g. from
+///   `DFTD4Structure::get_multiplicity`
+pub fn get_molden(
+    numbers: &[usize],
+    positions: &[f64],
+    frequencies: &[f64],
+    modes: &[f64],
+    multiplicity: Option<usize>,
+) -> String {
+    get_molden_f(numbers, positions, frequencies, modes, multiplicity).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cartesian hessian of harmonic bonds `k/2 (r - r0)^2` at equilibrium
+    fn bond_hessian(positions: &[f64], bonds: &[(usize, usize)], k: f64) -> Vec<f64> {
+        let n = positions.len();
+        let mut hessian = vec![0.0; n * n];
+        for &(i, j) in bonds {
+            let v = [0, 1, 2].map(|a| positions[3 * i + a] - positions[3 * j + a]);
+            let r = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            for a in 0..3 {
+                for b in 0..3 {
+                    let h = k * v[a] * v[b] / (r * r);
+                    hessian[(3 * i + a) * n + 3 * i + b] += h;
+                    hessian[(3 * j + a) * n + 3 * j + b] += h;
+                    hessian[(3 * i + a) * n + 3 * j + b] -= h;
+                    hessian[(3 * j + a) * n + 3 * i + b] -= h;
+                }
+            }
+        }
+        hessian
+    }
+
+    #[test]
+    fn test_harmonic_analysis() {
+        // diatomic: single stretching mode with frequency sqrt(k / mu)
+        let positions = [0.1, -0.2, 0.3, 0.9, 0.5, 1.1];
+        let hessian = bond_hessian(&positions, &[(0, 1)], 0.5);
+        let masses = get_masses(&[1, 9]);
+        let (frequencies, modes) = harmonic_analysis(&positions, &hessian, &masses, true);
+        assert_eq!(frequencies.len(), 1);
+        let mu = masses[0] * masses[1] / (masses[0] + masses[1]);
+        let expected = (0.5 / (mu * AMUTOAU)).sqrt() * AUTORCM;
+        assert!((frequencies[0] - expected).abs() < 1e-6);
+        let norm = modes.iter().map(|x| x * x).sum::<f64>();
+        assert!((norm - 1.0).abs() < 1e-12);
+
+        // bent triatomic with two bonds: 3 modes, one of them (bending) has zero frequency
+        let positions = [0.0, 0.0, 0.0, 1.8, 0.0, 0.0, -0.5, 1.7, 0.0];
+        let hessian = bond_hessian(&positions, &[(0, 1), (0, 2)], 0.5);
+        let (frequencies, _) =
+            harmonic_analysis(&positions, &hessian, &get_masses(&[8, 1, 1]), true);
+        assert_eq!(frequencies.len(), 3);
+        assert!(frequencies[0].abs() < 1e-3);
+        assert!(frequencies[1] > 1000.0);
+        // without projection of rotations, 3N - 3 modes remain
+        let (frequencies, _) =
+            harmonic_analysis(&positions, &hessian, &get_masses(&[8, 1, 1]), false);
+        assert_eq!(frequencies.len(), 6);
+
+        // isotope substitution lowers the frequency
+        let positions = [0.0, 0.0, 0.0, 0.0, 0.0, 1.4];
+        let hessian = bond_hessian(&positions, &[(0, 1)], 0.37);
+        let (h2, _) = harmonic_analysis(&positions, &hessian, &[1.008, 1.008], true);
+        let (d2, _) = harmonic_analysis(&positions, &hessian, &[2.014, 2.014], true);
+        assert!((h2[0] / d2[0] - (2.014_f64 / 1.008).sqrt()).abs() < 1e-8);
+
+        let molden = get_molden(
+            &[1, 1],
+            &positions,
+            &h2,
+            &[0.0, 0.0, -1.0, 0.0, 0.0, 1.0],
+            None,
+        );
+        assert!(molden.starts_with("[Molden Format]\n[FREQ]\n"));
+        assert!(molden.contains("[FR-NORM-COORD]\n vibration 1\n"));
+    }
+}
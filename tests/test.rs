@@ -1,7 +1,58 @@
 use rest_dftd4::prelude::*;
 
+#[allow(clippy::map_clone)]
 mod test {
     use super::*;
+    use rest_dftd4::data::AATOAU;
+
+    /// Water dimer: atomic numbers [6] and positions in Angstrom [6][3]
+    fn water_dimer() -> ([usize; 6], [f64; 18]) {
+        #[rustfmt::skip]
+        let coords = [
+            -1.551007, -0.114520,  0.000000,
+            -1.934259,  0.762503,  0.000000,
+            -0.599677,  0.040712,  0.000000,
+             1.350625,  0.111469,  0.000000,
+             1.680398, -0.373741, -0.758561,
+             1.680398, -0.373741,  0.758561,
+        ];
+        ([8, 1, 1, 8, 1, 1], coords)
+    }
+
+    /// Ammonia dimer: atomic numbers [8] and positions in Bohr [8][3]
+    fn ammonia_dimer() -> ([usize; 8], [f64; 24]) {
+        #[rustfmt::skip]
+        let coords = [
+            -2.983345508575, -0.088082052767,  0.000000000000,
+             2.983345508575,  0.088082052767,  0.000000000000,
+            -4.079203605652,  0.257751166821,  1.529856562614,
+            -1.605268001556,  1.243804812431,  0.000000000000,
+            -4.079203605652,  0.257751166821, -1.529856562614,
+             4.079203605652, -0.257751166821, -1.529856562614,
+             1.605268001556, -1.243804812431,  0.000000000000,
+             4.079203605652, -0.257751166821,  1.529856562614,
+        ];
+        ([7, 7, 1, 1, 1, 1, 1, 1], coords)
+    }
+
+    /// Pairwise repulsion c12 / r^12 of argon clusters: energy and gradient [natoms][3]
+    fn argon_repulsion(x: &[f64]) -> Result<(f64, Vec<f64>), DFTD4Error> {
+        let c12 = 1.0e5;
+        let mut energy = 0.0;
+        let mut grad = vec![0.0; x.len()];
+        for i in 0..x.len() / 3 {
+            for j in 0..i {
+                let v = [0, 1, 2].map(|k| x[3 * i + k] - x[3 * j + k]);
+                let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                energy += c12 / r2.powi(6);
+                for k in 0..3 {
+                    grad[3 * i + k] -= 12.0 * c12 / r2.powi(7) * v[k];
+                    grad[3 * j + k] += 12.0 * c12 / r2.powi(7) * v[k];
+                }
+            }
+        }
+        Ok((energy, grad))
+    }
 
     #[test]
     fn test_get_properties() {
@@ -98,20 +149,8 @@ mod test {
 
     #[test]
     fn test_pairwise_dispersion() {
-        #[rustfmt::skip]
-        let coords = [
-            [-2.983345508575, -0.088082052767,  0.000000000000],
-            [ 2.983345508575,  0.088082052767,  0.000000000000],
-            [-4.079203605652,  0.257751166821,  1.529856562614],
-            [-1.605268001556,  1.243804812431,  0.000000000000],
-            [-4.079203605652,  0.257751166821, -1.529856562614],
-            [ 4.079203605652, -0.257751166821, -1.529856562614],
-            [ 1.605268001556, -1.243804812431,  0.000000000000],
-            [ 4.079203605652, -0.257751166821,  1.529856562614],
-        ];
-        let coords = coords.iter().flatten().map(|&x| x).collect::<Vec<f64>>(); // coordinates needs to be flatten
+        let (charges, coords) = ammonia_dimer();
         let natoms = 8;
-        let charges = [7, 7, 1, 1, 1, 1, 1, 1];
         let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
         let model = DFTD4Model::new(&structure);
         let params = DFTD4Param::load_rational_damping("TPSS", false);
@@ -119,4 +158,1395 @@ mod test {
         assert!((pairwise.0.iter().sum::<f64>() - -0.0023605238432524104).abs() < 1e-6);
         assert!((pairwise.1.iter().sum::<f64>() - 8.794562567135391e-08).abs() < 1e-12);
     }
+
+    #[test]
+    fn test_atomic_dispersion() {
+        let (charges, coords) = ammonia_dimer();
+        let natoms = 8;
+        let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("TPSS", true);
+        let (energy, grad, _) = get_dispersion(&structure, &model, &params, true, false);
+        let (e2, e3, e, grad_norm) = get_atomic_dispersion(&structure, &model, &params, true);
+        // atomic energies sum to total energy
+        assert!((e.iter().sum::<f64>() - energy).abs() < 1e-12);
+        for i in 0..natoms {
+            assert!((e2[i] + e3[i] - e[i]).abs() < 1e-14);
+        }
+        // atoms related by inversion symmetry are equivalent
+        assert!((e[0] - e[1]).abs() < 1e-10);
+        let grad = grad.unwrap();
+        let grad_norm = grad_norm.unwrap();
+        let norm = (grad[0].powi(2) + grad[1].powi(2) + grad[2].powi(2)).sqrt();
+        assert!((grad_norm[0] - norm).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_cutoff() {
+        use rest_dftd4::cutoff::*;
+        let (charges, coords) = ammonia_dimer();
+        let natoms = 8;
+        let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
+        let mut model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("TPSS", true);
+        let cutoff = model.get_cutoff();
+        assert_eq!(cutoff, DFTD4Cutoff::default());
+        assert_eq!(get_cutoff_images(&structure, &cutoff), [1, 1, 1]);
+        let (energy, _, _) = get_dispersion(&structure, &model, &params, false, false);
+        let energies = get_cutoff_convergence(&structure, &mut model, &params, &[1.0]);
+        assert!((energies[0] - energy).abs() < 1e-14);
+
+        let short = DFTD4Cutoff {
+            disp2: 5.0,
+            ..cutoff
+        };
+        // the C API of libdftd4 only accepts the default cutoffs
+        #[cfg(not(feature = "pure-rust"))]
+        assert!(model.set_cutoff_f(short).is_err());
+
+        // cutoffs other than the defaults (only supported by the pure-Rust implementation)
+        #[cfg(feature = "pure-rust")]
+        {
+            // short two-body cutoff drops intermolecular pairs; large cutoffs change nothing
+            let energies = get_cutoff_convergence(&structure, &mut model, &params, &[0.1, 2.0]);
+            assert!(energies[0] > energy);
+            assert!((energies[1] - energy).abs() < 1e-10);
+            assert_eq!(model.get_cutoff(), cutoff);
+            model.set_cutoff(short);
+            let (short_energy, _, _) = get_dispersion(&structure, &model, &params, false, false);
+            assert!(short_energy > energy);
+            assert!(model
+                .set_cutoff_f(DFTD4Cutoff { cn: 0.0, ..cutoff })
+                .is_err());
+
+            // periodic cell: energy converges with cutoffs, gradient and virial are consistent
+            {
+                use rest_dftd4::validation::*;
+                #[rustfmt::skip]
+                let lattice = [
+                    14.0,  0.0,  0.0,
+                     1.0, 13.0,  0.0,
+                     0.0,  0.5, 15.0,
+                ];
+                let structure =
+                    DFTD4Structure::new(natoms, &charges, &coords, None, Some(&lattice), None);
+                let mut model = DFTD4Model::new(&structure);
+                model.set_cutoff(DFTD4Cutoff {
+                    cn: 20.0,
+                    disp2: 30.0,
+                    disp3: 10.0,
+                });
+                let energies =
+                    get_cutoff_convergence(&structure, &mut model, &params, &[0.5, 1.0, 1.5]);
+                assert!(energies.iter().all(|&e| e < 0.0));
+                assert!((energies[2] - energies[1]).abs() < (energies[1] - energies[0]).abs());
+                let report = check_derivatives(&structure, &model, &params, 1e-5);
+                assert!(report.max_gradient_error() < 1e-8);
+                assert!(report.max_sigma_error() < 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_r4r2() {
+        // H, C, N, O, Ne, Na, Ar, Ca, Fe, Zn, Kr; reference values of dftd4
+        let numbers = [1, 6, 7, 8, 10, 11, 18, 20, 26, 30, 36];
+        #[rustfmt::skip]
+        let r4r2_ref = [
+            2.00734898, 3.10492822, 2.71175247, 2.59361680, 2.21522516, 6.58585536,
+            3.44677275, 7.07623947, 5.58415561, 5.09817828, 4.31176815,
+        ];
+        let r4r2 = get_r4r2(&numbers);
+        for (r4r2, r4r2_ref) in r4r2.iter().zip(r4r2_ref) {
+            assert!((r4r2 - r4r2_ref).abs() < 1e-5 * r4r2_ref);
+        }
+        assert!(get_r4r2_f(&[0]).is_err());
+    }
+
+    #[test]
+    fn test_dispersion_coefficients() {
+        use rest_dftd4::polarizability::*;
+        // water
+        #[rustfmt::skip]
+        let coords = [
+             0.000000,  0.000000,  0.221665,
+             0.000000,  1.430901, -0.886659,
+             0.000000, -1.430901, -0.886659,
+        ];
+        let numbers = [8, 1, 1];
+        let structure = DFTD4Structure::new(3, &numbers, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+        let coefficients = get_dispersion_coefficients(&structure, &model);
+        let (_, _, c6, alpha) = get_properties(&structure, &model);
+        assert!((coefficients.molecular_c6 - c6.iter().sum::<f64>()).abs() < 1e-10);
+        assert!((coefficients.molecular_alpha - alpha.iter().sum::<f64>()).abs() < 1e-10);
+        // C8 / C6 = 3 r4r2[i] r4r2[j]
+        let r4r2 = get_r4r2(&numbers);
+        for i in 0..3 {
+            for j in 0..3 {
+                let ratio = coefficients.c8[3 * i + j] / coefficients.c6[3 * i + j];
+                assert!((ratio - 3.0 * r4r2[i] * r4r2[j]).abs() < 1e-10 * ratio);
+            }
+        }
+        assert!(coefficients.molecular_c8 > coefficients.molecular_c6);
+        // combination rule reproduces molecular C6 of water with itself
+        let c6 = get_intermolecular_c6(&coefficients, &coefficients);
+        assert!((c6 - coefficients.molecular_c6).abs() < 0.05 * coefficients.molecular_c6);
+
+        // dynamic polarizabilities (only available in the pure-Rust implementation)
+        #[cfg(feature = "pure-rust")]
+        {
+            use rest_dftd4::native::{FREQ, NFREQ};
+            let aiw = get_dynamic_polarizabilities(&structure, &model);
+            for i in 0..3 {
+                let aiw_i = &aiw[i * NFREQ..(i + 1) * NFREQ];
+                assert!((aiw_i[0] - alpha[i]).abs() < 1e-10);
+                for j in 0..3 {
+                    let c6_ij = casimir_polder(&FREQ, aiw_i, &aiw[j * NFREQ..(j + 1) * NFREQ]);
+                    assert!((c6_ij - coefficients.c6[3 * i + j]).abs() < 1e-8 * c6_ij);
+                }
+            }
+            let c6 = get_casimir_polder_c6(&structure, &model, &structure, &model);
+            assert!((c6 - coefficients.molecular_c6).abs() < 1e-8 * c6);
+        }
+        #[cfg(not(feature = "pure-rust"))]
+        assert!(get_dynamic_polarizabilities_f(&structure, &model).is_err());
+    }
+
+    #[test]
+    fn test_fragment_dispersion() {
+        let (charges, coords) = ammonia_dimer();
+        let natoms = 8;
+        let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("TPSS", true);
+        // ammonia dimer
+        let fragments = vec![vec![0, 2, 3, 4], vec![1, 5, 6, 7]];
+        let (interaction, energy, fragment_energies, fragment_pairs) =
+            get_fragment_dispersion(&structure, &model, &params, &fragments, None);
+        assert!((interaction - (energy - fragment_energies.iter().sum::<f64>())).abs() < 1e-14);
+        assert!((fragment_energies[0] - fragment_energies[1]).abs() < 1e-10);
+        assert!(interaction < 0.0);
+        // pairwise sums are symmetric, and sum to total energy
+        assert!((fragment_pairs[1] - fragment_pairs[2]).abs() < 1e-14);
+        assert!((fragment_pairs.iter().sum::<f64>() - energy).abs() < 1e-12);
+
+        // fragments keep the cutoffs and custom charges of the model (only supported by the
+        // pure-Rust implementation)
+        #[cfg(feature = "pure-rust")]
+        {
+            let q = [-0.3, -0.3, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1];
+            let mut model = DFTD4Model::new_with_charges(&structure, &q);
+            let cutoff = DFTD4Cutoff {
+                cn: 2.5,
+                disp3: 3.0,
+                ..model.get_cutoff()
+            };
+            model.set_cutoff(cutoff);
+            let (_, energy_custom, fragment_energies_custom, _) =
+                get_fragment_dispersion(&structure, &model, &params, &fragments, None);
+            let (energy_ref, _, _) = get_dispersion(&structure, &model, &params, false, false);
+            assert_eq!(energy_custom, energy_ref);
+            for (ifrag, fragment) in fragments.iter().enumerate() {
+                let fragment_structure = get_fragment_structure(&structure, fragment, None, None);
+                let q = fragment.iter().map(|&i| q[i]).collect::<Vec<f64>>();
+                let mut fragment_model = DFTD4Model::new_with_charges(&fragment_structure, &q);
+                fragment_model.set_cutoff(cutoff);
+                let (energy_ref, _, _) =
+                    get_dispersion(&fragment_structure, &fragment_model, &params, false, false);
+                assert!((fragment_energies_custom[ifrag] - energy_ref).abs() < 1e-14);
+                assert!((fragment_energies_custom[ifrag] - fragment_energies[ifrag]).abs() > 1e-8);
+            }
+        }
+
+        // fragments should be a partition of atoms
+        let fragments = vec![vec![0, 2, 3, 4], vec![1, 5, 6]];
+        assert!(get_fragment_dispersion_f(&structure, &model, &params, &fragments, None).is_err());
+    }
+
+    #[test]
+    fn test_check_derivatives() {
+        use rest_dftd4::validation::*;
+        let (charges, coords) = ammonia_dimer();
+        let natoms = 8;
+        let params = DFTD4Param::load_rational_damping("TPSS", true);
+        // charged molecule
+        let structure = DFTD4Structure::new(natoms, &charges, &coords, Some(1.0), None, None);
+        let model = DFTD4Model::new(&structure);
+        let report = check_derivatives(&structure, &model, &params, 1e-5);
+        assert!(report.max_gradient_error() < 1e-8);
+        assert!(report.max_sigma_error() < 1e-8);
+        // structure is restored
+        assert_eq!(structure.get_positions(), coords);
+
+        // periodic
+        {
+            #[rustfmt::skip]
+            let lattice = [
+                14.0,  0.0,  0.0,
+                 1.0, 13.0,  0.0,
+                 0.0,  0.5, 15.0,
+            ];
+            let structure =
+                DFTD4Structure::new(natoms, &charges, &coords, None, Some(&lattice), None);
+            let model = DFTD4Model::new(&structure);
+            let report = check_derivatives(&structure, &model, &params, 1e-5);
+            assert!(report.max_gradient_error() < 1e-8);
+            assert!(report.max_sigma_error() < 1e-7);
+            assert_eq!(structure.get_lattice().unwrap(), lattice);
+        }
+    }
+
+    #[test]
+    fn test_lattice() {
+        use rest_dftd4::lattice::*;
+        let numbers = [6, 6];
+        let positions = [0.0, 0.0, 0.0, 1.68, 1.68, 1.68];
+        #[rustfmt::skip]
+        let lattice = [
+            0.0, 3.37, 3.37,
+            3.37, 0.0, 3.37,
+            3.37, 3.37, 0.0,
+        ];
+        // invalid periodic mask and singular lattice
+        let singular = [3.37, 0.0, 0.0, 0.0, 3.37, 0.0, 3.37, 3.37, 0.0];
+        assert!(DFTD4Structure::new_f(
+            2,
+            &numbers,
+            &positions,
+            None,
+            Some(&lattice),
+            Some(&[true])
+        )
+        .is_err());
+        assert!(
+            DFTD4Structure::new_f(2, &numbers, &positions, None, Some(&singular), None).is_err()
+        );
+        // left-handed and badly skewed lattices
+        let mut left_handed = lattice;
+        left_handed.swap(0, 3);
+        left_handed.swap(1, 4);
+        left_handed.swap(2, 5);
+        let skewed = [3.37, 0.0, 0.0, 0.0, 3.37, 0.0, 3.37, 3.37, 0.2];
+        for lattice in [left_handed, skewed] {
+            assert!(
+                DFTD4Structure::new_f(2, &numbers, &positions, None, Some(&lattice), None).is_err()
+            );
+        }
+        let parameters = get_cell_parameters(&lattice);
+        assert!((parameters[3] - 60.0).abs() < 1e-10);
+
+        // supercell of diamond
+        {
+            let structure =
+                DFTD4Structure::new(2, &numbers, &positions, None, Some(&lattice), None);
+            let params = DFTD4Param::load_rational_damping("PBE", true);
+            let model = DFTD4Model::new(&structure);
+            let (energy, _, _) = get_dispersion(&structure, &model, &params, false, false);
+            // positions outside of the cell are wrapped, and kept as given
+            let shifted = [0.0, 0.0, 0.0, 1.68 + 6.74, 1.68 - 3.37, 1.68 + 3.37];
+            let outside = DFTD4Structure::new(2, &numbers, &shifted, None, Some(&lattice), None);
+            assert_eq!(outside.get_positions(), shifted);
+            let outside_model = DFTD4Model::new(&outside);
+            let (energy_outside, _, _) =
+                get_dispersion(&outside, &outside_model, &params, false, false);
+            assert!((energy_outside - energy).abs() < 1e-10);
+            let supercell = get_supercell(&structure, &[[2, 0, 0], [0, 1, 0], [1, 1, 1]]);
+            assert_eq!(supercell.get_natoms(), 4);
+            let model = DFTD4Model::new(&supercell);
+            let (energy2, _, _) = get_dispersion(&supercell, &model, &params, false, false);
+            assert!((energy2 - 2.0 * energy).abs() < 1e-8);
+            // supercell models keep the cutoffs and custom charges of the unit cell model (only
+            // supported by the pure-Rust implementation)
+            #[cfg(feature = "pure-rust")]
+            {
+                let mut cell_model = DFTD4Model::new_with_charges(&structure, &[0.1, -0.1]);
+                cell_model.set_cutoff(DFTD4Cutoff {
+                    disp3: 20.0,
+                    ..cell_model.get_cutoff()
+                });
+                let (energy, _, _) = get_dispersion(&structure, &cell_model, &params, false, false);
+                let supercell_model = get_supercell_model(&structure, &cell_model, &supercell);
+                assert_eq!(supercell_model.get_cutoff(), cell_model.get_cutoff());
+                let (energy2, _, _) =
+                    get_dispersion(&supercell, &supercell_model, &params, false, false);
+                assert!((energy2 - 2.0 * energy).abs() < 1e-8);
+            }
+            assert!(get_supercell_model_f(&supercell, &model, &structure).is_err());
+            // reduced lattice gives the same energy
+            let reduced = niggli_reduce(&supercell.get_lattice().unwrap());
+            let wrapped = wrap_positions(&supercell.get_positions(), &reduced, None);
+            supercell.update(&wrapped, Some(&reduced));
+            let (energy3, _, _) = get_dispersion(&supercell, &model, &params, false, false);
+            assert!((energy3 - energy2).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_low_dimensional() {
+        use rest_dftd4::lattice::*;
+        // graphene sheet, a = 2.46 Angstrom
+        let a = 4.648_726;
+        let graphene = [6, 6];
+        let graphene_positions = [0.0, 0.0, 0.0, 0.0, a / 3.0_f64.sqrt(), 0.0];
+        #[rustfmt::skip]
+        let graphene_lattice = [
+            a, 0.0, 0.0,
+            -0.5 * a, 0.5 * 3.0_f64.sqrt() * a, 0.0,
+            0.0, 0.0, 0.0,
+        ];
+        let slab = [true, true, false];
+
+        // invalid periodic mask
+        let mut skewed = get_vacuum_lattice(&graphene_lattice, &slab, 30.0);
+        skewed[6] = 1.0;
+        let new_graphene = |lattice: &[f64], periodic: &[bool]| {
+            DFTD4Structure::new_f(
+                2,
+                &graphene,
+                &graphene_positions,
+                None,
+                Some(lattice),
+                Some(periodic),
+            )
+        };
+        assert!(new_graphene(&skewed, &[true, true]).is_err());
+        assert!(new_graphene(&skewed, &[true, true, false, false]).is_err());
+        // vacuum vector not orthogonal to periodic vectors is made orthogonal, keeping its length
+        let structure = new_graphene(&skewed, &slab).unwrap();
+        let lattice = structure.get_lattice().unwrap();
+        assert!(lattice[6].abs() < 1e-12 && lattice[7].abs() < 1e-12);
+        assert!((lattice[8] - 901.0_f64.sqrt()).abs() < 1e-12);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let energy = |structure: &DFTD4Structure| {
+            let model = DFTD4Model::new(structure);
+            get_dispersion(structure, &model, &params, false, false).0
+        };
+        let orthogonal = get_vacuum_lattice(&graphene_lattice, &slab, 901.0_f64.sqrt());
+        let reference = new_graphene(&orthogonal, &slab).unwrap();
+        assert!((energy(&structure) - energy(&reference)).abs() < 1e-12);
+
+        // 1D and 2D periodicity: no upstream reference energies are available for these systems,
+        // so results are checked for consistency (vacuum, derivatives and supercells)
+        {
+            use rest_dftd4::validation::*;
+            // polyethylene chain along x, c = 2.55 Angstrom
+            let polymer = [6, 6, 1, 1, 1, 1];
+            #[rustfmt::skip]
+            let polymer_positions = [
+                0.000000,  0.000000,  0.000000,
+                2.409875,  0.793701,  0.000000,
+                0.000000, -1.190551,  1.681871,
+                0.000000, -1.190551, -1.681871,
+                2.409875,  1.984252,  1.681871,
+                2.409875,  1.984252, -1.681871,
+            ];
+            let polymer_lattice = [4.81975, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+            let wire = [true, false, false];
+            let params = DFTD4Param::load_rational_damping("PBE", true);
+            let systems = [
+                (
+                    &graphene[..],
+                    &graphene_positions[..],
+                    graphene_lattice,
+                    slab,
+                ),
+                (&polymer[..], &polymer_positions[..], polymer_lattice, wire),
+            ];
+            for (numbers, positions, lattice, periodic) in systems {
+                let natoms = numbers.len();
+                let energy_at = |vacuum: f64| {
+                    let lattice = get_vacuum_lattice(&lattice, &periodic, vacuum);
+                    let structure = DFTD4Structure::new(
+                        natoms,
+                        numbers,
+                        positions,
+                        None,
+                        Some(&lattice),
+                        Some(&periodic),
+                    );
+                    let model = DFTD4Model::new(&structure);
+                    let (energy, _, sigma) =
+                        get_dispersion(&structure, &model, &params, true, true);
+                    (energy, sigma.unwrap(), structure, model)
+                };
+                // converged with vacuum; EEQ charges carry a small dependence on the vacuum
+                // length through Ewald summation over the full lattice
+                let (energy, sigma, structure, model) = energy_at(30.0);
+                let (energy2, _, _, _) = energy_at(60.0);
+                assert!(energy < 0.0);
+                assert!((energy - energy2).abs() < 1e-8);
+                // no virial along vacuum directions (z, and y for the wire)
+                assert!(sigma[2].abs() < 1e-14 && sigma[8].abs() < 1e-14);
+                if !periodic[1] {
+                    assert!(sigma[4].abs() < 1e-14);
+                }
+                let report = check_derivatives(&structure, &model, &params, 1e-5);
+                assert!(report.max_gradient_error() < 1e-8);
+                assert!(report.max_sigma_error() < 1e-7);
+                // supercell along periodic directions
+                let supercell = get_supercell(&structure, &[[2, 0, 0], [0, 1, 0], [0, 0, 1]]);
+                let model = DFTD4Model::new(&supercell);
+                let (energy3, _, _) = get_dispersion(&supercell, &model, &params, false, false);
+                assert!((energy3 - 2.0 * energy).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_optimize_structure() {
+        use rest_dftd4::optimizer::*;
+        // argon trimer, with D4 dispersion and pairwise repulsion c12 / r^12
+        #[rustfmt::skip]
+        let coords = [
+            0.0, 0.0, 0.0,
+            7.5, 0.0, 0.0,
+            3.0, 6.5, 0.0,
+        ];
+        let natoms = 3;
+        let charges = [18, 18, 18];
+        let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let mut repulsion = argon_repulsion;
+        let config = OptimizerConfig {
+            energy_tol: 1.0e-10,
+            max_force_tol: 1.0e-7,
+            rms_force_tol: 1.0e-7,
+            ..Default::default()
+        };
+        let result = optimize_structure(&structure, &model, &params, &config, Some(&mut repulsion));
+        assert!(result.converged);
+        // structure is updated in place, and optimized trimer is equilateral
+        assert_eq!(structure.get_positions(), result.positions);
+        let x = &result.positions;
+        let dist = |i: usize, j: usize| {
+            (0..3)
+                .map(|k| (x[3 * i + k] - x[3 * j + k]).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        };
+        assert!((dist(0, 1) - dist(1, 2)).abs() < 1e-3);
+        assert!((dist(0, 1) - dist(0, 2)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_optimize_cell() {
+        use rest_dftd4::lattice::*;
+        use rest_dftd4::optimizer::*;
+        // fcc argon, with D4 dispersion and pairwise repulsion c12 / r^12
+        let a = 10.5;
+        #[rustfmt::skip]
+        let lattice = [
+            0.0, 0.5 * a, 0.5 * a,
+            0.5 * a, 0.0, 0.5 * a,
+            0.5 * a, 0.5 * a, 0.0,
+        ];
+        let structure = DFTD4Structure::new(1, &[18], &[0.0; 3], None, Some(&lattice), None);
+        let model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let c12 = 4.0e6;
+        let repulsion = |lattice: &[f64]| {
+            let (mut energy, mut sigma) = (0.0, vec![0.0; 9]);
+            for n in
+                (-4..=4).flat_map(|i| (-4..=4).flat_map(move |j| (-4..=4).map(move |k| [i, j, k])))
+            {
+                let v = [0, 1, 2].map(|b| {
+                    (0..3)
+                        .map(|a| n[a] as f64 * lattice[3 * a + b])
+                        .sum::<f64>()
+                });
+                let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                if !(1e-8..=400.0).contains(&r2) {
+                    continue;
+                }
+                // half of each pair of the single atom with its images
+                energy += 0.5 * c12 / r2.powi(6);
+                let de = -6.0 * c12 / r2.powi(7);
+                for a in 0..3 {
+                    for b in 0..3 {
+                        sigma[a + 3 * b] += de * v[a] * v[b];
+                    }
+                }
+            }
+            (energy, sigma)
+        };
+        let mut extra = |x: &[f64], lattice: &[f64]| {
+            let (energy, sigma) = repulsion(lattice);
+            Ok((energy, vec![0.0; x.len()], sigma))
+        };
+        let config = OptimizerConfig {
+            energy_tol: 1.0e-10,
+            max_force_tol: 1.0e-6,
+            rms_force_tol: 1.0e-6,
+            max_step: 0.1,
+            ..Default::default()
+        };
+        let result =
+            optimize_structure_cell(&structure, &model, &params, 0.0, &config, Some(&mut extra));
+        assert!(result.converged);
+        // structure is updated in place, and the cell stays fcc
+        let optimized = result.lattice.unwrap();
+        assert_eq!(structure.get_lattice().unwrap(), optimized);
+        let parameters = get_cell_parameters(&optimized);
+        for k in 0..3 {
+            assert!((parameters[k] - parameters[0]).abs() < 1e-4);
+            assert!((parameters[k + 3] - 60.0).abs() < 1e-3);
+        }
+        assert!((parameters[0] - 0.5 * a * 2.0_f64.sqrt()).abs() > 1e-2);
+        // virial of dispersion balances the repulsion
+        let (_, _, sigma) = get_dispersion(&structure, &model, &params, false, true);
+        let (_, extra_sigma) = repulsion(&optimized);
+        let sigma = sigma.unwrap();
+        assert!((0..9).all(|k| (sigma[k] + extra_sigma[k]).abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_dynamics() {
+        use rest_dftd4::dynamics::*;
+        // argon trimer, with D4 dispersion and pairwise repulsion c12 / r^12
+        #[rustfmt::skip]
+        let coords = [
+            0.0, 0.0, 0.0,
+            7.0, 0.0, 0.0,
+            3.5, 6.0, 0.0,
+        ];
+        let natoms = 3;
+        let charges = [18, 18, 18];
+        let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let mut repulsion = argon_repulsion;
+        let velocities = get_initial_velocities(&[39.948; 3], 20.0, 7);
+        let config = MDConfig {
+            nsteps: 200,
+            trajectory_interval: 20,
+            ..Default::default()
+        };
+        let result = run_structure_md(
+            &structure,
+            &model,
+            &params,
+            Some(&velocities),
+            None,
+            &config,
+            Some(&mut repulsion),
+        );
+        // structure is updated in place, and total energy is conserved
+        assert_eq!(structure.get_positions(), result.positions);
+        let total = |f: &MDFrame| f.potential + f.kinetic;
+        for frame in &result.trajectory {
+            assert!((total(frame) - total(&result.trajectory[0])).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_vibrations() {
+        use rest_dftd4::vibration::*;
+        // argon trimer, with D4 hessian only
+        #[rustfmt::skip]
+        let coords = [
+            0.0, 0.0, 0.0,
+            7.0, 0.0, 0.0,
+            3.5, 6.0, 0.0,
+        ];
+        let natoms = 3;
+        let charges = [18, 18, 18];
+        let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let (frequencies, modes) = get_vibrations(&structure, &model, &params, None, None);
+        // 3N - 6 modes, normalized
+        assert_eq!(frequencies.len(), 3);
+        assert_eq!(modes.len(), 3 * 3 * natoms);
+        for mode in modes.chunks(3 * natoms) {
+            assert!((mode.iter().map(|x| x * x).sum::<f64>() - 1.0).abs() < 1e-10);
+        }
+        // heavier isotopes scale frequencies by sqrt of mass ratio
+        let masses = get_masses(&charges)
+            .iter()
+            .map(|m| 2.0 * m)
+            .collect::<Vec<f64>>();
+        let (heavy, _) = get_vibrations(&structure, &model, &params, None, Some(&masses));
+        for (f, h) in frequencies.iter().zip(&heavy) {
+            assert!((f / h - 2.0_f64.sqrt()).abs() < 1e-8);
+        }
+        let multiplicity = Some(structure.get_multiplicity());
+        let molden = get_molden(&charges, &coords, &frequencies, &modes, multiplicity);
+        assert!(molden.contains("[Title]\nmultiplicity 1\n[FREQ]\n"));
+        assert!(molden.contains("[FR-COORD]\nAr "));
+    }
+
+    #[test]
+    fn test_eeq_charges() {
+        #[rustfmt::skip]
+        let coords = [
+            [ 2.79274810283778,  3.82998228828316, -2.79287054959216],
+            [-1.43447454186833,  0.43418729987882,  5.53854345129809],
+            [-3.26268343665218, -2.50644032426151, -1.56631149351046],
+            [ 2.14548759959147, -0.88798018953965, -2.24592534506187],
+            [-4.30233097423181, -3.93631518670031, -0.48930754109119],
+            [ 0.06107643564880, -3.82467931731366, -2.22333344469482],
+            [ 0.41168550401858,  0.58105573172764,  5.56854609916143],
+            [ 4.41363836635653,  3.92515871809283,  2.57961724984000],
+            [ 1.33707758998700,  1.40194471661647,  1.97530004949523],
+            [ 3.08342709834868,  1.72520024666801, -4.42666116106828],
+            [-3.02346932078505,  0.04438199934191, -0.27636197425010],
+            [ 1.11508390868455, -0.97617412809198,  6.25462847718180],
+            [ 0.61938955433011,  2.17903547389232, -6.21279842416963],
+            [-2.67491681346835,  3.00175899761859,  1.05038813614845],
+            [-4.13181080289514, -2.34226739863660, -3.44356159392859],
+            [ 2.85007173009739, -2.64884892757600,  0.71010806424206],
+        ];
+        let coords = coords.iter().flatten().copied().collect::<Vec<f64>>(); // coordinates needs to be flatten
+        let natoms = 16;
+        let charges = [1, 1, 6, 5, 1, 15, 8, 17, 13, 15, 5, 1, 9, 15, 1, 15];
+        let (q, dqdr) = get_eeq_charges(natoms, &charges, &coords, None, true);
+        assert!(q.iter().sum::<f64>().abs() < 1e-10);
+        // EEQ charges of pure-Rust implementation should be the same to those of libdftd4, which
+        // uses the EEQ 2019 parameters of multicharge
+        #[cfg(not(feature = "pure-rust"))]
+        {
+            let structure = DFTD4Structure::new(natoms, &charges, &coords, None, None, None);
+            let model = DFTD4Model::new(&structure);
+            let (_, q_ref, _, _) = get_properties(&structure, &model);
+            for i in 0..natoms {
+                assert!((q[i] - q_ref[i]).abs() < 1e-8);
+            }
+        }
+        // derivatives of charges should sum to zero, since total charge is fixed
+        let dqdr = dqdr.unwrap();
+        for j in 0..3 * natoms {
+            let sum = (0..natoms).map(|i| dqdr[i * 3 * natoms + j]).sum::<f64>();
+            assert!(sum.abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_multiplicity() {
+        // water and its cation
+        #[rustfmt::skip]
+        let coords = [
+             0.000000,  0.000000,  0.221665,
+             0.000000,  1.430901, -0.886659,
+             0.000000, -1.430901, -0.886659,
+        ];
+        let numbers = [8, 1, 1];
+        let new = |charge, multiplicity| {
+            DFTD4Structure::new_with_multiplicity_f(
+                3,
+                &numbers,
+                &coords,
+                charge,
+                multiplicity,
+                None,
+                None,
+            )
+        };
+        let singlet = DFTD4Structure::new(3, &numbers, &coords, None, None, None);
+        assert_eq!(singlet.get_multiplicity(), 1);
+        assert!(new(None, Some(2)).is_err());
+        assert!(new(None, Some(0)).is_err());
+        assert!(new(None, Some(13)).is_err());
+        let structure = new(None, Some(3)).unwrap();
+        assert_eq!(structure.get_multiplicity(), 3);
+        assert_eq!(new(Some(1.0), None).unwrap().get_multiplicity(), 2);
+        assert!(new(Some(1.0), Some(1)).is_err());
+        assert!(new(Some(0.5), Some(1)).is_err());
+        // multiplicity of fragments is given, and replicated in supercells
+        use rest_dftd4::fragment::*;
+        use rest_dftd4::lattice::*;
+        let fragment = get_fragment_structure(&structure, &[0, 1, 2], Some(1.0), Some(4));
+        assert_eq!(fragment.get_multiplicity(), 4);
+        assert!(get_fragment_structure_f(&structure, &[0, 1, 2], None, Some(2)).is_err());
+        let lattice = [20.0, 0.0, 0.0, 0.0, 20.0, 0.0, 0.0, 0.0, 20.0];
+        let cell = DFTD4Structure::new_with_multiplicity(
+            3,
+            &numbers,
+            &coords,
+            None,
+            Some(3),
+            Some(&lattice),
+            None,
+        );
+        let supercell = get_supercell(&cell, &[[2, 0, 0], [0, 1, 0], [0, 0, 1]]);
+        assert_eq!(supercell.get_multiplicity(), 5);
+        let cell = DFTD4Structure::new(3, &numbers, &coords, None, Some(&lattice), None);
+        let supercell = get_supercell(&cell, &[[2, 0, 0], [0, 1, 0], [0, 0, 1]]);
+        assert_eq!(supercell.get_multiplicity(), 1);
+
+        // REST interface reports invalid numbers of unpaired electrons
+        let rest = |uhf: i32| {
+            let (mut energy, mut gradient, mut sigma) = (0.0, [0.0; 9], [0.0; 9]);
+            let result = unsafe {
+                rest_dftd4::rest_interface::calc_dftd4_rest_(
+                    [8, 1, 1].as_ptr(),
+                    &3,
+                    coords.as_ptr(),
+                    std::ptr::null(),
+                    &uhf,
+                    "PBE".as_ptr() as *const std::ffi::c_char,
+                    &3,
+                    &mut energy,
+                    gradient.as_mut_ptr(),
+                    sigma.as_mut_ptr(),
+                )
+            };
+            result.map(|_| energy)
+        };
+        assert!(rest(-1).is_err());
+        assert!(rest(1).is_err());
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let (energy, _, _) = get_dispersion(
+            &structure,
+            &DFTD4Model::new(&structure),
+            &params,
+            false,
+            false,
+        );
+        assert!((rest(2).unwrap() - energy).abs() < 1e-14);
+
+        // multiplicity of the structure is used for electronic entropy, unless given in config
+        use rest_dftd4::thermo::*;
+        let model = DFTD4Model::new(&structure);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let mut config = ThermoConfig::default();
+        let triplet = get_thermochemistry(&structure, &model, &params, None, None, &config);
+        let singlet = get_thermochemistry(&singlet, &model, &params, None, None, &config);
+        assert_eq!((triplet.multiplicity, singlet.multiplicity), (3, 1));
+        let s_elec = 3.0_f64.ln() * rest_dftd4::data::BOLTZMANN;
+        assert!((triplet.entropy - singlet.entropy - s_elec).abs() < 1e-12);
+        config.multiplicity = Some(1);
+        let explicit = get_thermochemistry(&structure, &model, &params, None, None, &config);
+        assert_eq!(explicit.multiplicity, 1);
+        assert!((explicit.entropy - singlet.entropy).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_custom_charges() {
+        // water
+        #[rustfmt::skip]
+        let coords = [
+             0.000000,  0.000000,  0.221665,
+             0.000000,  1.430901, -0.886659,
+             0.000000, -1.430901, -0.886659,
+        ];
+        let numbers = [8, 1, 1];
+        let structure = DFTD4Structure::new(3, &numbers, &coords, None, None, None);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let mulliken = [-0.6, 0.3, 0.3];
+        assert!(DFTD4Model::new_with_charges_f(&structure, &mulliken[..2]).is_err());
+
+        // custom charges (only supported by the pure-Rust implementation)
+        #[cfg(feature = "pure-rust")]
+        {
+            let model = DFTD4Model::new_with_charges(&structure, &mulliken);
+            let (_, q, _, _) = get_properties(&structure, &model);
+            assert_eq!(q, mulliken);
+            let dedq = get_charge_derivatives(&structure, &model, &params);
+            assert_eq!(dedq.len(), 3);
+            // EEQ charges are restored
+            let mut model = model;
+            model.set_charges(None);
+            let (_, q, _, _) = get_properties(&structure, &model);
+            let (q_ref, _) = get_eeq_charges(3, &numbers, &coords, None, false);
+            assert!(q.iter().zip(&q_ref).all(|(a, b)| (a - b).abs() < 1e-12));
+
+            // SC-D4 with the REST interface
+            let (energy, dedq_scd4) =
+                rest_dftd4::scd4::get_scd4_energy(&structure, &mut model, &params, &mulliken);
+            assert_eq!(dedq_scd4, dedq);
+            let (mut energy_rest, mut dedq_rest) = (0.0, [0.0; 3]);
+            unsafe {
+                rest_dftd4::rest_interface::calc_dftd4_scd4_rest_(
+                    [8, 1, 1].as_ptr(),
+                    &3,
+                    coords.as_ptr(),
+                    std::ptr::null(),
+                    mulliken.as_ptr(),
+                    "PBE".as_ptr() as *const std::ffi::c_char,
+                    &3,
+                    &mut energy_rest,
+                    dedq_rest.as_mut_ptr(),
+                )
+                .unwrap();
+            }
+            assert!((energy_rest - energy).abs() < 1e-14);
+            assert_eq!(dedq_rest.to_vec(), dedq);
+            // dE/dq by finite differences in charges
+            let step = 1e-5;
+            for i in 0..3 {
+                let mut energy_at = |shift: f64| {
+                    let mut charges = mulliken;
+                    charges[i] += shift;
+                    rest_dftd4::scd4::get_scd4_energy(&structure, &mut model, &params, &charges).0
+                };
+                let numerical = (energy_at(step) - energy_at(-step)) / (2.0 * step);
+                assert!((numerical - dedq[i]).abs() < 1e-9);
+            }
+        }
+        #[cfg(not(feature = "pure-rust"))]
+        {
+            assert!(DFTD4Model::new_with_charges_f(&structure, &mulliken).is_err());
+            let mut model = DFTD4Model::new(&structure);
+            assert!(get_charge_derivatives_f(&structure, &model, &params).is_err());
+            let scd4 =
+                rest_dftd4::scd4::get_scd4_energy_f(&structure, &mut model, &params, &mulliken);
+            assert!(scd4.is_err());
+        }
+    }
+
+    #[test]
+    fn test_ghost_atoms() {
+        // water dimer, with the second monomer as ghost atoms
+        let (numbers, coords) = water_dimer();
+        let coords = coords.map(|x| x * AATOAU);
+        let ghosts = [false, false, false, true, true, true];
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+
+        let dimer =
+            DFTD4Structure::new_with_ghosts(6, &numbers, &coords, None, None, None, Some(&ghosts));
+        assert_eq!(dimer.get_natoms(), 6);
+        assert_eq!(dimer.get_nelectrons(), 10.0);
+        let model = DFTD4Model::new(&dimer);
+        let (energy, grad, _) = get_dispersion(&dimer, &model, &params, true, false);
+        let grad = grad.unwrap();
+        assert_eq!(grad.len(), 18);
+        let (pair2, pair3) = get_pairwise_dispersion(&dimer, &model, &params);
+        assert_eq!(pair2.len(), 36);
+        let (cn, _, c6, _) = get_properties(&dimer, &model);
+
+        // ghost atoms contribute nothing: energy of the monomer
+        let monomer = DFTD4Structure::new(3, &numbers[..3], &coords[..9], None, None, None);
+        let model_monomer = DFTD4Model::new(&monomer);
+        let (energy_ref, grad_ref, _) =
+            get_dispersion(&monomer, &model_monomer, &params, true, false);
+        let (cn_ref, _, _, _) = get_properties(&monomer, &model_monomer);
+        assert!((energy - energy_ref).abs() < 1e-12);
+        let grad_ref = grad_ref.unwrap();
+        assert!(grad[..9]
+            .iter()
+            .zip(&grad_ref)
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+        assert!(grad[9..].iter().all(|&g| g == 0.0));
+        assert!(cn[..3]
+            .iter()
+            .zip(&cn_ref)
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+        assert!(cn[3..].iter().all(|&x| x == 0.0));
+        for i in 0..6 {
+            for j in 0..6 {
+                if ghosts[i] || ghosts[j] {
+                    assert_eq!(pair2[i * 6 + j], 0.0);
+                    assert_eq!(pair3[i * 6 + j], 0.0);
+                    assert_eq!(c6[i * 6 + j], 0.0);
+                }
+            }
+        }
+        let pair_energy = pair2.iter().chain(&pair3).sum::<f64>();
+        assert!((pair_energy - energy).abs() < 1e-12);
+
+        // positions of ghost atoms are kept in updates
+        let mut moved = coords;
+        moved[9] += 1.0;
+        dimer.update(&moved, None);
+        let (energy_moved, _, _) = get_dispersion(&dimer, &model, &params, false, false);
+        assert!((energy_moved - energy_ref).abs() < 1e-12);
+        assert_eq!(dimer.get_positions(), moved.to_vec());
+        assert!(dimer.update_f(&moved[..9], None).is_err());
+
+        // counterpoise: the fragment of ghost atoms has no energy
+        use rest_dftd4::fragment::*;
+        dimer.update(&coords, None);
+        let fragments = vec![vec![0, 1, 2], vec![3, 4, 5]];
+        let (interaction, energy_cp, fragment_energies, _) =
+            get_fragment_dispersion(&dimer, &model, &params, &fragments, None);
+        assert_eq!(fragment_energies[1], 0.0);
+        assert!((fragment_energies[0] - energy_ref).abs() < 1e-12);
+        assert!((energy_cp - energy_ref).abs() < 1e-12);
+        assert!(interaction.abs() < 1e-12);
+        let charges = [1.0, -1.0];
+        let result = get_fragment_dispersion_f(&dimer, &model, &params, &fragments, Some(&charges));
+        assert!(result.is_err());
+
+        assert!(DFTD4Structure::new_with_ghosts_f(
+            6,
+            &numbers,
+            &coords,
+            None,
+            None,
+            None,
+            Some(&ghosts[..3])
+        )
+        .is_err());
+        assert!(DFTD4Structure::new_with_ghosts_f(
+            6,
+            &numbers,
+            &coords,
+            None,
+            None,
+            None,
+            Some(&[true; 6])
+        )
+        .is_err());
+        // the ghost mask is validated before the lattice
+        let singular = [10.0, 0.0, 0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0];
+        let error = DFTD4Structure::new_with_ghosts_f(
+            6,
+            &numbers,
+            &coords,
+            None,
+            Some(&singular),
+            None,
+            Some(&ghosts[..3]),
+        );
+        assert!(matches!(&error, Err(DFTD4Error::Rust(msg)) if msg.contains("ghosts")));
+    }
+
+    #[test]
+    fn test_qmmm_dispersion() {
+        use rest_dftd4::qmmm::*;
+        // water dimer, with the first monomer in the QM region
+        let (numbers, coords) = water_dimer();
+        let coords = coords.map(|x| x * AATOAU);
+        let qm = [true, true, true, false, false, false];
+        let structure = DFTD4Structure::new(6, &numbers, &coords, None, None, None);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        let model = DFTD4Model::new(&structure);
+        let result = get_qmmm_dispersion(&structure, &model, &params, &qm, None, true);
+
+        // contributions sum to the whole structure
+        let (energy, grad, _) = get_dispersion(&structure, &model, &params, true, false);
+        let (pair2, pair3) = get_pairwise_dispersion(&structure, &model, &params);
+        let regions = [&result.qm_qm, &result.qm_mm, &result.mm_mm];
+        assert!((result.get_energy() - energy).abs() < 1e-12);
+        for (k, g) in grad.unwrap().iter().enumerate() {
+            let sum = regions
+                .iter()
+                .map(|r| r.gradient.as_ref().unwrap()[k])
+                .sum::<f64>();
+            assert!((sum - g).abs() < 1e-12);
+        }
+        for ij in 0..36 {
+            let sum2 = regions.iter().map(|r| r.pair_energy2[ij]).sum::<f64>();
+            let sum3 = regions.iter().map(|r| r.pair_energy3[ij]).sum::<f64>();
+            assert_eq!(sum2, pair2[ij]);
+            assert_eq!(sum3, pair3[ij]);
+        }
+        assert!(result.qm_mm.pair_energy2[..3].iter().all(|&x| x == 0.0));
+        assert!(result.qm_qm.pair_energy2[3..6].iter().all(|&x| x == 0.0));
+
+        // QM-QM contribution is the energy of the QM region alone
+        let monomer = DFTD4Structure::new(3, &numbers[..3], &coords[..9], None, None, None);
+        let model_monomer = DFTD4Model::new(&monomer);
+        let (energy_qm, _, _) = get_dispersion(&monomer, &model_monomer, &params, false, false);
+        assert!((result.qm_qm.energy - energy_qm).abs() < 1e-12);
+        assert!(result.qm_qm.gradient.as_ref().unwrap()[9..]
+            .iter()
+            .all(|&g| g == 0.0));
+        assert!(result.mm_mm.gradient.as_ref().unwrap()[..9]
+            .iter()
+            .all(|&g| g == 0.0));
+        assert!(result.qm_mm.energy < 0.0);
+
+        assert!(get_qmmm_dispersion_f(&structure, &model, &params, &qm[..3], None, false).is_err());
+        assert!(
+            get_qmmm_dispersion_f(&structure, &model, &params, &[true; 6], None, false).is_err()
+        );
+        assert!(
+            get_qmmm_dispersion_f(&structure, &model, &params, &qm, Some([1.0, 0.0]), false)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_dispersion_terms() {
+        use rest_dftd4::terms::*;
+        // water dimer
+        let (numbers, coords) = water_dimer();
+        let coords = coords.map(|x| x * AATOAU);
+        let structure = DFTD4Structure::new(6, &numbers, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+        let values = [1.0, 0.95948085, 1.0, 0.38574991, 4.80688534, 16.0];
+        let [s6, s8, s9, a1, a2, alp] = values;
+        let params = DFTD4Param::new_rational_damping(s6, s8, s9, a1, a2, alp);
+        assert_eq!(params.get_rational_damping(), values);
+
+        // terms sum to the dispersion energy, gradient and virial
+        let terms = get_dispersion_terms(&structure, &model, &params, None, true, true);
+        assert_eq!(terms.s9, 1.0);
+        let (energy, grad, sigma) = get_dispersion(&structure, &model, &params, true, true);
+        assert!((terms.get_energy() - energy).abs() < 1e-14);
+        let sum = |a: &Option<Vec<f64>>, b: &Option<Vec<f64>>| {
+            let (a, b) = (a.as_ref().unwrap(), b.as_ref().unwrap());
+            a.iter().zip(b).map(|(x, y)| x + y).collect::<Vec<f64>>()
+        };
+        let grad_terms = sum(&terms.gradient2, &terms.gradient3);
+        assert!(grad_terms
+            .iter()
+            .zip(&grad.unwrap())
+            .all(|(a, b)| (a - b).abs() < 1e-14));
+        let sigma_terms = sum(&terms.sigma2, &terms.sigma3);
+        assert!(sigma_terms
+            .iter()
+            .zip(&sigma.unwrap())
+            .all(|(a, b)| (a - b).abs() < 1e-14));
+        let (_, pair3) = get_pairwise_dispersion(&structure, &model, &params);
+        assert!((pair3.iter().sum::<f64>() - terms.energy3).abs() < 1e-14);
+        assert!(terms.energy3 != 0.0);
+
+        // three-body term switched off
+        let terms2 = get_dispersion_terms(&structure, &model, &params, Some(0.0), true, false);
+        assert_eq!(terms2.energy2, terms.energy2);
+        assert_eq!(terms2.energy3, 0.0);
+        assert!(terms2.gradient3.unwrap().iter().all(|&g| g == 0.0));
+        assert!(terms2.sigma3.is_none());
+
+        // terms of a named functional (values of loaded parameters are not available through
+        // the C API of libdftd4)
+        let loaded = DFTD4Param::load_rational_damping("PBE", true);
+        #[cfg(not(feature = "pure-rust"))]
+        {
+            assert!(loaded.get_rational_damping_f().is_err());
+            assert!(
+                get_dispersion_terms_f(&structure, &model, &loaded, None, true, false).is_err()
+            );
+        }
+        #[cfg(feature = "pure-rust")]
+        {
+            let terms = get_dispersion_terms(&structure, &model, &loaded, None, true, false);
+            assert_eq!(terms.s9, loaded.get_rational_damping()[2]);
+            assert!(terms.energy3 != 0.0);
+            let (energy, grad, _) = get_dispersion(&structure, &model, &loaded, true, false);
+            assert!((terms.get_energy() - energy).abs() < 1e-14);
+            let grad_terms = sum(&terms.gradient2, &terms.gradient3);
+            assert!(grad_terms
+                .iter()
+                .zip(&grad.unwrap())
+                .all(|(a, b)| (a - b).abs() < 1e-14));
+        }
+    }
+
+    #[test]
+    fn test_dispersion_table() {
+        use rest_dftd4::screening::*;
+        // water dimer
+        let (numbers, coords) = water_dimer();
+        let coords = coords.map(|x| x * AATOAU);
+        let structure = DFTD4Structure::new(6, &numbers, &coords, None, None, None);
+        let model = DFTD4Model::new(&structure);
+
+        // same results as separate evaluations
+        let methods = ["PBE", "B3LYP", "TPSS"];
+        let table = get_functional_table(&structure, &model, &methods, true, true);
+        assert_eq!(table.names, methods);
+        let gradients = table.gradients.as_ref().unwrap();
+        for (k, method) in methods.iter().enumerate() {
+            let params = DFTD4Param::load_rational_damping(method, true);
+            let (energy, grad, _) = get_dispersion(&structure, &model, &params, true, false);
+            assert!((table.energies[k] - energy).abs() < 1e-14);
+            let grad = grad.unwrap();
+            assert!(gradients[k]
+                .iter()
+                .zip(&grad)
+                .all(|(a, b)| (a - b).abs() < 1e-14));
+        }
+        let (de, dg) = table.get_differences("PBE");
+        assert_eq!(de[0], 0.0);
+        assert!((de[1] - (table.energies[1] - table.energies[0])).abs() < 1e-14);
+        assert!(dg.unwrap()[0].iter().all(|&g| g == 0.0));
+        assert!(table.get_differences_f("SCAN").is_err());
+        assert!(get_functional_table_f(&structure, &model, &["PBE", "none"], true, false).is_err());
+
+        // custom parameter sets
+        let custom = DFTD4Param::new_rational_damping(1.0, 0.9, 1.0, 0.4, 4.8, 16.0);
+        let no_atm = DFTD4Param::new_rational_damping(1.0, 0.9, 0.0, 0.4, 4.8, 16.0);
+        let table = get_dispersion_table(
+            &structure,
+            &model,
+            &[("custom", &custom), ("no-atm", &no_atm)],
+            false,
+        );
+        assert!(table.gradients.is_none());
+        let (energy, _, _) = get_dispersion(&structure, &model, &no_atm, false, false);
+        assert!((table.energies[1] - energy).abs() < 1e-14);
+        assert!(table.energies[0] != table.energies[1]);
+    }
+
+    #[test]
+    fn test_conformer_ranking() {
+        use rest_dftd4::conformer::*;
+        // water dimers at different separations (Angstrom)
+        let frame = |shift: f64| {
+            let mut xyz = format!("6\nshift {}\n", shift);
+            let (numbers, coords) = water_dimer();
+            for (k, (z, r)) in numbers.iter().zip(coords.chunks(3)).enumerate() {
+                let symbol = if *z == 8 { "O" } else { "H" };
+                let x = if k >= 3 { r[0] + shift } else { r[0] };
+                xyz += &format!("{} {} {} {}\n", symbol, x, r[1], r[2]);
+            }
+            xyz
+        };
+        let shifts = [1.0, 0.0, 3.0, 0.5];
+        let xyz = shifts.iter().map(|&s| frame(s)).collect::<String>();
+        let frames = read_xyz_frames(&xyz);
+        assert_eq!(frames.len(), 4);
+        let config = ConformerConfig {
+            method: "PBE".to_string(),
+            nthreads: 2,
+            ..Default::default()
+        };
+
+        // same energies as separate evaluations
+        let energies = get_conformer_energies(&frames, &config);
+        let params = DFTD4Param::load_rational_damping("PBE", true);
+        for (frame, energy) in frames.iter().zip(&energies) {
+            let natoms = frame.numbers.len();
+            let structure =
+                DFTD4Structure::new(natoms, &frame.numbers, &frame.positions, None, None, None);
+            let model = DFTD4Model::new(&structure);
+            let (energy_ref, _, _) = get_dispersion(&structure, &model, &params, false, false);
+            assert!((energy - energy_ref).abs() < 1e-14);
+        }
+        let serial = ConformerConfig {
+            nthreads: 1,
+            ..config.clone()
+        };
+        assert_eq!(get_conformer_energies(&frames, &serial), energies);
+
+        // ranking by dispersion energies only: dimers approach
+        let ranking = rank_conformers(&frames, None, &config);
+        assert_eq!(ranking.order, [1, 3, 0, 2]);
+        assert_eq!(ranking.relative[1], 0.0);
+        let expected = (energies[0] - energies[1]) * 627.5094740631;
+        assert!((ranking.relative[0] - expected).abs() < 1e-10);
+
+        // base energies change the ranking
+        let base = [-152.0, -151.9, -152.1, -152.0];
+        let ranking = rank_conformers(&frames, Some(&base), &config);
+        assert_eq!(ranking.order[0], 2);
+        assert!((ranking.total[2] - (base[2] + energies[2])).abs() < 1e-14);
+        assert!(rank_conformers_f(&frames, Some(&base[..2]), &config).is_err());
+        // frames of different composition are not ranked
+        let mixed = read_xyz_frames(
+            &(xyz.clone() + "3\nwater\nO 0 0 0.117\nH 0 0.757 -0.467\nH 0 -0.757 -0.467\n"),
+        );
+        assert_eq!(get_conformer_energies(&mixed, &config).len(), 5);
+        assert!(rank_conformers_f(&mixed, None, &config).is_err());
+        // spin multiplicity is validated and recorded
+        let triplet = ConformerConfig {
+            multiplicity: Some(3),
+            ..config.clone()
+        };
+        assert_eq!(rank_conformers(&frames, None, &triplet).multiplicity, 3);
+        let doublet = ConformerConfig {
+            multiplicity: Some(2),
+            ..config.clone()
+        };
+        assert!(rank_conformers_f(&frames, None, &doublet).is_err());
+
+        // sorted ensemble
+        let sorted = read_xyz_frames(&get_xyz_ensemble(&frames, &ranking));
+        for (frame, &k) in sorted.iter().zip(&ranking.order) {
+            assert_eq!(frame.numbers, frames[k].numbers);
+            let suffix = format!("multiplicity 1 frame {} | {}", k + 1, frames[k].comment);
+            assert!(frame.comment.ends_with(&suffix));
+            let total = frame.comment.split_whitespace().next().unwrap();
+            assert!((total.parse::<f64>().unwrap() - ranking.total[k]).abs() < 1e-9);
+        }
+
+        let invalid = ConformerConfig {
+            method: "none".to_string(),
+            ..config
+        };
+        assert!(get_conformer_energies_f(&frames, &invalid).is_err());
+    }
+
+    /// Mock backend: pairwise `-s6 / r^6` between atoms, as seen by the public API
+    struct MockBackend;
+
+    impl rest_dftd4::backend::DispersionBackend for MockBackend {
+        type Structure = std::cell::RefCell<Vec<f64>>;
+        type Model = ();
+        type Param = f64;
+
+        fn get_version() -> usize {
+            0
+        }
+
+        fn new_structure_f(
+            natoms: usize,
+            _numbers: &[usize],
+            positions: &[f64],
+            _charge: Option<f64>,
+            _lattice: Option<&[f64]>,
+            _periodic: Option<&[bool]>,
+        ) -> Result<Self::Structure, DFTD4Error> {
+            assert_eq!(positions.len(), 3 * natoms);
+            Ok(std::cell::RefCell::new(positions.to_vec()))
+        }
+
+        fn update_structure_f(
+            structure: &Self::Structure,
+            positions: &[f64],
+            _lattice: Option<&[f64]>,
+        ) -> Result<(), DFTD4Error> {
+            structure.borrow_mut().copy_from_slice(positions);
+            Ok(())
+        }
+
+        fn get_natoms(structure: &Self::Structure) -> usize {
+            structure.borrow().len() / 3
+        }
+
+        fn new_model_f(_structure: &Self::Structure) -> Result<(), DFTD4Error> {
+            Ok(())
+        }
+
+        fn custom_model_f(
+            _structure: &Self::Structure,
+            _ga: f64,
+            _gc: f64,
+            _wf: f64,
+        ) -> Result<(), DFTD4Error> {
+            Ok(())
+        }
+
+        fn new_rational_damping_f(
+            s6: f64,
+            _s8: f64,
+            _s9: f64,
+            _a1: f64,
+            _a2: f64,
+            _alp: f64,
+        ) -> Result<f64, DFTD4Error> {
+            Ok(s6)
+        }
+
+        fn load_rational_damping_f(_method: &str, _mdb: bool) -> Result<f64, DFTD4Error> {
+            Ok(1.0)
+        }
+
+        fn get_properties_f(
+            structure: &Self::Structure,
+            _model: &(),
+        ) -> Result<DFTD4Properties, DFTD4Error> {
+            let natoms = Self::get_natoms(structure);
+            let c6 = vec![1.0; natoms * natoms];
+            Ok((vec![0.0; natoms], vec![0.0; natoms], c6, vec![1.0; natoms]))
+        }
+
+        fn get_dispersion_f(
+            structure: &Self::Structure,
+            _model: &(),
+            param: &f64,
+            eval_grad: bool,
+            _eval_sigma: bool,
+        ) -> Result<DFTD4Dispersion, DFTD4Error> {
+            let (pair, _) = Self::get_pairwise_dispersion_f(structure, &(), param)?;
+            let positions = structure.borrow();
+            let natoms = positions.len() / 3;
+            let mut gradient = vec![0.0; 3 * natoms];
+            for i in 0..natoms {
+                for j in (0..natoms).filter(|&j| j != i) {
+                    let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k]);
+                    let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                    for k in 0..3 {
+                        gradient[3 * i + k] += -12.0 * pair[i * natoms + j] / r2 * v[k];
+                    }
+                }
+            }
+            let energy = pair.iter().sum();
+            Ok((energy, eval_grad.then_some(gradient), None))
+        }
+
+        fn get_pairwise_dispersion_f(
+            structure: &Self::Structure,
+            _model: &(),
+            param: &f64,
+        ) -> Result<(Vec<f64>, Vec<f64>), DFTD4Error> {
+            let positions = structure.borrow();
+            let natoms = positions.len() / 3;
+            let mut pair = vec![0.0; natoms * natoms];
+            for i in 0..natoms {
+                for j in 0..natoms {
+                    if i != j {
+                        let v = [0, 1, 2].map(|k| positions[3 * i + k] - positions[3 * j + k]);
+                        let r2 = v.iter().map(|x| x * x).sum::<f64>();
+                        pair[i * natoms + j] = -0.5 * param / r2.powi(3);
+                    }
+                }
+            }
+            Ok((pair, vec![0.0; natoms * natoms]))
+        }
+
+        fn get_numerical_hessian_f(
+            structure: &Self::Structure,
+            _model: &(),
+            _param: &f64,
+        ) -> Result<Vec<f64>, DFTD4Error> {
+            let natoms = Self::get_natoms(structure);
+            Ok(vec![1.0; 9 * natoms * natoms])
+        }
+    }
+
+    #[test]
+    fn test_mock_backend() {
+        // two atoms 2 Bohr apart, and a ghost atom
+        let numbers = [1, 1, 1];
+        let positions = [0.0, 0.0, 0.0, 0.0, 0.0, 2.0, 5.0, 0.0, 0.0];
+        let ghosts = [false, false, true];
+        let structure = DFTD4Structure::<MockBackend>::new_with_backend_f(
+            3,
+            &numbers,
+            &positions,
+            None,
+            None,
+            None,
+            Some(&ghosts),
+        )
+        .unwrap();
+        let model = DFTD4Model::new(&structure);
+        let param = DFTD4Param::<MockBackend>::new_rational_damping_with_backend_f(
+            2.0, 1.0, 0.0, 0.4, 5.0, 16.0,
+        )
+        .unwrap();
+        assert_eq!(param.get_rational_damping()[0], 2.0);
+
+        // results of the backend are expanded to all atoms by the public API
+        let (energy, grad, _) = get_dispersion(&structure, &model, &param, true, false);
+        assert!((energy + 2.0 / 64.0).abs() < 1e-14);
+        let grad = grad.unwrap();
+        assert_eq!(grad.len(), 9);
+        assert!((grad[5] - 12.0 / 128.0).abs() < 1e-14);
+        assert!((grad[2] + grad[5]).abs() < 1e-14);
+        assert!(grad[6..].iter().all(|&g| g == 0.0));
+        let (_, _, c6, alpha) = get_properties(&structure, &model);
+        assert_eq!(alpha, [1.0, 1.0, 0.0]);
+        assert_eq!(c6[2 * 3 + 2], 0.0);
+        let (atomic2, _, atomic, _) = get_atomic_dispersion(&structure, &model, &param, false);
+        assert_eq!(atomic2[2], 0.0);
+        assert!((atomic.iter().sum::<f64>() - energy).abs() < 1e-14);
+        let hessian = get_numerical_hessian(&structure, &model, &param);
+        assert_eq!(hessian.len(), 81);
+        assert_eq!(hessian[0], 1.0);
+        assert_eq!(hessian[80], 0.0);
+        let results = get_dispersion_multi(&structure, &model, &[&param, &param], false);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].0, energy);
+
+        // updates reach the backend; features the backend lacks are reported as errors
+        structure.update(&[0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 5.0, 0.0, 0.0], None);
+        let (energy, _, _) = get_dispersion(&structure, &model, &param, false, false);
+        assert!((energy + 2.0).abs() < 1e-14);
+        assert!(get_charge_derivatives_f(&structure, &model, &param).is_err());
+        assert!(get_dynamic_polarizabilities_f(&structure, &model).is_err());
+        let mut model = DFTD4Model::new(&structure);
+        assert!(model.set_charges_f(Some(&[0.1, -0.1, 0.0])).is_err());
+        let cutoff = DFTD4Cutoff {
+            cn: 10.0,
+            ..Default::default()
+        };
+        assert!(model.set_cutoff_f(cutoff).is_err());
+    }
 }